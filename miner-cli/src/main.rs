@@ -0,0 +1,243 @@
+use std::{io::Write, path::PathBuf, time::Duration};
+
+use anyhow::anyhow;
+use clap::{Parser, ValueEnum};
+use ore_miner_types::{
+    hash::{find_hash_par, MinerControl},
+    nonce::nonce_indices,
+    pool::PoolClient,
+    OutputMessage,
+};
+use ore_pool_types::{Member, MemberChallenge};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Signer},
+};
+
+/// Consecutive failed challenge requests after which the miner backs off.
+const MAX_POLL_ERRORS: u32 = 5;
+
+/// Interval between challenge requests while waiting for a new one.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Wait after repeated challenge request failures before polling again.
+const BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Parser)]
+#[command(about = "Mines with an ORE pool, without the app UI")]
+struct Args {
+    /// Keypair of the pool member, as a Solana keypair file
+    #[arg(long, default_value_os_t = default_keypair_path())]
+    keypair: PathBuf,
+
+    /// Url of the pool server
+    #[arg(long)]
+    pool_url: String,
+
+    /// Number of cores to hash on, all of them by default
+    #[arg(long)]
+    cores: Option<u8>,
+
+    /// Seconds before the round ends at which hashing stops, leaving time to submit
+    #[arg(long, default_value_t = 5)]
+    buffer_time: i64,
+
+    #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LogFormat {
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    init_logger(args.log_format);
+
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow!("failed to read keypair {}: {}", args.keypair.display(), err))?;
+    let authority = keypair.pubkey();
+    let pool_url = args.pool_url.trim_end_matches('/').to_string();
+    let max_cores = std::thread::available_parallelism()
+        .map(|n| n.get().min(u8::MAX as usize) as u8)
+        .unwrap_or(1);
+    let cores = args.cores.unwrap_or(max_cores).clamp(1, max_cores);
+    log::info!(
+        "Mining with {} on {} cores as {}",
+        pool_url,
+        cores,
+        authority
+    );
+
+    let client = PoolClient::new(reqwest::Client::new());
+    let member = get_or_register_member(&client, &pool_url, authority).await?;
+
+    let mut last_hash_at = 0;
+    loop {
+        let challenge = poll_new_challenge(&client, &pool_url, authority, last_hash_at).await;
+        let cutoff_time = challenge
+            .challenge
+            .lash_hash_at
+            .saturating_add(60)
+            .saturating_sub(args.buffer_time)
+            .saturating_sub(challenge.unix_timestamp)
+            .max(0);
+        match mine(
+            &client,
+            &pool_url,
+            authority,
+            &member,
+            &challenge,
+            cutoff_time,
+            cores,
+        )
+        .await
+        {
+            Ok(lha) => last_hash_at = lha,
+            Err(err) => {
+                log::error!("Error mining challenge: {:?}", err);
+                last_hash_at = challenge.challenge.lash_hash_at;
+            }
+        }
+    }
+}
+
+/// Returns the member's record on the pool server, registering with the server if the member
+/// account exists on chain but the server does not know it yet.
+async fn get_or_register_member(
+    client: &PoolClient,
+    pool_url: &str,
+    authority: Pubkey,
+) -> anyhow::Result<Member> {
+    if let Ok(member) = client.get_member_record(pool_url, authority).await {
+        return Ok(member);
+    }
+    log::info!("Registering with pool server");
+    client.register(pool_url, authority).await.map_err(|err| {
+        anyhow!(
+            "failed to register with the pool, join it from the app first: {:?}",
+            err
+        )
+    })
+}
+
+/// Polls until the pool serves a challenge newer than `last_hash_at`, backing off while the
+/// pool keeps failing.
+async fn poll_new_challenge(
+    client: &PoolClient,
+    pool_url: &str,
+    authority: Pubkey,
+    last_hash_at: i64,
+) -> MemberChallenge {
+    let mut errors = 0;
+    loop {
+        match client.get_challenge(pool_url, authority).await {
+            Ok(challenge) if challenge.challenge.lash_hash_at != last_hash_at => return challenge,
+            Ok(_) => {
+                log::debug!("Same challenge, retry...");
+                errors = 0;
+            }
+            Err(err) => {
+                log::error!("Error polling challenge: {:?}", err);
+                errors += 1;
+                if errors >= MAX_POLL_ERRORS {
+                    log::warn!("Pool is failing, retrying in {}s", BACKOFF.as_secs());
+                    tokio::time::sleep(BACKOFF).await;
+                    errors = 0;
+                    continue;
+                }
+            }
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Hashes the challenge until the cutoff, contributing each improving solution to the pool.
+/// Returns the last hash of the challenge once it expires.
+async fn mine(
+    client: &PoolClient,
+    pool_url: &str,
+    authority: Pubkey,
+    member: &Member,
+    challenge: &MemberChallenge,
+    cutoff_time: i64,
+    cores: u8,
+) -> anyhow::Result<i64> {
+    let device_id = 0;
+    let nonce_indices = nonce_indices(member, challenge, cores, device_id)?;
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<OutputMessage>();
+    let control = MinerControl::new(cores, false);
+    log::info!(
+        "Hashing challenge {} for {}s",
+        challenge.challenge.lash_hash_at,
+        cutoff_time
+    );
+    find_hash_par(
+        &challenge.challenge.challenge,
+        challenge.challenge.lash_hash_at,
+        nonce_indices.as_slice(),
+        cutoff_time as u64,
+        challenge.challenge.min_difficulty as u32,
+        cores,
+        &control,
+        &sender,
+    )
+    .await?;
+    drop(sender);
+
+    // Contribute improving solutions until the challenge expires
+    let mut best_difficulty = 0;
+    while let Some(msg) = receiver.recv().await {
+        match msg {
+            OutputMessage::Solution(solution) => {
+                let difficulty = solution.to_hash().difficulty();
+                if difficulty <= best_difficulty {
+                    continue;
+                }
+                best_difficulty = difficulty;
+                match client.post_solution(pool_url, authority, solution).await {
+                    Ok(()) => log::info!("Submitted solution with difficulty {}", difficulty),
+                    Err(err) => log::error!("Error posting solution: {:?}", err),
+                }
+            }
+            OutputMessage::Expired(last_hash_at) => {
+                log::info!("Challenge expired, best difficulty {}", best_difficulty);
+                return Ok(last_hash_at);
+            }
+            OutputMessage::Stats { hashes, .. } => log::debug!("{} hashes so far", hashes),
+            OutputMessage::TimeRemaining(..)
+            | OutputMessage::Init
+            | OutputMessage::Ready(_)
+            | OutputMessage::Benchmark(_) => {}
+        }
+    }
+    Err(anyhow!(
+        "miner threads stopped before the challenge expired"
+    ))
+}
+
+fn init_logger(format: LogFormat) {
+    let mut builder =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"));
+    if let LogFormat::Json = format {
+        builder.format(|buf, record| {
+            let line = serde_json::json!({
+                "timestamp": buf.timestamp().to_string(),
+                "level": record.level().to_string(),
+                "target": record.target(),
+                "message": record.args().to_string(),
+            });
+            writeln!(buf, "{}", line)
+        });
+    }
+    builder.init();
+}
+
+fn default_keypair_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    PathBuf::from(home).join(".config/solana/id.json")
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+/// Share of the single-core hashrate an added core must contribute to be worth using.
+const MIN_MARGINAL_GAIN: f64 = 0.5;
+
+/// Hashing measured on one core count during a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkSample {
+    pub cores: usize,
+    pub hashes: u64,
+    /// Milliseconds spent hashing.
+    pub elapsed_ms: u64,
+    pub best_difficulty: u32,
+}
+
+impl BenchmarkSample {
+    /// Hashes per second.
+    pub fn hashrate(&self) -> f64 {
+        if self.elapsed_ms == 0 {
+            return 0.0;
+        }
+        self.hashes as f64 * 1000.0 / self.elapsed_ms as f64
+    }
+}
+
+/// Recommends the highest core count for which each added core still contributes at least
+/// half of the single-core hashrate.
+pub fn recommend_cores(samples: &[BenchmarkSample]) -> Option<usize> {
+    let mut samples = samples.to_vec();
+    samples.sort_by_key(|sample| sample.cores);
+    let first = samples.first()?;
+    let per_core = first.hashrate() / first.cores.max(1) as f64;
+    let mut recommended = first.cores;
+    for pair in samples.windows(2) {
+        let added = pair[1].cores.saturating_sub(pair[0].cores);
+        if added == 0 {
+            continue;
+        }
+        let marginal = (pair[1].hashrate() - pair[0].hashrate()) / added as f64;
+        if marginal < per_core * MIN_MARGINAL_GAIN {
+            break;
+        }
+        recommended = pair[1].cores;
+    }
+    Some(recommended)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A one second sample at the given hashrate.
+    fn sample(cores: usize, hashrate: u64) -> BenchmarkSample {
+        BenchmarkSample {
+            cores,
+            hashes: hashrate,
+            elapsed_ms: 1000,
+            best_difficulty: 0,
+        }
+    }
+
+    #[test]
+    fn recommends_nothing_without_samples() {
+        assert_eq!(recommend_cores(&[]), None);
+    }
+
+    #[test]
+    fn recommends_the_only_sample() {
+        assert_eq!(recommend_cores(&[sample(4, 400)]), Some(4));
+    }
+
+    #[test]
+    fn recommends_all_cores_while_scaling_linearly() {
+        let samples = [
+            sample(1, 100),
+            sample(2, 200),
+            sample(3, 300),
+            sample(4, 400),
+        ];
+        assert_eq!(recommend_cores(&samples), Some(4));
+    }
+
+    #[test]
+    fn stops_at_diminishing_gain() {
+        // the third core adds 40 hashes per second, under half of the first core's 100
+        let samples = [
+            sample(1, 100),
+            sample(2, 190),
+            sample(3, 230),
+            sample(4, 400),
+        ];
+        assert_eq!(recommend_cores(&samples), Some(2));
+    }
+
+    #[test]
+    fn sorts_samples_by_cores() {
+        let samples = [sample(3, 230), sample(1, 100), sample(2, 190)];
+        assert_eq!(recommend_cores(&samples), Some(2));
+    }
+
+    #[test]
+    fn treats_zero_elapsed_as_no_hashrate() {
+        let zero = BenchmarkSample {
+            elapsed_ms: 0,
+            ..sample(2, 200)
+        };
+        assert_eq!(zero.hashrate(), 0.0);
+        assert_eq!(recommend_cores(&[sample(1, 100), zero]), Some(1));
+    }
+}
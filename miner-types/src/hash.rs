@@ -0,0 +1,241 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use drillx::{equix, Solution};
+
+use crate::{benchmark::BenchmarkSample, nonce::solo_nonce_indices, OutputMessage};
+
+/// Interval at which a paused thread checks whether it may resume.
+const PAUSE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Interval between stats messages.
+const STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Shared with the hashing threads of a job to pause, resume or cancel them, and to count
+/// their hashes.
+#[derive(Clone)]
+pub struct MinerControl {
+    paused: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    hashes: Arc<Vec<AtomicU64>>,
+    best_difficulty: Arc<AtomicU32>,
+}
+
+impl MinerControl {
+    pub fn new(cores: u8, paused: bool) -> Self {
+        MinerControl {
+            paused: Arc::new(AtomicBool::new(paused)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            hashes: Arc::new((0..cores).map(|_| AtomicU64::new(0)).collect()),
+            best_difficulty: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Stops the threads without waiting for the cutoff. No expiry is sent.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Hashes computed and best difficulty found so far.
+    pub fn stats(&self) -> OutputMessage {
+        let per_core = self
+            .hashes
+            .iter()
+            .map(|hashes| hashes.load(Ordering::Relaxed))
+            .collect::<Vec<_>>();
+        OutputMessage::Stats {
+            hashes: per_core.iter().sum(),
+            best_difficulty: self.best_difficulty.load(Ordering::Relaxed),
+            per_core,
+        }
+    }
+
+    fn record(&self, core: usize, hashes: u64, best_difficulty: u32) {
+        if let Some(counter) = self.hashes.get(core) {
+            counter.fetch_add(hashes, Ordering::Relaxed);
+        }
+        self.best_difficulty
+            .fetch_max(best_difficulty, Ordering::Relaxed);
+    }
+}
+
+/// Hashes a challenge on the given number of cores, each pinned to its own thread and starting
+/// from its own nonce index. Improving solutions, time remaining, stats and expiry are sent on
+/// `solutions_channel`. Paused threads still expire at the cutoff.
+#[allow(clippy::too_many_arguments)]
+pub async fn find_hash_par(
+    challenge: &[u8; 32],
+    last_hash_at: i64,
+    nonce_indices: &[u64],
+    cutoff_time: u64,
+    min_difficulty: u32,
+    cores: u8,
+    control: &MinerControl,
+    solutions_channel: &tokio::sync::mpsc::UnboundedSender<OutputMessage>,
+) -> Result<()> {
+    // get cores
+    let core_ids = core_affinity::get_core_ids().ok_or(anyhow::anyhow!("failed to query cores"))?;
+    let core_ids = core_ids.into_iter().filter(|id| id.id < (cores as usize));
+    // distribute
+    for core_id in core_ids {
+        let challenge = *challenge;
+        std::thread::spawn({
+            // init drillx solver
+            let mut memory = drillx::equix::SolverMemory::new();
+            let solutions_channel = solutions_channel.clone();
+            let control = control.clone();
+            let nonce = nonce_indices[core_id.id];
+            move || {
+                // pin to core
+                let _ = core_affinity::set_for_current(core_id);
+                // start hashing
+                let timer = std::time::Instant::now();
+                let mut last_stats = timer;
+                let mut nonce = nonce;
+                let mut best_difficulty = 0;
+                loop {
+                    // exit without expiring if cancelled
+                    if control.is_cancelled() {
+                        break;
+                    }
+                    let paused = control.is_paused();
+                    if paused {
+                        std::thread::sleep(PAUSE_INTERVAL);
+                    } else {
+                        // get hashes
+                        let hxs = solve(&mut memory, &challenge, &nonce.to_le_bytes());
+                        let num_hashes = hxs.len() as u64;
+                        // look for best difficulty score in all hashes
+                        for hx in hxs {
+                            let difficulty = hx.difficulty();
+                            if difficulty.gt(&best_difficulty) {
+                                best_difficulty = difficulty;
+                                // continously submit best solution to pool
+                                if difficulty.ge(&min_difficulty) {
+                                    let diggest = hx.d;
+                                    let nonce = nonce.to_le_bytes();
+                                    let solution = Solution {
+                                        d: diggest,
+                                        n: nonce,
+                                    };
+                                    let solution = OutputMessage::Solution(solution);
+                                    if let Err(err) = solutions_channel.send(solution) {
+                                        log::error!("{:?}", err);
+                                    }
+                                }
+                            }
+                        }
+                        control.record(core_id.id, num_hashes, best_difficulty);
+                    }
+                    // exit if time has elapsed
+                    if paused || nonce % 100 == 0 {
+                        if timer.elapsed().as_secs().ge(&cutoff_time) {
+                            // send expiration message
+                            if core_id.id == 0 {
+                                let expired = OutputMessage::Expired(last_hash_at);
+                                if let Err(err) = solutions_channel.send(expired) {
+                                    log::error!("{:?}", err);
+                                }
+                            }
+                            break;
+                        } else if core_id.id == 0 {
+                            let remaining = cutoff_time.saturating_sub(timer.elapsed().as_secs());
+                            if let Err(err) = solutions_channel
+                                .send(OutputMessage::TimeRemaining(remaining as i64, vec![]))
+                            {
+                                log::error!("{:?}", err);
+                            }
+                            // send stats
+                            if last_stats.elapsed() >= STATS_INTERVAL {
+                                last_stats = std::time::Instant::now();
+                                if let Err(err) = solutions_channel.send(control.stats()) {
+                                    log::error!("{:?}", err);
+                                }
+                            }
+                        }
+                    }
+                    if !paused {
+                        nonce = nonce.wrapping_add(1);
+                    }
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Hashes a fixed challenge on the given number of cores for `duration`, counting the hashes
+/// computed and the best difficulty found.
+pub fn benchmark(cores: u8, duration: std::time::Duration) -> BenchmarkSample {
+    let core_ids = core_affinity::get_core_ids().unwrap_or_default();
+    let core_ids = core_ids
+        .into_iter()
+        .filter(|id| id.id < (cores as usize))
+        .collect::<Vec<_>>();
+    let nonce_indices = solo_nonce_indices(cores);
+    let timer = std::time::Instant::now();
+    let handles = core_ids
+        .iter()
+        .map(|core_id| {
+            let core_id = *core_id;
+            let nonce = nonce_indices[core_id.id];
+            std::thread::spawn(move || {
+                // pin to core
+                let _ = core_affinity::set_for_current(core_id);
+                let mut memory = equix::SolverMemory::new();
+                let mut nonce = nonce;
+                let mut hashes = 0u64;
+                let mut best_difficulty = 0;
+                while timer.elapsed() < duration {
+                    for hx in solve(&mut memory, &[0; 32], &nonce.to_le_bytes()) {
+                        hashes += 1;
+                        best_difficulty = best_difficulty.max(hx.difficulty());
+                    }
+                    nonce = nonce.wrapping_add(1);
+                }
+                (hashes, best_difficulty)
+            })
+        })
+        .collect::<Vec<_>>();
+    let (hashes, best_difficulty) = handles
+        .into_iter()
+        .filter_map(|handle| handle.join().ok())
+        .fold((0, 0), |(hashes, best), (h, b)| (hashes + h, best.max(b)));
+    BenchmarkSample {
+        cores: core_ids.len(),
+        hashes,
+        elapsed_ms: timer.elapsed().as_millis() as u64,
+        best_difficulty,
+    }
+}
+
+#[inline(always)]
+fn solve(
+    mem: &mut equix::SolverMemory,
+    challenge: &[u8; 32],
+    nonce: &[u8; 8],
+) -> Vec<drillx::Hash> {
+    drillx::hashes_with_memory(mem, challenge, nonce)
+}
@@ -1,11 +1,51 @@
 use serde::{Deserialize, Serialize};
 
+pub mod benchmark;
+#[cfg(feature = "native")]
+pub mod hash;
+pub mod nonce;
+#[cfg(feature = "client")]
+pub mod pool;
+
+/// Version of the messages below, bumped whenever they change so the app can tell a stale
+/// miner build apart.
+pub const PROTOCOL_VERSION: u32 = 3;
+
 #[derive(Debug, Serialize, Deserialize)]
-pub struct InputMessage {
-    pub member: ore_pool_types::Member,
-    pub challenge: ore_pool_types::MemberChallenge,
-    pub cutoff_time: i64,
-    pub cores: usize,
+pub enum InputMessage {
+    /// Contribute to a pool challenge, searching the member's share of the nonce space.
+    Pool {
+        member: ore_pool_types::Member,
+        challenge: ore_pool_types::MemberChallenge,
+        cutoff_time: i64,
+        cores: usize,
+    },
+    /// Mine a proof challenge directly, searching the whole nonce space.
+    Solo {
+        challenge: [u8; 32],
+        last_hash_at: i64,
+        min_difficulty: u32,
+        cutoff_time: i64,
+        cores: usize,
+    },
+    /// Hash a challenge on a single thread, starting from `nonce`. Sent to each browser worker.
+    Nonces {
+        challenge: [u8; 32],
+        last_hash_at: i64,
+        min_difficulty: u64,
+        cutoff_time: i64,
+        nonce: u64,
+    },
+    /// Measure the hashrate for a fixed number of seconds on each core count up to `cores`.
+    Benchmark {
+        seconds: u64,
+        cores: usize,
+    },
+    /// Stop hashing until resumed. Work in progress still expires at its cutoff.
+    Pause,
+    Resume,
+    /// Stop the work in progress right away, without an expiry.
+    Cancel,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -14,7 +54,18 @@ pub enum OutputMessage {
     Solution(drillx::Solution),
     Expired(LastHashAt),
     TimeRemaining(Seconds, CpuUtilization),
+    Benchmark(benchmark::BenchmarkSample),
+    /// Sent once the miner is ready, with the protocol version it implements.
+    Ready(ProtocolVersion),
+    /// Progress of the work in progress, sent periodically.
+    Stats {
+        hashes: u64,
+        best_difficulty: u32,
+        /// Hashes computed on each core.
+        per_core: Vec<u64>,
+    },
 }
+type ProtocolVersion = u32;
 type LastHashAt = i64;
 type Seconds = i64;
 type CpuUtilization = Vec<f32>;
@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+/// Splits a member's share of the pool nonce space across cores.
+pub fn nonce_indices(
+    member: &ore_pool_types::Member,
+    challenge: &ore_pool_types::MemberChallenge,
+    cores: u8,
+    device_id: u8,
+) -> Result<Vec<u64>> {
+    // build nonce indices
+    let cores = cores as u64;
+    let num_total_members = challenge.num_total_members.max(1);
+    let member_search_space_size = u64::MAX.saturating_div(num_total_members);
+    let device_search_space_size =
+        member_search_space_size.saturating_div(challenge.num_devices as u64);
+    // check device id doesn't go beyond pool limit
+    if device_id > challenge.num_devices {
+        return Err(anyhow::anyhow!("too many devices"));
+    }
+    // calculate bounds on nonce space
+    let left_bound = member_search_space_size.saturating_mul(member.id as u64)
+        + (device_id as u64).saturating_mul(device_search_space_size);
+    // split nonce-device space for muliple cores
+    let range_per_core = device_search_space_size.saturating_div(cores);
+    let mut nonce_indices = Vec::with_capacity(cores as usize);
+    for n in 0..(cores) {
+        let index = left_bound.saturating_add(n.saturating_mul(range_per_core));
+        nonce_indices.push(index);
+    }
+    Ok(nonce_indices)
+}
+
+/// Splits the whole nonce space evenly across cores, for mining without a pool.
+pub fn solo_nonce_indices(cores: u8) -> Vec<u64> {
+    let cores = cores.max(1) as u64;
+    let range_per_core = u64::MAX.saturating_div(cores);
+    (0..cores)
+        .map(|n| n.saturating_mul(range_per_core))
+        .collect()
+}
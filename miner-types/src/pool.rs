@@ -0,0 +1,66 @@
+use ore_pool_types::{ContributePayloadV2, Member, MemberChallenge, RegisterPayload};
+use solana_program::pubkey::Pubkey;
+
+/// Requests to a pool server's mining endpoints, shared by the app and the command-line miner.
+#[derive(Clone)]
+pub struct PoolClient {
+    http: reqwest::Client,
+}
+
+impl PoolClient {
+    pub fn new(http: reqwest::Client) -> Self {
+        PoolClient { http }
+    }
+
+    pub async fn get_challenge(
+        &self,
+        pool_url: &str,
+        authority: Pubkey,
+    ) -> reqwest::Result<MemberChallenge> {
+        let get_url = format!("{}/challenge/{}", pool_url, authority);
+        self.http.get(get_url).send().await?.json().await
+    }
+
+    pub async fn get_member_record(
+        &self,
+        pool_url: &str,
+        authority: Pubkey,
+    ) -> reqwest::Result<Member> {
+        let get_url = format!("{}/member/{}", pool_url, authority);
+        self.http.get(get_url).send().await?.json().await
+    }
+
+    /// Registers the authority with the pool server. Its member account must already exist.
+    pub async fn register(&self, pool_url: &str, authority: Pubkey) -> reqwest::Result<Member> {
+        let post_url = format!("{}/register", pool_url);
+        let body = RegisterPayload { authority };
+        self.http
+            .post(post_url)
+            .json(&body)
+            .send()
+            .await?
+            .json()
+            .await
+    }
+
+    /// Contributes a solution, failing if the pool does not accept it.
+    pub async fn post_solution(
+        &self,
+        pool_url: &str,
+        authority: Pubkey,
+        solution: drillx::Solution,
+    ) -> reqwest::Result<()> {
+        let post_url = format!("{}/contribute", pool_url);
+        let payload = ContributePayloadV2 {
+            authority,
+            solution,
+        };
+        self.http
+            .post(post_url)
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
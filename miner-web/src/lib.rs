@@ -1,18 +1,47 @@
-use std::ops::Div;
-
 #[cfg(feature = "worker")]
 use gloo_worker::Registrable;
-use gloo_worker::{Worker, WorkerScope};
-use ore_miner_types::{InputMessage, OutputMessage};
+use gloo_worker::{HandlerId, Worker, WorkerScope};
+use ore_miner_types::{
+    benchmark::BenchmarkSample, nonce::nonce_indices, InputMessage, OutputMessage, PROTOCOL_VERSION,
+};
 #[cfg(feature = "worker")]
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+/// Milliseconds spent hashing before yielding to incoming messages.
+const BATCH_MS: f64 = 100.0;
+
+/// Milliseconds between checks of whether paused work has expired.
+const PAUSE_MS: i32 = 100;
+
+/// Milliseconds between stats messages.
+const STATS_MS: f64 = 1000.0;
 
-pub struct Miner;
+pub struct Miner {
+    job: Option<Job>,
+    paused: bool,
+    /// Whether the next step is already scheduled.
+    scheduled: bool,
+    memory: drillx::equix::SolverMemory,
+}
+
+/// A pool challenge being hashed, a batch at a time.
+struct Job {
+    id: HandlerId,
+    challenge: [u8; 32],
+    last_hash_at: i64,
+    min_difficulty: u64,
+    /// Time at which the job expires, in milliseconds.
+    cutoff_ms: f64,
+    nonce: u64,
+    hashes: u64,
+    best_difficulty: u64,
+    last_stats_ms: f64,
+}
 
 impl Worker for Miner {
-    /// Update message type.
-    type Message = String;
+    /// Update message type, sent to continue hashing the job in progress.
+    type Message = ();
     /// Incoming message type.
     type Input = InputMessage;
     /// Outgoing message type.
@@ -20,28 +49,201 @@ impl Worker for Miner {
 
     /// Creates an instance of a worker.
     fn create(_: &WorkerScope<Self>) -> Self {
-        Miner
+        Miner {
+            job: None,
+            paused: false,
+            scheduled: false,
+            memory: drillx::equix::SolverMemory::new(),
+        }
     }
 
     /// Receives an update.
     ///
     /// This method is called when the worker send messages to itself via [`WorkerScope::send_message`].
-    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+    fn update(&mut self, scope: &WorkerScope<Self>, _msg: Self::Message) {
+        self.scheduled = false;
+        self.step(scope);
+    }
+
+    /// Tells a newly connected bridge which protocol version this worker implements.
+    fn connected(&mut self, scope: &WorkerScope<Self>, id: HandlerId) {
+        scope.respond(id, OutputMessage::Ready(PROTOCOL_VERSION));
+    }
 
     /// Receives an input from a connected bridge.
     ///
     /// When a bridge sends an input via [`WorkerBridge::send`](crate::WorkerBridge::send), the worker will receive the
     /// input via this method.
-    fn received(
-        &mut self,
-        scope: &WorkerScope<Self>,
-        msg: Self::Input,
-        id: gloo_worker::HandlerId,
-    ) {
-        log::info!("challenge received: {:?}", msg);
-        // mine for solutions
-        if let Err(err) = mine(msg.member, msg.challenge, msg.cutoff_time, &scope, id) {
-            log::error!("{:?}", err);
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        log::info!("message received: {:?}", msg);
+        match msg {
+            InputMessage::Pool {
+                member,
+                challenge,
+                cutoff_time,
+                ..
+            } => {
+                // a lone worker searches the member's whole share
+                match nonce_indices(&member, &challenge, 1, 0) {
+                    Ok(nonce_indices) => {
+                        let challenge = challenge.challenge;
+                        self.job = Some(Job::new(
+                            challenge.challenge,
+                            challenge.lash_hash_at,
+                            challenge.min_difficulty,
+                            cutoff_time,
+                            nonce_indices[0],
+                            id,
+                        ));
+                        self.schedule(scope, 0);
+                    }
+                    Err(err) => log::error!("{:?}", err),
+                }
+            }
+            InputMessage::Nonces {
+                challenge,
+                last_hash_at,
+                min_difficulty,
+                cutoff_time,
+                nonce,
+            } => {
+                // mine for solutions
+                self.job = Some(Job::new(
+                    challenge,
+                    last_hash_at,
+                    min_difficulty,
+                    cutoff_time,
+                    nonce,
+                    id,
+                ));
+                self.schedule(scope, 0);
+            }
+            InputMessage::Solo { .. } => {
+                log::error!("solo mining is not supported in the browser");
+            }
+            InputMessage::Benchmark { seconds, .. } => {
+                // a worker hashes on a single thread
+                let sample = benchmark(seconds);
+                scope.respond(id, OutputMessage::Benchmark(sample));
+            }
+            InputMessage::Pause => {
+                self.paused = true;
+            }
+            InputMessage::Resume => {
+                self.paused = false;
+                self.schedule(scope, 0);
+            }
+            InputMessage::Cancel => {
+                self.job = None;
+            }
+        }
+    }
+}
+
+impl Miner {
+    /// Hashes the job in progress for a batch, then schedules the next batch.
+    fn step(&mut self, scope: &WorkerScope<Self>) {
+        let Some(job) = &mut self.job else {
+            return;
+        };
+        if !self.paused {
+            job.hash(&mut self.memory, scope);
+        }
+
+        // exit if time has elapsed
+        let now = timer().now();
+        if now > job.cutoff_ms {
+            scope.respond(job.id, OutputMessage::Expired(job.last_hash_at));
+            self.job = None;
+            return;
+        }
+        let remaining = ((job.cutoff_ms - now) / 1000.0) as i64;
+        scope.respond(job.id, OutputMessage::TimeRemaining(remaining, vec![]));
+        if now - job.last_stats_ms >= STATS_MS {
+            job.last_stats_ms = now;
+            scope.respond(
+                job.id,
+                OutputMessage::Stats {
+                    hashes: job.hashes,
+                    best_difficulty: job.best_difficulty as u32,
+                    per_core: vec![job.hashes],
+                },
+            );
+        }
+        let delay = if self.paused { PAUSE_MS } else { 0 };
+        self.schedule(scope, delay);
+    }
+
+    /// Continues the job after `delay_ms`, letting incoming messages through in between.
+    fn schedule(&mut self, scope: &WorkerScope<Self>, delay_ms: i32) {
+        if self.scheduled || self.job.is_none() {
+            return;
+        }
+        let scope = scope.clone();
+        let callback = Closure::once_into_js(move || scope.send_message(()));
+        let global = js_sys::global().unchecked_into::<web_sys::WorkerGlobalScope>();
+        match global.set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.unchecked_ref(),
+            delay_ms,
+        ) {
+            Ok(_) => self.scheduled = true,
+            Err(err) => log::error!("{:?}", err),
+        }
+    }
+}
+
+impl Job {
+    fn new(
+        challenge: [u8; 32],
+        last_hash_at: i64,
+        min_difficulty: u64,
+        cutoff_time: i64,
+        nonce: u64,
+        id: HandlerId,
+    ) -> Self {
+        let now = timer().now();
+        Job {
+            id,
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_ms: now + cutoff_time as f64 * 1000.0,
+            nonce,
+            hashes: 0,
+            best_difficulty: 0,
+            last_stats_ms: now,
+        }
+    }
+
+    fn hash(&mut self, memory: &mut drillx::equix::SolverMemory, scope: &WorkerScope<Miner>) {
+        let t0 = timer().now();
+        while timer().now() - t0 < BATCH_MS {
+            // get hashes
+            let hxs =
+                drillx::hashes_with_memory(memory, &self.challenge, &self.nonce.to_le_bytes());
+            self.hashes += hxs.len() as u64;
+
+            // look for best difficulty score in all hashes
+            for hx in hxs {
+                let difficulty = hx.difficulty() as u64;
+                // push continuous update thru channel
+                if difficulty.gt(&self.best_difficulty) {
+                    self.best_difficulty = difficulty;
+                    if difficulty.ge(&self.min_difficulty) {
+                        let digest = hx.d;
+                        let nonce = self.nonce.to_le_bytes();
+                        let solution = drillx::Solution {
+                            d: digest,
+                            n: nonce,
+                        };
+                        log::info!("submitting solution to app: {:?}", solution);
+                        scope.respond(self.id, OutputMessage::Solution(solution));
+                    }
+                }
+            }
+
+            // increment nonce
+            self.nonce = self.nonce.wrapping_add(1);
         }
     }
 }
@@ -55,84 +257,26 @@ fn timer() -> web_sys::Performance {
     performance
 }
 
-fn now() -> i64 {
-    let ms = timer().now();
-    let seconds = ms.div(1000.0);
-    seconds as i64
-}
-
-fn elapsed(t0: i64) -> i64 {
-    let diff = now() - t0;
-    diff
-}
-
-fn mine(
-    member: ore_pool_types::Member,
-    challenge: ore_pool_types::MemberChallenge,
-    cutoff_time: i64,
-    scope: &WorkerScope<Miner>,
-    id: gloo_worker::HandlerId,
-) -> Result<(), anyhow::Error> {
-    // build nonce indices
-    let nonce_index = member.id as u64;
-    let num_total_members = challenge.num_total_members.max(1);
-    let u64_unit = u64::MAX.saturating_div(num_total_members);
-
-    // split member nonce space for multiple devices
-    let device_id = 0u64;
-    let nonce_unit = u64_unit.saturating_div(challenge.num_devices as u64);
-    let left_bound = u64_unit.saturating_mul(nonce_index) + device_id.saturating_mul(nonce_unit);
-
-    // start hashing
-    let t0 = now();
-    let mut nonce = left_bound;
+fn benchmark(seconds: u64) -> BenchmarkSample {
+    let t0 = timer().now();
+    let duration_ms = seconds as f64 * 1000.0;
+    let mut nonce = 0u64;
+    let mut hashes = 0;
     let mut best_difficulty = 0;
     let mut memory = drillx::equix::SolverMemory::new();
-    loop {
-        // get hashes
-        let hxs = drillx::hashes_with_memory(
-            &mut memory,
-            &challenge.challenge.challenge,
-            &nonce.to_le_bytes(),
-        );
-
-        // look for best difficulty score in all hashes
-        for hx in hxs {
-            let difficulty = hx.difficulty() as u64;
-            // push continuous update thru channel
-            if difficulty.gt(&best_difficulty) {
-                best_difficulty = difficulty;
-                if difficulty.ge(&challenge.challenge.min_difficulty) {
-                    let digest = hx.d;
-                    let nonce = nonce.to_le_bytes();
-                    let solution = drillx::Solution {
-                        d: digest,
-                        n: nonce,
-                    };
-                    log::info!("submitting solution to app: {:?}", solution);
-                    scope.respond(id, OutputMessage::Solution(solution));
-                }
-            }
+    while timer().now() - t0 < duration_ms {
+        for hx in drillx::hashes_with_memory(&mut memory, &[0; 32], &nonce.to_le_bytes()) {
+            hashes += 1;
+            best_difficulty = best_difficulty.max(hx.difficulty());
         }
-
-        // exit if time has elapsed
-        if nonce % 10 == 0 {
-            log::info!("mining: {:?}", nonce);
-            let elapsed_time = elapsed(t0);
-            if elapsed_time > cutoff_time {
-                scope.respond(id, OutputMessage::Expired(challenge.challenge.lash_hash_at));
-                break;
-            } else {
-                scope.respond(
-                    id,
-                    OutputMessage::TimeRemaining(cutoff_time - elapsed_time, vec![]),
-                );
-            }
-        }
-        // increment nonce
-        nonce += 1;
+        nonce = nonce.wrapping_add(1);
+    }
+    BenchmarkSample {
+        cores: 1,
+        hashes,
+        elapsed_ms: (timer().now() - t0) as u64,
+        best_difficulty,
     }
-    Ok(())
 }
 
 #[cfg(feature = "worker")]
@@ -41,7 +41,7 @@ pub fn IdleDepositForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {}
+                Fee { transaction: tx }
             }
             SubmitButton {
                 title: "Submit".to_string(),
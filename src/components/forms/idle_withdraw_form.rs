@@ -61,7 +61,7 @@ pub fn IdleWithdrawForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {}
+                Fee { transaction: tx }
             }
             SubmitButton {
                 title: "Submit".to_string(),
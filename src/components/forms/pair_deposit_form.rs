@@ -152,7 +152,7 @@ pub fn PairDepositForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {},
+                Fee { transaction: tx }
             }
 
             SubmitButton {
@@ -162,7 +162,7 @@ pub fn PairWithdrawForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {}
+                Fee { transaction: tx }
             }
             SubmitButton {
                 title: "Submit".to_string(),
@@ -62,9 +62,6 @@ pub fn SwapForm(
     // Quote fetcher with debounce
     let mut quote_fetcher = use_quote(sell_token, buy_token, buy_input_amount, quote_response);
 
-    // Priority fee
-    let mut priority_fee = use_signal::<u64>(|| 0);
-
     // When sell input amount changes, fetch a new quote
     use_effect(move || {
         let sell_input_amount = sell_input_amount.read().clone();
@@ -77,18 +74,7 @@ pub fn SwapForm(
     });
 
     // Build swap transaction
-    let swap_tx = use_swap_transaction(
-        quote_response,
-        sell_token,
-        sell_token_balance,
-        priority_fee,
-        err,
-    );
-
-    // TODO: remove priority fee from use_effect once we finish swap priority fee. Including now to avoid rerenders on write
-    use_effect(move || {
-        priority_fee.set(0000000000);
-    });
+    let swap_tx = use_swap_transaction(quote_response, sell_token, sell_token_balance, err);
 
     // On successful transaction, reset input amounts
     on_transaction_done(move |_sig| {
@@ -141,7 +127,7 @@ pub fn SwapForm(
             }
             SwapDetails {
                 quote_response,
-                priority_fee,
+                swap_tx,
             }
             SwapButton {
                 quote_response,
@@ -155,7 +141,7 @@ pub fn SwapForm(
 #[component]
 fn SwapDetails(
     quote_response: Signal<Option<QuoteResponse>>,
-    priority_fee: Signal<u64>,
+    swap_tx: Resource<GatewayResult<VersionedTransaction>>,
 ) -> Element {
     let (price_impact_value, _slippage, _transaction_fee) = {
         let quote_response = &*quote_response.read();
@@ -186,7 +172,7 @@ fn SwapDetails(
             class: "px-4",
             gap: 2,
             SwapDetailLabel { title: "Price impact", value: price_impact_value }
-            Fee {}
+            Fee { transaction: swap_tx }
         }
     }
 }
@@ -41,7 +41,7 @@ pub fn TokenDepositForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {}
+                Fee { transaction: tx }
             }
             SubmitButton {
                 title: "Submit".to_string(),
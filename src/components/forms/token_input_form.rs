@@ -1,16 +1,19 @@
 use dioxus::prelude::*;
 
 use crate::{
-    components::{CarrotDownIcon, Col, LoadingValue, LoadingValueSize, Row, TokenPicker},
+    components::{
+        CarrotDownIcon, Col, LoadingValue, LoadingValueSize, Row, TokenPicker, UnconfirmedValue,
+    },
     config::Token,
-    gateway::{GatewayResult, UiTokenAmount},
-    hooks::MIN_SOL_BALANCE,
+    gateway::{GatewayError, GatewayResult, UiTokenAmount},
+    hooks::{use_token_balance_unconfirmed, MIN_SOL_BALANCE},
 };
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum TokenInputError {
     InsufficientBalance(Token),
     _InsufficientSol,
+    Gateway(GatewayError),
 }
 
 impl ToString for TokenInputError {
@@ -20,6 +23,7 @@ impl ToString for TokenInputError {
             TokenInputError::_InsufficientSol => {
                 format!("Not enough SOL (Minimum {:.1} SOL)", MIN_SOL_BALANCE)
             }
+            TokenInputError::Gateway(err) => err.user_message(),
         }
     }
 }
@@ -218,11 +222,16 @@ fn Toolbar(
 }
 
 #[component]
-fn ToolbarBalance(ui_amount_string: String, token: Token) -> Element {
+fn ToolbarBalance(ui_amount_string: String, token: ReadOnlySignal<Token>) -> Element {
+    let mint = use_memo(move || token.read().mint);
+    let unconfirmed = use_token_balance_unconfirmed(mint);
     rsx! {
+        if unconfirmed() {
+            UnconfirmedValue {}
+        }
         span {
             class: "my-auto text-xs font-medium py-1 px-1 font-medium text-elements-lowEmphasis",
-            "{ui_amount_string} {token.ticker}"
+            "{ui_amount_string} {token.read().ticker}"
         }
     }
 }
@@ -64,7 +64,7 @@ pub fn TokenWithdrawForm(
             }
             Col {
                 class: "w-full px-4",
-                Fee {}
+                Fee { transaction: tx }
             }
             SubmitButton {
                 title: "Submit".to_string(),
@@ -1,7 +1,95 @@
 use crate::components::*;
-use crate::hooks::{APP_FEE, JITO_TIP_AMOUNT, SOLANA_BASE_FEE};
+#[cfg(not(feature = "web"))]
+use crate::gateway::jito::{jito_config, JITO_TIP_ADDRESSES};
+use crate::gateway::{compute_budget::estimate_fees, GatewayResult};
+#[cfg(not(feature = "web"))]
+use crate::hooks::JITO_TIP_AMOUNT;
+use crate::hooks::{APP_FEE, APP_FEE_ACCOUNT, SOLANA_BASE_FEE};
 use dioxus::prelude::*;
-use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::{
+    message::VersionedMessage, native_token::lamports_to_sol, pubkey::Pubkey, system_program,
+    transaction::VersionedTransaction,
+};
+
+/// Fees a transaction will be charged, in lamports.
+struct FeeBreakdown {
+    base_fee: u64,
+    /// Priority fee at the transaction's compute unit limit. The limit is sized to the units
+    /// the transaction consumes when it is submitted, so this is the most it can cost.
+    priority_fee: u64,
+    app_fee: u64,
+    jito_fee: u64,
+}
+
+impl FeeBreakdown {
+    /// The fees a typical app transaction is charged, for when no transaction is available.
+    fn default_estimate() -> Self {
+        #[cfg(feature = "web")]
+        let jito_fee = 0;
+        // Tips are only added to transactions submitted as bundles
+        #[cfg(not(feature = "web"))]
+        let jito_fee = if jito_config().enabled {
+            JITO_TIP_AMOUNT
+        } else {
+            0
+        };
+        FeeBreakdown {
+            base_fee: SOLANA_BASE_FEE,
+            priority_fee: 0,
+            app_fee: APP_FEE,
+            jito_fee,
+        }
+    }
+
+    fn from_transaction(tx: &VersionedTransaction) -> Self {
+        let (base_fee, priority_fee) = estimate_fees(&tx.message);
+        let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
+        let mut app_fee = 0;
+        let mut jito_fee = 0;
+        for (recipient, lamports) in system_transfers(&tx.message) {
+            if recipient == app_fee_account {
+                app_fee += lamports;
+            }
+            #[cfg(not(feature = "web"))]
+            if JITO_TIP_ADDRESSES.contains(&recipient) {
+                jito_fee += lamports;
+            }
+        }
+        FeeBreakdown {
+            base_fee,
+            priority_fee,
+            app_fee,
+            jito_fee,
+        }
+    }
+
+    fn total(&self) -> u64 {
+        self.base_fee + self.priority_fee + self.app_fee + self.jito_fee
+    }
+}
+
+/// Returns the recipient and amount of each system transfer in the message.
+fn system_transfers(message: &VersionedMessage) -> Vec<(Pubkey, u64)> {
+    const TRANSFER_TAG: u32 = 2;
+    let keys = message.static_account_keys();
+    message
+        .instructions()
+        .iter()
+        .filter(|ix| keys.get(ix.program_id_index as usize) == Some(&system_program::ID))
+        .filter_map(|ix| {
+            if ix.data.len() < 12 {
+                return None;
+            }
+            let tag = u32::from_le_bytes(ix.data[..4].try_into().ok()?);
+            if tag != TRANSFER_TAG {
+                return None;
+            }
+            let lamports = u64::from_le_bytes(ix.data[4..12].try_into().ok()?);
+            let recipient = keys.get(*ix.accounts.get(1)? as usize)?;
+            Some((*recipient, lamports))
+        })
+        .collect()
+}
 
 fn format_fee(amount: f64) -> String {
     // Remove trailing zeros after decimal point
@@ -10,16 +98,17 @@ fn format_fee(amount: f64) -> String {
 }
 
 #[component]
-pub fn Fee() -> Element {
+pub fn Fee(transaction: Option<Resource<GatewayResult<VersionedTransaction>>>) -> Element {
     let mut is_open = use_signal(|| false);
-    let base_fee = lamports_to_sol(SOLANA_BASE_FEE);
-    let app_fee = lamports_to_sol(APP_FEE);
-    let jito_fee = lamports_to_sol(JITO_TIP_AMOUNT);
-
-    #[cfg(feature = "web")]
-    let total_fee = base_fee + app_fee;
-    #[cfg(not(feature = "web"))]
-    let total_fee = base_fee + app_fee + jito_fee;
+    let fees = match transaction.as_ref().and_then(|tx| tx.cloned()) {
+        Some(Ok(tx)) => FeeBreakdown::from_transaction(&tx),
+        _ => FeeBreakdown::default_estimate(),
+    };
+    let base_fee = lamports_to_sol(fees.base_fee);
+    let priority_fee = lamports_to_sol(fees.priority_fee);
+    let app_fee = lamports_to_sol(fees.app_fee);
+    let jito_fee = lamports_to_sol(fees.jito_fee);
+    let total_fee = lamports_to_sol(fees.total());
 
     let max_height = if *is_open.read() {
         "max-h-32"
@@ -50,8 +139,10 @@ pub fn Fee() -> Element {
                 }
                 span {
                     class: "text-elements-midEmphasis font-medium text-right",
-                    {
-                        format!("{} SOL", format_fee(total_fee))
+                    if fees.priority_fee > 0 {
+                        "Up to {format_fee(total_fee)} SOL"
+                    } else {
+                        "{format_fee(total_fee)} SOL"
                     }
                 }
             }
@@ -76,6 +167,13 @@ pub fn Fee() -> Element {
                         span { class: "font-medium text-sm text-elements-lowEmphasis text-left", "Solana fee" }
                         span { class: "font-medium text-sm text-elements-lowEmphasis text-right", "{format_fee(base_fee)}" }
                     }
+                    if fees.priority_fee > 0 {
+                        Row {
+                            class: "w-full justify-between",
+                            span { class: "font-medium text-sm text-elements-lowEmphasis text-left", "Max priority fee" }
+                            span { class: "font-medium text-sm text-elements-lowEmphasis text-right", "{format_fee(priority_fee)}" }
+                        }
+                    }
                 }
             }
         }
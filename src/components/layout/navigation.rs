@@ -5,7 +5,7 @@ use crate::{
         BoltIcon, CircleStackIcon, Col, DexscreenIcon, DiscordIcon, GithubIcon, GlobeIcon,
         MegaphoneIcon, OreWordmarkIcon, Row, WalletAdapter, XIcon,
     },
-    hooks::use_wallet_drawer_state,
+    hooks::{use_wallet_drawer_state, use_wss_connection_state, WssConnectionState},
     route::Route,
 };
 
@@ -26,7 +26,32 @@ pub(crate) fn AppNavBar(tabs: bool) -> Element {
                         TabBar {}
                     }
                 }
-                WalletAdapter {}
+                Row {
+                    gap: 4,
+                    ConnectionStatus {}
+                    WalletAdapter {}
+                }
+            }
+        }
+    }
+}
+
+/// Shown while live updates are paused by a dropped connection.
+fn ConnectionStatus() -> Element {
+    let connection_state = use_wss_connection_state();
+    let WssConnectionState::Reconnecting(_) = *connection_state.read() else {
+        return rsx! {};
+    };
+    rsx! {
+        Row {
+            class: "hidden sm:flex my-auto text-xs text-elements-lowEmphasis",
+            gap: 2,
+            span {
+                class: "my-auto h-2 w-2 rounded-full bg-elements-gold animate-pulse",
+            }
+            span {
+                class: "my-auto",
+                "Reconnecting..."
             }
         }
     }
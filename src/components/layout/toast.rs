@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 
-use crate::components::{Col, Row, Spinner, TransactionStatus, Updater};
+use crate::components::{retry_transaction, Col, Row, Spinner, TransactionStatus, Updater};
 use crate::gateway::GatewayError;
 use crate::hooks::use_transaction_status;
 use crate::time::Duration;
@@ -49,18 +49,18 @@ pub fn ToastDisplay() -> Element {
                         }
                     }
                 }
-                // Remove option from transcation status & sub gatewayerror::Uknown for None into TransactionStatus::Error
                 TransactionStatus::Error(err) => {
-                    // Display different error messages based on the GatewayError type
-                    let error_message = match err {
-                        GatewayError::InsufficientSOL => "Insufficient SOL balance",
-                        GatewayError::Unknown => "Transaction failed",
-                        _ => "Transaction failed"
+                    // Fall back to a generic title for errors without a specific message
+                    let error_message = match &err {
+                        GatewayError::Unknown => "Transaction failed".to_string(),
+                        err => err.user_message(),
                     };
-                    
                     rsx! {
                         Col { class: "{toast_class} border-l-4 border-red-500",
                             span { class: "{title_class} my-auto", "{error_message}" }
+                            if err.is_retriable() {
+                                span { class: "{detail_class}", "Please try again." }
+                            }
                         }
                     }
                 }
@@ -68,18 +68,34 @@ pub fn ToastDisplay() -> Element {
                     rsx! {
                         Col { class: "{toast_class} border-l-4 border-red-500",
                             span { class: "{title_class} my-auto", "Transaction timed out" }
-                            span { class: "{detail_class}", "Please try again." }
+                            button {
+                                class: "{detail_class} text-left hover:text-elements-highEmphasis hover:cursor-pointer",
+                                onclick: move |_| retry_transaction(),
+                                "Retry"
+                            }
                         }
                     }
                 }
-                TransactionStatus::Sending(_attempt) => {
+                TransactionStatus::Sending(progress) => {
+                    let detail = if progress.processed {
+                        "Processed, waiting for confirmation".to_string()
+                    } else if progress.attempt > 1 {
+                        format!("Rebroadcasting (attempt {})", progress.attempt)
+                    } else {
+                        "Waiting for confirmation".to_string()
+                    };
+                    let slots = match progress.slots {
+                        0 => String::new(),
+                        1 => " (1 slot)".to_string(),
+                        slots => format!(" ({slots} slots)"),
+                    };
                     rsx! {
                         Col { class: "{toast_class} border-l-4 border-blue-500",
                             Row { gap: 2,
                                 Spinner { class: "my-auto" }
                                 span { class: "{title_class} my-auto", "Submitting transaction" }
                             }
-                            span { class: "{detail_class} ml-8", "Waiting for confirmation..." }
+                            span { class: "{detail_class} ml-8", "{detail}{slots}..." }
                         }
                     }
                 }
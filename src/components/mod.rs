@@ -3,6 +3,7 @@ mod common;
 mod docs;
 mod forms;
 mod layout;
+mod settings;
 mod submit_transaction;
 mod tables;
 mod token_value;
@@ -14,6 +15,7 @@ pub use common::*;
 pub use docs::*;
 pub use forms::*;
 pub use layout::*;
+pub use settings::*;
 pub use submit_transaction::*;
 pub use tables::*;
 pub use token_value::*;
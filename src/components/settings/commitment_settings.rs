@@ -0,0 +1,44 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::*,
+    gateway::commitment::{set_commitment, Commitment},
+    hooks::use_commitment,
+};
+
+pub fn CommitmentSettings() -> Element {
+    let mut commitment = use_commitment();
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Commitment"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "How settled on-chain data must be before balances and stakes reflect it. Processed updates fastest but may be rolled back, while finalized can no longer change. Values that are not yet finalized are marked as unconfirmed."
+            }
+            Row {
+                class: "w-full flex-wrap",
+                gap: 2,
+                for c in Commitment::ALL {
+                    button {
+                        key: "{c.label()}",
+                        class: if *commitment.read() == c {
+                            "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                        } else {
+                            "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                        },
+                        onclick: move |_| {
+                            set_commitment(c);
+                            commitment.set(c);
+                        },
+                        "{c.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
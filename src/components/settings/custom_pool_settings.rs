@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    components::*,
+    config::{Pool, LISTED_POOLS},
+    gateway::{pool::PoolGateway, GatewayError},
+    hooks::{
+        add_custom_pool, remove_custom_pool, use_custom_pools, use_gateway, use_selected_pool,
+        use_wallet, Wallet,
+    },
+};
+
+pub fn CustomPoolSettings() -> Element {
+    let custom_pools = use_custom_pools();
+    let selected_pool = use_selected_pool();
+    let wallet = use_wallet();
+    let mut err = use_signal::<Option<String>>(|| None);
+    let mut checking = use_signal(|| false);
+
+    // New pool form
+    let mut name = use_signal(|| "".to_string());
+    let mut pool_url = use_signal(|| "".to_string());
+    let mut pool_address = use_signal(|| "".to_string());
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Custom pools"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "Add a pool by its server url and on-chain address. The pool server and your membership are checked before it is saved."
+            }
+            Col {
+                class: "w-full elevated elevated-border rounded-xl",
+                for pool in custom_pools.cloned() {
+                    Row {
+                        key: "{pool.address}",
+                        class: "w-full p-4 border-b border-gray-800 justify-between items-center",
+                        gap: 4,
+                        Col {
+                            class: "overflow-hidden",
+                            gap: 1,
+                            Row {
+                                gap: 2,
+                                span {
+                                    class: "text-elements-highEmphasis font-semibold",
+                                    "{pool.name}"
+                                }
+                                if selected_pool.read().address == pool.address {
+                                    span {
+                                        class: "text-elements-gold text-xs my-auto",
+                                        "Selected"
+                                    }
+                                }
+                            }
+                            span {
+                                class: "text-elements-lowEmphasis text-xs truncate",
+                                "{pool.url}"
+                            }
+                            span {
+                                class: "text-elements-lowEmphasis text-xs truncate",
+                                "{pool.address}"
+                            }
+                        }
+                        button {
+                            class: "controls-secondary rounded-full px-3 h-8 text-xs hover:cursor-pointer",
+                            onclick: move |_| remove_custom_pool(custom_pools, selected_pool, pool.address),
+                            "Remove"
+                        }
+                    }
+                }
+                Col {
+                    class: "w-full p-4",
+                    gap: 2,
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "Name",
+                        value: name.cloned(),
+                        oninput: move |e: FormEvent| name.set(e.value()),
+                    }
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "Pool url (https://...)",
+                        value: pool_url.cloned(),
+                        oninput: move |e: FormEvent| pool_url.set(e.value()),
+                    }
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "Pool address",
+                        value: pool_address.cloned(),
+                        oninput: move |e: FormEvent| pool_address.set(e.value()),
+                    }
+                    if let Some(err) = err.cloned() {
+                        span {
+                            class: "text-red-500 text-sm",
+                            "{err}"
+                        }
+                    }
+                    button {
+                        class: "h-12 w-full rounded-full controls-primary hover:cursor-pointer",
+                        disabled: *checking.read() || pool_url.read().is_empty() || pool_address.read().is_empty(),
+                        onclick: move |_| {
+                            let url = pool_url.read().trim().trim_end_matches('/').to_string();
+                            if !is_url::is_url(&url) {
+                                err.set(Some("Invalid pool url".to_string()));
+                                return;
+                            }
+                            let Ok(address) = Pubkey::from_str(pool_address.read().trim()) else {
+                                err.set(Some("Invalid pool address".to_string()));
+                                return;
+                            };
+                            let is_duplicate = LISTED_POOLS
+                                .iter()
+                                .chain(custom_pools.read().iter())
+                                .any(|pool| pool.address == address || pool.url == url);
+                            if is_duplicate {
+                                err.set(Some("This pool has already been added".to_string()));
+                                return;
+                            }
+                            let Wallet::Connected(authority) = *wallet.read() else {
+                                err.set(Some(GatewayError::WalletDisconnected.user_message()));
+                                return;
+                            };
+                            let pool_name = if name.read().trim().is_empty() {
+                                format!("Custom pool {}", custom_pools.read().len() + 1)
+                            } else {
+                                name.read().trim().to_string()
+                            };
+                            checking.set(true);
+                            err.set(None);
+                            spawn(async move {
+                                // Only save pools that pass the compatibility handshake
+                                match use_gateway().check_pool(authority, address, url.clone()).await {
+                                    Ok(()) => {
+                                        add_custom_pool(
+                                            custom_pools,
+                                            Pool {
+                                                address,
+                                                url,
+                                                name: pool_name,
+                                                description: "".to_string(),
+                                                image: "".to_string(),
+                                            },
+                                        );
+                                        pool_url.set("".to_string());
+                                        pool_address.set("".to_string());
+                                        name.set("".to_string());
+                                    }
+                                    Err(e) => err.set(Some(e.user_message())),
+                                }
+                                checking.set(false);
+                            });
+                        },
+                        if *checking.read() {
+                            "Checking pool..."
+                        } else {
+                            "Add pool"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::*,
+    gateway::endpoints::{Endpoint, EndpointHealth},
+    hooks::{save_endpoints, use_active_endpoint, use_endpoint_health, use_endpoints},
+};
+
+pub fn EndpointSettings() -> Element {
+    let endpoints = use_endpoints();
+    let health = use_endpoint_health();
+    let active = use_active_endpoint();
+    let mut err = use_signal::<Option<String>>(|| None);
+
+    // New endpoint form
+    let mut name = use_signal(|| "".to_string());
+    let mut rpc_url = use_signal(|| "".to_string());
+    let mut wss_url = use_signal(|| "".to_string());
+
+    let mut update = move |new_endpoints: Vec<Endpoint>| match save_endpoints(new_endpoints) {
+        Ok(_) => err.set(None),
+        Err(e) => err.set(Some(e.user_message())),
+    };
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "RPC endpoints"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "Requests are sent to the first healthy endpoint. If it errors or falls behind, the app fails over to the next one."
+            }
+            Col {
+                class: "w-full elevated elevated-border rounded-xl",
+                for (i, endpoint) in endpoints.cloned().into_iter().enumerate() {
+                    Row {
+                        key: "{endpoint.rpc_url}",
+                        class: "w-full p-4 border-b border-gray-800 justify-between items-center",
+                        gap: 4,
+                        Col {
+                            class: "overflow-hidden",
+                            gap: 1,
+                            Row {
+                                gap: 2,
+                                span {
+                                    class: "text-elements-highEmphasis font-semibold",
+                                    "{endpoint.name}"
+                                }
+                                if *active.read() == endpoint {
+                                    span {
+                                        class: "text-elements-gold text-xs my-auto",
+                                        "Active"
+                                    }
+                                }
+                            }
+                            span {
+                                class: "text-elements-lowEmphasis text-xs truncate",
+                                "{endpoint.rpc_url}"
+                            }
+                            EndpointHealthLabel {
+                                health: health.read().get(i).cloned().unwrap_or(EndpointHealth::Unknown)
+                            }
+                        }
+                        Row {
+                            gap: 2,
+                            if i > 0 {
+                                button {
+                                    class: "controls-secondary rounded-full px-3 h-8 text-xs hover:cursor-pointer",
+                                    onclick: move |_| {
+                                        let mut new_endpoints = endpoints.cloned();
+                                        new_endpoints.swap(i, i - 1);
+                                        update(new_endpoints);
+                                    },
+                                    "Prefer"
+                                }
+                            }
+                            if endpoints.read().len() > 1 {
+                                button {
+                                    class: "controls-secondary rounded-full px-3 h-8 text-xs hover:cursor-pointer",
+                                    onclick: move |_| {
+                                        let mut new_endpoints = endpoints.cloned();
+                                        new_endpoints.remove(i);
+                                        update(new_endpoints);
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+                Col {
+                    class: "w-full p-4",
+                    gap: 2,
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "Name",
+                        value: name.cloned(),
+                        oninput: move |e: FormEvent| name.set(e.value()),
+                    }
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "RPC url (https://...)",
+                        value: rpc_url.cloned(),
+                        oninput: move |e: FormEvent| rpc_url.set(e.value()),
+                    }
+                    input {
+                        class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                        placeholder: "WebSocket url (wss://...)",
+                        value: wss_url.cloned(),
+                        oninput: move |e: FormEvent| wss_url.set(e.value()),
+                    }
+                    if let Some(err) = err.cloned() {
+                        span {
+                            class: "text-red-500 text-sm",
+                            "{err}"
+                        }
+                    }
+                    button {
+                        class: "h-12 w-full rounded-full controls-primary hover:cursor-pointer",
+                        disabled: rpc_url.read().is_empty() || wss_url.read().is_empty(),
+                        onclick: move |_| {
+                            let name = if name.read().is_empty() {
+                                format!("Endpoint {}", endpoints.read().len() + 1)
+                            } else {
+                                name.cloned()
+                            };
+                            let mut new_endpoints = endpoints.cloned();
+                            new_endpoints.push(Endpoint {
+                                name,
+                                rpc_url: rpc_url.cloned().trim().to_string(),
+                                wss_url: wss_url.cloned().trim().to_string(),
+                            });
+                            update(new_endpoints);
+                            if err.read().is_none() {
+                                name.set("".to_string());
+                                rpc_url.set("".to_string());
+                                wss_url.set("".to_string());
+                            }
+                        },
+                        "Add endpoint"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn EndpointHealthLabel(health: EndpointHealth) -> Element {
+    let (class, label) = match health {
+        EndpointHealth::Unknown => ("text-elements-lowEmphasis", "Checking...".to_string()),
+        EndpointHealth::Healthy(slot) => {
+            ("text-elements-green", format!("Healthy · slot {}", slot))
+        }
+        EndpointHealth::Lagging(slot) => ("text-elements-gold", format!("Lagging · slot {}", slot)),
+        EndpointHealth::Unreachable => ("text-red-500", "Unreachable".to_string()),
+    };
+    rsx! {
+        span {
+            class: "text-xs {class}",
+            "{label}"
+        }
+    }
+}
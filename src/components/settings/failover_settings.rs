@@ -0,0 +1,38 @@
+use dioxus::prelude::*;
+
+use crate::{components::*, hooks::use_pool_failover};
+
+pub fn FailoverSettings() -> Element {
+    let mut failover = use_pool_failover();
+    let allow_register = failover.allow_register();
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Pool failover"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "If the pool you mine with keeps failing, the miner switches to the next healthy pool and returns once your pool recovers. Allow joining to fail over to pools you are not yet a member of, which requires a transaction."
+            }
+            Row {
+                class: "w-full flex-wrap",
+                gap: 2,
+                for (label, value) in [("Member pools only", false), ("Allow joining", true)] {
+                    button {
+                        key: "{label}",
+                        class: if *allow_register.read() == value {
+                            "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                        } else {
+                            "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                        },
+                        onclick: move |_| failover.set_allow_register(value),
+                        "{label}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::*,
+    gateway::jito::{jito_config, set_jito_config, JitoConfig},
+};
+
+pub fn JitoSettings() -> Element {
+    let mut config = use_signal(jito_config);
+    let mut block_engine_url = use_signal(|| jito_config().block_engine_url);
+    let mut err = use_signal::<Option<String>>(|| None);
+
+    let mut update = move |new_config: JitoConfig| match set_jito_config(new_config.clone()) {
+        Ok(_) => {
+            err.set(None);
+            config.set(new_config);
+        }
+        Err(e) => err.set(Some(e.user_message())),
+    };
+
+    let enabled = config.read().enabled;
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Jito bundles"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "Transactions are sent to a Jito block engine as bundles so the tip they carry is honored. If a bundle does not land, the transaction is sent through the RPC instead."
+            }
+            Row {
+                class: "w-full",
+                gap: 2,
+                button {
+                    class: if enabled {
+                        "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                    } else {
+                        "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                    },
+                    onclick: move |_| {
+                        update(JitoConfig {
+                            enabled: true,
+                            ..config.cloned()
+                        })
+                    },
+                    "Enabled"
+                }
+                button {
+                    class: if !enabled {
+                        "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                    } else {
+                        "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                    },
+                    onclick: move |_| {
+                        update(JitoConfig {
+                            enabled: false,
+                            ..config.cloned()
+                        })
+                    },
+                    "Disabled"
+                }
+            }
+            Row {
+                class: "w-full items-center elevated elevated-border rounded-full px-4",
+                gap: 2,
+                input {
+                    class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                    placeholder: "Block engine url (https://...)",
+                    value: block_engine_url.cloned(),
+                    oninput: move |e: FormEvent| block_engine_url.set(e.value()),
+                }
+                button {
+                    class: "text-elements-gold text-xs text-nowrap hover:cursor-pointer",
+                    disabled: *block_engine_url.read() == config.read().block_engine_url,
+                    onclick: move |_| {
+                        update(JitoConfig {
+                            block_engine_url: block_engine_url.cloned().trim().to_string(),
+                            ..config.cloned()
+                        })
+                    },
+                    "Save"
+                }
+            }
+            if let Some(err) = err.cloned() {
+                span {
+                    class: "text-red-500 text-sm",
+                    "{err}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod commitment_settings;
+mod custom_pool_settings;
+mod endpoint_settings;
+mod failover_settings;
+#[cfg(not(feature = "web"))]
+mod jito_settings;
+mod poll_interval_settings;
+mod priority_fee_settings;
+
+pub use commitment_settings::*;
+pub use custom_pool_settings::*;
+pub use endpoint_settings::*;
+pub use failover_settings::*;
+#[cfg(not(feature = "web"))]
+pub use jito_settings::*;
+pub use poll_interval_settings::*;
+pub use priority_fee_settings::*;
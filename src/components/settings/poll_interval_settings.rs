@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::*,
+    hooks::{poll_interval_secs, set_poll_interval_secs},
+};
+
+pub fn PollIntervalSettings() -> Element {
+    let mut interval = use_signal(poll_interval_secs);
+
+    let intervals = [5, 10, 30, 60];
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Offline refresh"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "Balances and stakes update live over a WebSocket. If it is blocked or disconnected, they are refreshed on this interval instead."
+            }
+            Row {
+                class: "w-full flex-wrap",
+                gap: 2,
+                for secs in intervals {
+                    button {
+                        key: "{secs}",
+                        class: if *interval.read() == secs {
+                            "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                        } else {
+                            "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                        },
+                        onclick: move |_| {
+                            set_poll_interval_secs(secs);
+                            interval.set(secs);
+                        },
+                        "{secs}s"
+                    }
+                }
+            }
+        }
+    }
+}
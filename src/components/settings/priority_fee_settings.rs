@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::*,
+    gateway::priority_fee::{priority_fee_tier, set_priority_fee_tier, PriorityFeeTier},
+};
+
+pub fn PriorityFeeSettings() -> Element {
+    let mut tier = use_signal(priority_fee_tier);
+    let mut custom_fee = use_signal(|| match priority_fee_tier() {
+        PriorityFeeTier::Custom(fee) => fee.to_string(),
+        _ => "".to_string(),
+    });
+
+    let mut update = move |new_tier: PriorityFeeTier| {
+        set_priority_fee_tier(new_tier);
+        tier.set(new_tier);
+    };
+
+    let tiers = [
+        PriorityFeeTier::None,
+        PriorityFeeTier::Low,
+        PriorityFeeTier::Medium,
+        PriorityFeeTier::High,
+    ];
+
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 4,
+            Subheading {
+                title: "Priority fee"
+            }
+            span {
+                class: "text-elements-lowEmphasis text-sm",
+                "Higher priority fees help transactions land when the network is congested. Fees are estimated from recent activity on the accounts each transaction touches."
+            }
+            Row {
+                class: "w-full flex-wrap",
+                gap: 2,
+                for t in tiers {
+                    button {
+                        key: "{t.label()}",
+                        class: if *tier.read() == t {
+                            "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                        } else {
+                            "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                        },
+                        onclick: move |_| update(t),
+                        "{t.label()}"
+                    }
+                }
+            }
+            Row {
+                class: "w-full items-center elevated elevated-border rounded-full px-4",
+                gap: 2,
+                input {
+                    class: "h-10 outline-none w-full bg-transparent text-elements-highEmphasis",
+                    placeholder: "Custom (microlamports per compute unit)",
+                    value: custom_fee.cloned(),
+                    oninput: move |e: FormEvent| {
+                        let value = e.value();
+                        if let Ok(fee) = value.parse::<u64>() {
+                            update(PriorityFeeTier::Custom(fee));
+                        }
+                        custom_fee.set(value);
+                    },
+                }
+                if let PriorityFeeTier::Custom(_) = *tier.read() {
+                    span {
+                        class: "text-elements-gold text-xs text-nowrap",
+                        "Active"
+                    }
+                }
+            }
+        }
+    }
+}
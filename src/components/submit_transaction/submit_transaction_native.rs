@@ -2,17 +2,21 @@ use dioxus::prelude::*;
 use ore_types::request::TransactionType;
 use solana_sdk::{
     hash::Hash,
-    instruction::InstructionError,
     signature::Keypair,
-    transaction::{Transaction, TransactionError, VersionedTransaction},
+    transaction::{Transaction, VersionedTransaction},
 };
 
 use crate::{
     components::*,
-    gateway::{solana::SolanaGateway, GatewayResult, GatewayError, NativeRpc, Rpc},
+    gateway::{
+        compute_budget::size_compute_unit_limit, program_error::decode_simulation_error,
+        ConfirmationProgress, Gateway, GatewayError, GatewayResult, Rpc,
+    },
     hooks::{use_gateway, use_transaction_status},
 };
 
+use super::transaction_status::set_last_transaction;
+
 pub async fn sign_transaction_partial(mut tx: Transaction) -> GatewayResult<(Transaction, Hash)> {
     let gateway = use_gateway();
     let wallet_data = crate::hooks::use_wallet_native::get()?;
@@ -22,8 +26,9 @@ pub async fn sign_transaction_partial(mut tx: Transaction) -> GatewayResult<(Tra
     Ok((tx, hash))
 }
 
-pub fn submit_transaction(tx: VersionedTransaction, _tx_type: TransactionType) {
+pub fn submit_transaction(tx: VersionedTransaction, tx_type: TransactionType) {
     let mut transaction_status = use_transaction_status();
+    set_last_transaction(&tx, &tx_type);
     spawn(async move {
         transaction_status.set(Some(TransactionStatus::Waiting));
         // get signer
@@ -31,9 +36,11 @@ pub fn submit_transaction(tx: VersionedTransaction, _tx_type: TransactionType) {
             Ok(wallet_data) => {
                 let signer = wallet_data.0;
                 let gateway = use_gateway();
-                transaction_status.set(Some(TransactionStatus::Sending(0)));
+                transaction_status.set(Some(TransactionStatus::Sending(
+                    ConfirmationProgress::default(),
+                )));
                 // sign
-                if let Err(err) = sign_submit_confirm(&gateway.rpc, &signer.creator, tx).await {
+                if let Err(err) = sign_submit_confirm(&gateway, &signer.creator, tx).await {
                     // log::error!("{:?}", err);
                     let err_clone = err.clone();
                     transaction_status.set(Some(TransactionStatus::Error(err_clone)));
@@ -47,45 +54,34 @@ pub fn submit_transaction(tx: VersionedTransaction, _tx_type: TransactionType) {
     });
 }
 
-async fn sign(
-    rpc: &NativeRpc,
-    signer: &Keypair,
-    tx: VersionedTransaction,
-) -> GatewayResult<(VersionedTransaction, Hash)> {
-    let hash = rpc.get_latest_blockhash().await?;
-    let mut message = tx.message;
-    message.set_recent_blockhash(hash);
-    let signed = VersionedTransaction::try_new(message, &[signer])?;
-    Ok((signed, hash))
-}
-
-async fn sign_submit_confirm(
-    rpc: &NativeRpc,
+async fn sign_submit_confirm<R: Rpc>(
+    gateway: &Gateway<R>,
     signer: &Keypair,
-    tx: VersionedTransaction,
+    mut tx: VersionedTransaction,
 ) -> GatewayResult<()> {
     let mut transaction_status = use_transaction_status();
-    // sign
-    let (signed, _) = sign(rpc, signer, tx).await?;
-    
-    // simulate transaction to check for insufficient funds
-    let simulated_tx = rpc.simulate_transaction(&signed).await?;        
+    let rpc = &gateway.rpc;
+    // set blockhash
+    let (hash, last_valid_block_height) = rpc.get_latest_blockhash_with_height().await?;
+    tx.message.set_recent_blockhash(hash);
 
-    if let Some(err) = simulated_tx.err {
-        if let TransactionError::InstructionError(index, instruction_error) = err {
-            if matches!(instruction_error, InstructionError::Custom(1)) {
-                return Err(GatewayError::InsufficientSOL);
-            }
-        }
+    // simulate transaction to size compute units and check why it would fail
+    let simulated_tx = size_compute_unit_limit(rpc, &mut tx).await?;
+    if let Some(err) = decode_simulation_error(&tx, &simulated_tx) {
+        return Err(err);
     }
-    // submit
-    let sig = rpc.send_transaction(&signed).await?;
-    // confirm
-    let confirmed = rpc.confirm_signature(sig).await;
-    if confirmed.is_ok() {
-        transaction_status.set(Some(TransactionStatus::Done(sig)));
-    } else {
-        transaction_status.set(Some(TransactionStatus::Timeout));
+    // sign and submit as a bundle, or rebroadcast through the rpc until confirmed or expired
+    let confirmed = gateway
+        .send_and_confirm_bundle(signer, tx, last_valid_block_height, |progress| {
+            transaction_status.set(Some(TransactionStatus::Sending(progress)));
+        })
+        .await;
+    match confirmed {
+        Ok(sig) => transaction_status.set(Some(TransactionStatus::Done(sig))),
+        Err(GatewayError::TransactionTimeout) => {
+            transaction_status.set(Some(TransactionStatus::Timeout))
+        }
+        Err(err) => return Err(err),
     }
     Ok(())
 }
@@ -4,17 +4,22 @@ use js_sys::Date;
 use ore_types::request::{AppId, TransactionEvent, TransactionType};
 use solana_sdk::{
     hash::Hash,
-    instruction::InstructionError,
     message::VersionedMessage,
-    transaction::{Transaction, TransactionError, VersionedTransaction},
+    transaction::{Transaction, VersionedTransaction},
 };
 
 use crate::{
     components::*,
-    gateway::{ore::OreGateway, solana::SolanaGateway, GatewayError, GatewayResult, Rpc},
+    gateway::{
+        compute_budget::size_compute_unit_limit, ore::OreGateway,
+        program_error::decode_simulation_error, solana::SolanaGateway, ConfirmationProgress,
+        GatewayError, GatewayResult, Rpc,
+    },
     hooks::{use_gateway, use_transaction_status},
 };
 
+use super::transaction_status::set_last_transaction;
+
 pub async fn sign_transaction_partial(mut tx: Transaction) -> GatewayResult<(Transaction, Hash)> {
     // set blockhash
     let gateway = use_gateway();
@@ -55,6 +60,7 @@ pub async fn sign_transaction_partial(mut tx: Transaction) -> GatewayResult<(Tra
 /// signs and submits
 pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType) {
     let mut transaction_status = use_transaction_status();
+    set_last_transaction(&tx, &tx_type);
 
     spawn(async move {
         // Set blockhash
@@ -69,6 +75,20 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
                     message.recent_blockhash = hash;
                 }
             }
+
+            // Size compute unit limit before handing the transaction to the wallet,
+            // and stop early if the simulation shows it would fail
+            match size_compute_unit_limit(&gateway.rpc, &mut tx).await {
+                Ok(simulation) => {
+                    if let Some(err) = decode_simulation_error(&tx, &simulation) {
+                        transaction_status.set(Some(TransactionStatus::Error(err)));
+                        return;
+                    }
+                }
+                Err(err) => {
+                    log::error!("Failed to size compute unit limit: {:?}", err);
+                }
+            }
         }
 
         // Build eval command for wallet signing
@@ -99,39 +119,28 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
                                 let gateway = use_gateway();
                                 let decode_res = base64::engine::general_purpose::STANDARD
                                     .decode(string)
-                                    .ok();
-                                let decode_res = decode_res.and_then(|buffer| {
-                                    bincode::deserialize::<VersionedTransaction>(&buffer).ok()
-                                });
-
-                                // let tx_for_simulation = tx.clone();
-
-                                // // Simulate transaction to check for insufficient funds
-                                // if let Ok(simulated_tx) = gateway.rpc.simulate_transaction(&tx_for_simulation).await {
-                                //     if let Some(err) = simulated_tx.err {
-                                //         if let TransactionError::InstructionError(_index, instruction_error) = err {
-                                //             if matches!(instruction_error, InstructionError::Custom(1)) {
-                                //                 transaction_status.set(Some(TransactionStatus::Error(GatewayError::InsufficientSOL)));
-                                //                 return;
-                                //             }
-                                //         }
-                                //     }
-                                // }
+                                    .map_err(|err| GatewayError::from(anyhow::anyhow!(err)))
+                                    .and_then(|buffer| {
+                                        bincode::deserialize::<VersionedTransaction>(&buffer)
+                                            .map_err(|_| GatewayError::BincodeDeserialize)
+                                    });
 
                                 // Send transaction to rpc
-                                transaction_status.set(Some(TransactionStatus::Sending(0)));
+                                transaction_status.set(Some(TransactionStatus::Sending(
+                                    ConfirmationProgress::default(),
+                                )));
                                 let rpc_res = match decode_res {
-                                    Some(tx) => gateway.rpc.send_transaction(&tx).await.ok(),
-                                    None => {
+                                    Ok(tx) => gateway.rpc.send_transaction(&tx).await,
+                                    Err(err) => {
                                         log::info!("error decoding tx");
-                                        None
+                                        Err(err)
                                     }
                                 };
 
                                 let signer = tx.message.static_account_keys()[0];
                                 let timestamp = (Date::now() / 1000.0) as i64;
                                 // Write transaction to db (API)
-                                if let Some(sig) = rpc_res {
+                                if let Ok(sig) = rpc_res {
                                     match gateway
                                         .log_transaction_event(TransactionEvent {
                                             sig,
@@ -157,21 +166,29 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
 
                                 // Confirm transaction
                                 match rpc_res {
-                                    Some(sig) => {
-                                        let confirmed = gateway.rpc.confirm_signature(sig).await;
-                                        if confirmed.is_ok() {
-                                            transaction_status
-                                                .set(Some(TransactionStatus::Done(sig)));
-                                        } else {
-                                            transaction_status
-                                                .set(Some(TransactionStatus::Timeout));
+                                    Ok(sig) => {
+                                        let confirmed = gateway
+                                            .rpc
+                                            .confirm_signature(sig, |progress| {
+                                                transaction_status.set(Some(
+                                                    TransactionStatus::Sending(progress),
+                                                ));
+                                            })
+                                            .await;
+                                        match confirmed {
+                                            Ok(sig) => transaction_status
+                                                .set(Some(TransactionStatus::Done(sig))),
+                                            Err(GatewayError::TransactionTimeout) => {
+                                                transaction_status
+                                                    .set(Some(TransactionStatus::Timeout))
+                                            }
+                                            Err(err) => transaction_status
+                                                .set(Some(TransactionStatus::Error(err))),
                                         }
                                     }
-                                    None => {
+                                    Err(err) => {
                                         log::info!("error sending tx");
-                                        transaction_status.set(Some(TransactionStatus::Error(
-                                            GatewayError::Unknown,
-                                        )))
+                                        transaction_status.set(Some(TransactionStatus::Error(err)))
                                     }
                                 }
                             }
@@ -182,13 +199,15 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
                             }
                             Err(err) => {
                                 log::error!("error signing transaction: {}", err);
-                                transaction_status
-                                    .set(Some(TransactionStatus::Error(GatewayError::Unknown)))
+                                transaction_status.set(Some(TransactionStatus::Error(
+                                    GatewayError::SignatureFailed,
+                                )))
                             }
                             _ => {
                                 log::error!("unrecognized signing response");
-                                transaction_status
-                                    .set(Some(TransactionStatus::Error(GatewayError::Unknown)))
+                                transaction_status.set(Some(TransactionStatus::Error(
+                                    GatewayError::SignatureFailed,
+                                )))
                             }
                         };
                     }
@@ -196,8 +215,9 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
                     // Process eval errors
                     Err(err) => {
                         log::error!("error executing wallet signing script: {}", err);
-                        transaction_status
-                            .set(Some(TransactionStatus::Error(GatewayError::Unknown)))
+                        transaction_status.set(Some(TransactionStatus::Error(
+                            anyhow::anyhow!("{}", err).into(),
+                        )))
                     }
                 }
             }
@@ -205,7 +225,9 @@ pub fn submit_transaction(mut tx: VersionedTransaction, tx_type: TransactionType
             // Process serialization errors
             Err(err) => {
                 log::error!("err serializing tx: {}", err);
-                transaction_status.set(Some(TransactionStatus::Error(GatewayError::Unknown)))
+                transaction_status.set(Some(TransactionStatus::Error(
+                    GatewayError::BincodeSerialize,
+                )))
             }
         };
     });
@@ -1,7 +1,16 @@
-use std::fmt::Display;
+use std::{cell::RefCell, fmt::Display};
 
-use crate::gateway::GatewayError;
-use solana_sdk::signature::Signature;
+use crate::gateway::{ConfirmationProgress, GatewayError};
+use ore_types::request::TransactionType;
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+
+use super::submit_transaction;
+
+thread_local! {
+    /// The last transaction handed to `submit_transaction`, before it was signed.
+    static LAST_TRANSACTION: RefCell<Option<(VersionedTransaction, TransactionType)>> =
+        const { RefCell::new(None) };
+}
 
 #[derive(PartialEq, Clone, Debug)]
 pub enum TransactionStatus {
@@ -9,12 +18,31 @@ pub enum TransactionStatus {
     Denied,
     Error(GatewayError),
     Timeout,
-    Sending(u8),
+    Sending(ConfirmationProgress),
     Done(Signature),
 }
 
 impl Display for TransactionStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            TransactionStatus::Waiting => write!(f, "Waiting for signature"),
+            TransactionStatus::Denied => write!(f, "Signature denied"),
+            TransactionStatus::Error(err) => write!(f, "{}", err.user_message()),
+            TransactionStatus::Timeout => write!(f, "Transaction timed out"),
+            TransactionStatus::Sending(_) => write!(f, "Submitting transaction"),
+            TransactionStatus::Done(_) => write!(f, "Transaction confirmed"),
+        }
+    }
+}
+
+/// Records a transaction so it can be retried if its submission times out.
+pub(super) fn set_last_transaction(tx: &VersionedTransaction, tx_type: &TransactionType) {
+    LAST_TRANSACTION.with(|last| *last.borrow_mut() = Some((tx.clone(), tx_type.clone())));
+}
+
+/// Resubmits the last transaction, signing it again with a fresh blockhash.
+pub fn retry_transaction() {
+    if let Some((tx, tx_type)) = LAST_TRANSACTION.with(|last| last.borrow().clone()) {
+        submit_transaction(tx, tx_type);
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     components::*,
-    hooks::{use_miner_events, MiningEvent},
+    hooks::{use_miner_events, MinerEvent, MiningEvent, PoolSwitchEvent, PoolSwitchReason},
 };
 use chrono::{DateTime, Local};
 use dioxus::prelude::*;
@@ -56,8 +56,17 @@ pub fn MineTable() -> Element {
                     },
                     rows: rsx! {
                         for event in events {
-                            MineTableRow {
-                                event: event.clone()
+                            match event {
+                                MinerEvent::Mining(event) => rsx! {
+                                    MineTableRow {
+                                        event: event.clone()
+                                    }
+                                },
+                                MinerEvent::PoolSwitch(event) => rsx! {
+                                    PoolSwitchRow {
+                                        event: event.clone()
+                                    }
+                                },
                             }
                         }
                     }
@@ -98,6 +107,36 @@ fn MineTableRow(event: MiningEvent) -> Element {
     }
 }
 
+#[component]
+fn PoolSwitchRow(event: PoolSwitchEvent) -> Element {
+    let reason = match event.reason {
+        PoolSwitchReason::Failover => format!("{} is failing", event.from),
+        PoolSwitchReason::Recovered => format!("{} recovered", event.to),
+    };
+    rsx! {
+        Row {
+            class: "w-full min-w-max px-5 sm:px-3 h-20 justify-between",
+            Col {
+                class: "w-min sm:w-56 text-nowrap my-auto",
+                span {
+                    class: "font-medium",
+                    "Switched to {event.to}"
+                }
+                span {
+                    class: "text-xs font-medium text-elements-lowEmphasis",
+                    "{reason}"
+                }
+            }
+            span {
+                class: "flex text-right w-56 my-auto justify-end",
+                MineTableRowDate {
+                    timestamp: event.timestamp
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn MineTableRowDate(timestamp: u64) -> Element {
     let datetime = DateTime::from_timestamp(timestamp as i64, 0)
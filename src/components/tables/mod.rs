@@ -1,9 +1,11 @@
 mod mine_table;
+mod pool_table;
 mod stake_table;
 mod table;
 mod titled_row;
 
 pub use mine_table::*;
+pub use pool_table::*;
 pub use stake_table::*;
 pub use table::*;
 pub use titled_row::*;
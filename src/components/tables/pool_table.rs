@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use ore_api::consts::TOKEN_DECIMALS;
+use ore_pool_api::state::member_pda;
+
+use crate::{
+    components::*,
+    config::Pool,
+    gateway::{pool::PoolGateway, GatewayError},
+    hooks::{
+        select_pool, use_gateway, use_miner_is_active, use_miner_status, use_pools,
+        use_selected_pool, use_wallet, MinerStatus, Wallet,
+    },
+    solana::spl_token::amount_to_ui_amount_string,
+};
+
+pub fn PoolTable() -> Element {
+    let pools = use_pools();
+    let mut info_hidden = use_signal(|| true);
+    rsx! {
+        Col {
+            class: "w-full",
+            gap: 0,
+            button {
+                class: "flex flex-row gap-2 px-5 w-min sm:px-8 group hover:cursor-pointer",
+                onclick: move |_| info_hidden.set(!info_hidden.cloned()),
+                Subheading {
+                    class: "my-auto",
+                    title: "Pools"
+                }
+                InfoIcon {
+                    class: "h-4 w-4 shrink-0 text-elements-lowEmphasis group-hover:text-elements-highEmphasis transition-all duration-300 ease-in-out my-auto",
+                }
+            }
+            InfoText {
+                class: "text-wrap px-5 sm:px-8",
+                text: "Select the pool to mine with. Your choice is saved on this device. Custom pools can be added in settings.",
+                hidden: info_hidden,
+            }
+            Table {
+                class: "mt-4 mx-0 sm:mx-8",
+                header: rsx! {
+                    TableHeader {
+                        left: "Pool",
+                        right_1: "Latency",
+                        right_2: "Member",
+                        right_3: "Balance",
+                        help_left: "Mining pools you can join.",
+                        help_right_1: "Round trip time of a request to the pool server.",
+                        help_right_2: "Whether you are registered as a member of the pool.",
+                        help_right_3: "Amount of ORE you may claim from the pool.",
+                        help_hidden: info_hidden,
+                    }
+                },
+                rows: rsx! {
+                    for pool in pools.cloned() {
+                        PoolTableRow {
+                            key: "{pool.address}",
+                            pool: pool
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PoolTableRow(pool: Pool) -> Element {
+    let selected_pool = use_selected_pool();
+    let mut miner_status = use_miner_status();
+    let is_active = use_miner_is_active();
+    let wallet = use_wallet();
+
+    // Probe the pool server
+    let pool_url = pool.url.clone();
+    let latency = use_resource(move || {
+        let pool_url = pool_url.clone();
+        async move { use_gateway().get_pool_latency(pool_url).await }
+    });
+
+    // Fetch the member account for this pool
+    let pool_address = pool.address;
+    let member = use_resource(move || async move {
+        let Wallet::Connected(authority) = *wallet.read() else {
+            return Err(GatewayError::AccountNotFound);
+        };
+        use_gateway()
+            .get_member(member_pda(authority, pool_address).0)
+            .await
+    });
+
+    let is_selected = selected_pool.read().address == pool.address;
+    let is_connected = matches!(*wallet.read(), Wallet::Connected(_));
+
+    rsx! {
+        TableRowButton {
+            onclick: {
+                let pool = pool.clone();
+                move |_| {
+                    if selected_pool.peek().address == pool.address {
+                        return;
+                    }
+                    // Membership is per pool, so the miner must register again after switching
+                    if *is_active.peek() {
+                        miner_status.set(MinerStatus::Stopped);
+                    }
+                    select_pool(selected_pool, pool.clone());
+                }
+            },
+            left: rsx! {
+                Row {
+                    class: "my-auto",
+                    gap: 4,
+                    if pool.image.is_empty() {
+                        img {
+                            class: "w-8 h-8 rounded-full shrink-0 my-auto",
+                            src: asset!("/public/icon.png"),
+                        }
+                    } else {
+                        img {
+                            class: "w-8 h-8 rounded-full shrink-0 my-auto",
+                            src: "{pool.image}",
+                        }
+                    }
+                    Row {
+                        class: "my-auto",
+                        gap: 2,
+                        span {
+                            class: "font-semibold my-auto",
+                            "{pool.name}"
+                        }
+                        if is_selected {
+                            span {
+                                class: "font-medium my-auto text-xs text-elements-gold px-1.5 py-0 rounded bg-elements-gold/10",
+                                "Selected"
+                            }
+                        }
+                    }
+                }
+            },
+            right_1: rsx! {
+                match latency.cloned() {
+                    None => rsx! { TableCellLoading {} },
+                    Some(Ok(latency)) => rsx! {
+                        span {
+                            class: "font-medium",
+                            "{latency.as_millis()} ms"
+                        }
+                    },
+                    Some(Err(_)) => rsx! {
+                        span {
+                            class: "font-medium text-elements-lowEmphasis",
+                            "Unreachable"
+                        }
+                    },
+                }
+            },
+            right_2: rsx! {
+                match member.cloned() {
+                    _ if !is_connected => rsx! { NullValue {} },
+                    None => rsx! { TableCellLoading {} },
+                    Some(Ok(_)) => rsx! {
+                        span {
+                            class: "font-medium",
+                            "Registered"
+                        }
+                    },
+                    Some(Err(GatewayError::AccountNotFound)) => rsx! {
+                        span {
+                            class: "font-medium text-elements-lowEmphasis",
+                            "Not registered"
+                        }
+                    },
+                    Some(Err(_)) => rsx! { NullValue {} },
+                }
+            },
+            right_3: rsx! {
+                if let Some(Ok(member)) = member.cloned() {
+                    OreValue {
+                        ui_amount_string: amount_to_ui_amount_string(member.balance, TOKEN_DECIMALS),
+                        with_decimal_units: true,
+                        size: TokenValueSize::Small,
+                    }
+                } else {
+                    NullValue {}
+                }
+            },
+        }
+    }
+}
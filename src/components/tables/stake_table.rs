@@ -10,7 +10,7 @@ use crate::{
     gateway::GatewayResult,
     hooks::{
         use_all_liquidity_pairs, use_boost_apr, use_boost_tvl, use_boost_wss, use_claimable_yield,
-        use_stake_wss,
+        use_stake_unconfirmed, use_stake_wss,
     },
     route::Route,
     solana::spl_token::amount_to_ui_amount_string,
@@ -150,6 +150,7 @@ fn StakeTableRow(
 
 #[component]
 fn IdleTableRowTitle(token: Token, stake: Signal<GatewayResult<Stake>>) -> Element {
+    let unconfirmed = use_stake_unconfirmed(token.mint);
     let balance = use_resource(move || async move {
         let Ok(stake) = stake.cloned() else {
             return None;
@@ -183,9 +184,15 @@ fn IdleTableRowTitle(token: Token, stake: Signal<GatewayResult<Stake>>) -> Eleme
                     }
                 }
                 if let Some(Some(balance)) = balance.cloned() {
-                    span {
-                        class: "font-medium text-xs text-elements-lowEmphasis",
-                        "{balance} ORE"
+                    Row {
+                        gap: 2,
+                        span {
+                            class: "font-medium text-xs text-elements-lowEmphasis",
+                            "{balance} ORE"
+                        }
+                        if unconfirmed() {
+                            UnconfirmedValue {}
+                        }
                     }
                 }
             }
@@ -201,6 +208,7 @@ fn StakeTableRowTitle(
     liquidity_pair: Resource<GatewayResult<LiquidityPair>>,
 ) -> Element {
     let token = LISTED_TOKENS.get(&pair_mint).cloned();
+    let unconfirmed = use_stake_unconfirmed(boost_meta.lp_mint);
 
     let token_balances = use_resource(move || async move {
         let Some(Ok(liquidity_pair)) = liquidity_pair.cloned() else {
@@ -261,9 +269,15 @@ fn StakeTableRowTitle(
                     }
                 }
                 if let Some(Some(token_balances)) = token_balances.cloned() {
-                    span {
-                        class: "font-medium text-xs text-elements-lowEmphasis",
-                        "{token_balances}"
+                    Row {
+                        gap: 2,
+                        span {
+                            class: "font-medium text-xs text-elements-lowEmphasis",
+                            "{token_balances}"
+                        }
+                        if unconfirmed() {
+                            UnconfirmedValue {}
+                        }
                     }
                 }
             }
@@ -3,6 +3,7 @@ mod null_value;
 mod ore_value;
 mod pair_value;
 mod token_value;
+mod unconfirmed_value;
 mod usd_value;
 mod utils;
 
@@ -11,5 +12,6 @@ pub use null_value::*;
 pub use ore_value::*;
 pub use pair_value::*;
 pub use token_value::*;
+pub use unconfirmed_value::*;
 pub use usd_value::*;
 pub use utils::*;
@@ -0,0 +1,12 @@
+use dioxus::prelude::*;
+
+/// Marks a value derived from data that is not yet finalized and may still change.
+pub fn UnconfirmedValue() -> Element {
+    rsx! {
+        span {
+            class: "font-medium my-auto text-xs text-elements-gold px-1.5 py-0 rounded bg-elements-gold/10",
+            title: "This value is not yet finalized and may change.",
+            "Unconfirmed"
+        }
+    }
+}
@@ -5,7 +5,8 @@ use dioxus_sdk::clipboard::use_clipboard;
 
 use super::token_list::TokenList;
 use crate::components::{
-    ChevronDownIcon, Col, CopyIcon, GlobeIcon, PaperAirplaneIcon, PlusIcon, Row, WalletPicker,
+    ChevronDownIcon, CogIcon, Col, CopyIcon, GlobeIcon, PaperAirplaneIcon, PlusIcon, Row,
+    WalletPicker,
 };
 use crate::hooks::{use_wallet, use_wallet_native, Wallet};
 use crate::route::Route;
@@ -186,6 +187,23 @@ pub fn WalletDrawer(on_close: EventHandler<MouseEvent>) -> Element {
                             "Transfer"
                         }
                     }
+                    Col {
+                        class: "items-center",
+                        gap: 2,
+                        Link {
+                            class: "flex items-center justify-center w-12 h-12 rounded-full controls-secondary",
+                            onclick: move |e: MouseEvent| {
+                                e.stop_propagation();
+                                on_close.call(e);
+                            },
+                            to: Route::Settings {},
+                            CogIcon { class: "h-5" }
+                        }
+                        span {
+                            class: "text-xs whitespace-nowrap text-elements-lowEmphasis",
+                            "Settings"
+                        }
+                    }
                 }
             }
 
@@ -1,4 +1,4 @@
-use crate::components::{Col, CopyIcon, GlobeIcon, PaperAirplaneIcon, Row};
+use crate::components::{CogIcon, Col, CopyIcon, GlobeIcon, PaperAirplaneIcon, Row};
 use crate::hooks::{use_wallet, Wallet};
 use crate::route::Route;
 use dioxus::document::eval;
@@ -178,6 +178,23 @@ pub fn WalletDrawer(on_close: EventHandler<MouseEvent>, wallet_remount: Signal<b
                             "Transfer"
                         }
                     }
+                    Col {
+                        class: "items-center",
+                        gap: 2,
+                        Link {
+                            class: "flex items-center justify-center w-12 h-12 rounded-full controls-secondary",
+                            onclick: move |e: MouseEvent| {
+                                e.stop_propagation();
+                                on_close.call(e);
+                            },
+                            to: Route::Settings {},
+                            CogIcon { class: "h-5" }
+                        }
+                        span {
+                            class: "text-xs whitespace-nowrap text-elements-lowEmphasis",
+                            "Settings"
+                        }
+                    }
                 }
             }
 
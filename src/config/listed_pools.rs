@@ -1,10 +1,10 @@
 use std::ops::Index;
 
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use steel::Pubkey;
 
-use crate::utils::deserialize_pubkey;
+use crate::utils::{deserialize_pubkey, serialize_pubkey};
 
 pub static LISTED_POOLS: Lazy<Vec<Pool>> = Lazy::new(|| {
     // Read the YAML file at compile time
@@ -19,9 +19,12 @@ pub static LISTED_POOLS: Lazy<Vec<Pool>> = Lazy::new(|| {
 pub const FIRST_POOL: Lazy<Pool> = Lazy::new(|| LISTED_POOLS.index(0).clone());
 pub const SECOND_POOL: Lazy<Pool> = Lazy::new(|| LISTED_POOLS.index(1).clone());
 
-#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pool {
-    #[serde(deserialize_with = "deserialize_pubkey")]
+    #[serde(
+        serialize_with = "serialize_pubkey",
+        deserialize_with = "deserialize_pubkey"
+    )]
     pub address: Pubkey,
     pub url: String,
     pub name: String,
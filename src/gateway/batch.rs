@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+
+use futures::channel::oneshot;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use super::{
+    commitment::{commitment, Commitment},
+    Gateway, GatewayError, GatewayResult, Rpc,
+};
+
+/// Maximum number of accounts the `getMultipleAccounts` rpc method accepts per call.
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Time the first read of a batch waits for concurrent reads to join it.
+const BATCH_WINDOW_MS: u64 = 5;
+
+type PendingRead = (
+    Pubkey,
+    Commitment,
+    oneshot::Sender<GatewayResult<Option<Account>>>,
+);
+
+thread_local! {
+    /// Reads waiting on the batch currently being collected, if any.
+    static PENDING: RefCell<Option<Vec<PendingRead>>> = const { RefCell::new(None) };
+}
+
+/// Releases the pending batch if the read collecting it is dropped before flushing,
+/// so the reads that joined it fall back to fetching on their own.
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        PENDING.with(|pending| pending.borrow_mut().take());
+    }
+}
+
+impl<R: Rpc> Gateway<R> {
+    /// Fetches an account at the app-wide commitment, coalescing concurrent reads made
+    /// within the same tick into a single `getMultipleAccounts` call.
+    pub async fn get_account(&self, pubkey: &Pubkey) -> GatewayResult<Account> {
+        self.get_account_with_commitment(pubkey, commitment()).await
+    }
+
+    /// Fetches an account at the given commitment, batched with concurrent reads.
+    pub async fn get_account_with_commitment(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Account> {
+        let (sender, receiver) = oneshot::channel();
+        let is_leader = PENDING.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            match pending.as_mut() {
+                Some(reads) => {
+                    reads.push((*pubkey, commitment, sender));
+                    false
+                }
+                None => {
+                    *pending = Some(vec![(*pubkey, commitment, sender)]);
+                    true
+                }
+            }
+        });
+        if is_leader {
+            let guard = BatchGuard;
+            async_std::task::sleep(std::time::Duration::from_millis(BATCH_WINDOW_MS)).await;
+            let reads = PENDING
+                .with(|pending| pending.borrow_mut().take())
+                .unwrap_or_default();
+            std::mem::forget(guard);
+            self.flush(reads).await;
+        }
+        let res = match receiver.await {
+            Ok(res) => res,
+            Err(_) => {
+                // The batch was abandoned before it was sent
+                self.rpc
+                    .get_multiple_accounts(&[*pubkey], commitment)
+                    .await
+                    .map(|mut accounts| accounts.pop().flatten())
+            }
+        };
+        res.map_err(|err| err.with_account(*pubkey))?
+            .ok_or(GatewayError::AccountNotFound)
+    }
+
+    /// Fetches the data of an account, batched with concurrent reads.
+    pub async fn get_account_data(&self, pubkey: &Pubkey) -> GatewayResult<Vec<u8>> {
+        Ok(self.get_account(pubkey).await?.data)
+    }
+
+    /// Fetches any number of accounts at the app-wide commitment, chunked to the rpc limit.
+    pub async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        self.get_multiple_accounts_with_commitment(pubkeys, commitment())
+            .await
+    }
+
+    /// Fetches any number of accounts at the given commitment, chunked to the rpc limit.
+    pub async fn get_multiple_accounts_with_commitment(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+        for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            accounts.extend(self.rpc.get_multiple_accounts(chunk, commitment).await?);
+        }
+        Ok(accounts)
+    }
+
+    async fn flush(&self, mut reads: Vec<PendingRead>) {
+        // Reads at different commitments cannot share a request
+        while let Some(&(_, commitment, _)) = reads.first() {
+            let (batch, rest): (Vec<_>, Vec<_>) = reads
+                .into_iter()
+                .partition(|(_, read_commitment, _)| *read_commitment == commitment);
+            reads = rest;
+            self.flush_commitment(batch, commitment).await;
+        }
+    }
+
+    async fn flush_commitment(&self, reads: Vec<PendingRead>, commitment: Commitment) {
+        let mut pubkeys = reads
+            .iter()
+            .map(|(pubkey, _, _)| *pubkey)
+            .collect::<Vec<_>>();
+        pubkeys.sort_unstable();
+        pubkeys.dedup();
+        let res = self
+            .get_multiple_accounts_with_commitment(&pubkeys, commitment)
+            .await;
+        for (pubkey, _, sender) in reads {
+            let res = match &res {
+                Ok(accounts) => Ok(pubkeys
+                    .binary_search(&pubkey)
+                    .ok()
+                    .and_then(|i| accounts.get(i).cloned().flatten())),
+                Err(err) => Err(err.clone()),
+            };
+            sender.send(res).ok();
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Mutex;
+
+use cached::{Cached, SizedCache};
+use dioxus::prelude::spawn_forever;
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::time::{Duration, Instant};
+
+use super::{Gateway, GatewayResult, Rpc};
+
+/// Maximum number of responses held in the cache.
+const CACHE_SIZE: usize = 256;
+
+/// How long a cached response is served for a given endpoint.
+#[derive(Clone, Copy, Debug)]
+pub struct CachePolicy {
+    /// Age after which a response is refreshed in the background.
+    pub ttl: Duration,
+    /// Age after which a response is no longer served and is refetched before returning.
+    pub max_stale: Duration,
+}
+
+impl CachePolicy {
+    pub const fn new(ttl_secs: u64, max_stale_secs: u64) -> Self {
+        CachePolicy {
+            ttl: Duration::from_secs(ttl_secs),
+            max_stale: Duration::from_secs(max_stale_secs),
+        }
+    }
+}
+
+pub const BOOST_YIELD_POLICY: CachePolicy = CachePolicy::new(600, 3_600);
+pub const ORE_HOLDERS_POLICY: CachePolicy = CachePolicy::new(300, 3_600);
+pub const METEORA_POOL_METRICS_POLICY: CachePolicy = CachePolicy::new(60, 600);
+pub const KAMINO_STRATEGY_METRICS_POLICY: CachePolicy = CachePolicy::new(60, 600);
+
+struct CacheEntry {
+    value: Value,
+    fetched_at: Instant,
+    refreshing: bool,
+}
+
+static CACHE: Lazy<Mutex<SizedCache<String, CacheEntry>>> =
+    Lazy::new(|| Mutex::new(SizedCache::with_size(CACHE_SIZE)));
+
+/// Drops every cached response, so the next read of each endpoint is fetched fresh.
+pub fn invalidate_cache() {
+    CACHE.lock().unwrap().cache_clear();
+}
+
+enum Lookup {
+    Fresh(Value),
+    Stale(Value),
+    Miss,
+}
+
+fn lookup(url: &str, policy: CachePolicy) -> Lookup {
+    let mut cache = CACHE.lock().unwrap();
+    let Some(entry) = cache.cache_get_mut(&url.to_string()) else {
+        return Lookup::Miss;
+    };
+    let age = entry.fetched_at.elapsed();
+    if age < policy.ttl {
+        Lookup::Fresh(entry.value.clone())
+    } else if age < policy.max_stale {
+        if entry.refreshing {
+            // A refresh is already in flight
+            return Lookup::Fresh(entry.value.clone());
+        }
+        entry.refreshing = true;
+        Lookup::Stale(entry.value.clone())
+    } else {
+        Lookup::Miss
+    }
+}
+
+fn store(url: String, value: Value) {
+    CACHE.lock().unwrap().cache_set(
+        url,
+        CacheEntry {
+            value,
+            fetched_at: Instant::now(),
+            refreshing: false,
+        },
+    );
+}
+
+/// Fetches a json response, caching it only if it parses as `T`.
+async fn fetch<T: DeserializeOwned>(http: &reqwest::Client, url: &str) -> GatewayResult<T> {
+    let resp = http.get(url).send().await?;
+    let value = resp.json::<Value>().await?;
+    let parsed = serde_json::from_value::<T>(value.clone())?;
+    store(url.to_string(), value);
+    Ok(parsed)
+}
+
+impl<R: Rpc> Gateway<R> {
+    /// Fetches a json response, serving it from the cache while it is younger than the policy ttl.
+    ///
+    /// Stale responses are returned immediately and refreshed in the background.
+    pub async fn get_cached<T: DeserializeOwned + 'static>(
+        &self,
+        url: String,
+        policy: CachePolicy,
+    ) -> GatewayResult<T> {
+        match lookup(&url, policy) {
+            Lookup::Fresh(value) => Ok(serde_json::from_value(value)?),
+            Lookup::Stale(value) => {
+                let http = self.http.clone();
+                let refresh_url = url.clone();
+                let task = spawn_forever(async move {
+                    if let Err(err) = fetch::<T>(&http, &refresh_url).await {
+                        log::error!("Failed to refresh {}: {:?}", refresh_url, err);
+                        // Let the next read retry the refresh
+                        if let Some(entry) = CACHE.lock().unwrap().cache_get_mut(&refresh_url) {
+                            entry.refreshing = false;
+                        }
+                    }
+                });
+                if task.is_some() {
+                    Ok(serde_json::from_value(value)?)
+                } else {
+                    fetch(&self.http, &url).await
+                }
+            }
+            Lookup::Miss => fetch(&self.http, &url).await,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use solana_sdk::commitment_config::CommitmentConfig;
+
+use crate::utils::{get_value, set_key_value};
+
+const COMMITMENT_KEY: &str = "commitment";
+
+/// How settled the chain state behind a read or subscription must be.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+    /// The latest block seen by the node, which may still be rolled back.
+    Processed,
+    /// Voted on by a supermajority of the cluster.
+    #[default]
+    Confirmed,
+    /// Rooted by the cluster and can no longer be rolled back.
+    Finalized,
+}
+
+impl Commitment {
+    pub const ALL: [Commitment; 3] = [
+        Commitment::Processed,
+        Commitment::Confirmed,
+        Commitment::Finalized,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Commitment::Processed => "Processed",
+            Commitment::Confirmed => "Confirmed",
+            Commitment::Finalized => "Finalized",
+        }
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        *self == Commitment::Finalized
+    }
+}
+
+impl From<Commitment> for CommitmentConfig {
+    fn from(commitment: Commitment) -> Self {
+        match commitment {
+            Commitment::Processed => CommitmentConfig::processed(),
+            Commitment::Confirmed => CommitmentConfig::confirmed(),
+            Commitment::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+}
+
+static COMMITMENT: Lazy<Mutex<Commitment>> =
+    Lazy::new(|| Mutex::new(get_value::<Commitment>(COMMITMENT_KEY).unwrap_or_default()));
+
+/// Returns the app-wide default commitment for reads and subscriptions.
+pub fn commitment() -> Commitment {
+    *COMMITMENT.lock().unwrap()
+}
+
+/// Sets the app-wide default commitment and persists it.
+pub fn set_commitment(commitment: Commitment) {
+    if let Err(err) = set_key_value(COMMITMENT_KEY, &commitment) {
+        log::error!("Failed to persist commitment: {:?}", err);
+    }
+    *COMMITMENT.lock().unwrap() = commitment;
+}
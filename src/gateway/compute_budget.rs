@@ -0,0 +1,194 @@
+use solana_sdk::{
+    compute_budget::{self, ComputeBudgetInstruction},
+    instruction::{CompiledInstruction, Instruction},
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    transaction::VersionedTransaction,
+};
+
+use super::{GatewayResult, Rpc, SimulateTransactionResponse};
+
+/// Compute unit limit the runtime assigns to each instruction when none is requested.
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+/// Maximum compute unit limit of a transaction.
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+/// Percentage added on top of simulated compute units to absorb variance between simulation and execution.
+const COMPUTE_UNIT_MARGIN_PERCENT: u64 = 15;
+/// Flat number of compute units added on top of simulated compute units.
+const COMPUTE_UNIT_MARGIN: u64 = 1_000;
+/// Base fee charged per required signature.
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+const SET_COMPUTE_UNIT_LIMIT_TAG: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE_TAG: u8 = 3;
+
+/// Compute budget requested by a transaction message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    pub unit_limit: Option<u32>,
+    /// Compute unit price, in microlamports.
+    pub unit_price: Option<u64>,
+}
+
+impl ComputeBudget {
+    pub fn from_message(message: &VersionedMessage) -> Self {
+        let keys = message.static_account_keys();
+        let mut budget = ComputeBudget::default();
+        for ix in message.instructions() {
+            if keys.get(ix.program_id_index as usize) != Some(&compute_budget::ID) {
+                continue;
+            }
+            match ix.data.split_first() {
+                Some((&SET_COMPUTE_UNIT_LIMIT_TAG, rest)) if rest.len() >= 4 => {
+                    budget.unit_limit = Some(u32::from_le_bytes(rest[..4].try_into().unwrap()));
+                }
+                Some((&SET_COMPUTE_UNIT_PRICE_TAG, rest)) if rest.len() >= 8 => {
+                    budget.unit_price = Some(u64::from_le_bytes(rest[..8].try_into().unwrap()));
+                }
+                _ => {}
+            }
+        }
+        budget
+    }
+
+    /// Compute unit limit the runtime will enforce for the message.
+    pub fn effective_unit_limit(&self, message: &VersionedMessage) -> u32 {
+        self.unit_limit
+            .unwrap_or_else(|| {
+                let keys = message.static_account_keys();
+                let count = message
+                    .instructions()
+                    .iter()
+                    .filter(|ix| {
+                        keys.get(ix.program_id_index as usize) != Some(&compute_budget::ID)
+                    })
+                    .count() as u32;
+                count.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+            })
+            .min(MAX_COMPUTE_UNIT_LIMIT)
+    }
+}
+
+/// Returns the priority fee, in lamports, for a compute unit limit and price.
+pub fn priority_fee_lamports(unit_limit: u32, unit_price: u64) -> u64 {
+    (unit_limit as u128 * unit_price as u128).div_ceil(1_000_000) as u64
+}
+
+/// Returns the base and priority fees, in lamports, the message will be charged.
+pub fn estimate_fees(message: &VersionedMessage) -> (u64, u64) {
+    let budget = ComputeBudget::from_message(message);
+    let base_fee = message.header().num_required_signatures as u64 * LAMPORTS_PER_SIGNATURE;
+    let priority_fee = priority_fee_lamports(
+        budget.effective_unit_limit(message),
+        budget.unit_price.unwrap_or(0),
+    );
+    (base_fee, priority_fee)
+}
+
+/// Returns the accounts written to by a set of instructions, for fee estimation.
+pub fn writable_accounts(ixs: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts = vec![];
+    for ix in ixs {
+        for meta in ix.accounts.iter() {
+            if meta.is_writable && !accounts.contains(&meta.pubkey) {
+                accounts.push(meta.pubkey);
+            }
+        }
+    }
+    accounts
+}
+
+/// Inserts a `SetComputeUnitPrice` instruction, replacing any existing one.
+pub fn set_compute_unit_price(ixs: &mut Vec<Instruction>, unit_price: u64) {
+    ixs.retain(|ix| {
+        !(ix.program_id == compute_budget::ID
+            && ix.data.first() == Some(&SET_COMPUTE_UNIT_PRICE_TAG))
+    });
+    let index = ixs
+        .iter()
+        .take_while(|ix| ix.program_id == compute_budget::ID)
+        .count();
+    ixs.insert(
+        index,
+        ComputeBudgetInstruction::set_compute_unit_price(unit_price),
+    );
+}
+
+/// Simulates a transaction and rewrites its compute unit limit to fit the units it consumes.
+///
+/// The transaction is simulated at the maximum limit so heavy transactions are not cut short,
+/// and the original limit is restored if the simulation fails. The compute unit price is zeroed
+/// for the simulation, so the fee at the maximum limit can't exceed the payer's balance, and
+/// restored afterwards. The transaction must already carry a recent blockhash, and is left
+/// unsigned. Returns the simulation result.
+pub async fn size_compute_unit_limit<R: Rpc>(
+    rpc: &R,
+    tx: &mut VersionedTransaction,
+) -> GatewayResult<SimulateTransactionResponse> {
+    let budget = ComputeBudget::from_message(&tx.message);
+    let Some(original_limit) = budget.unit_limit else {
+        // Without a limit instruction to rewrite, simulate as-is
+        return rpc.simulate_transaction(tx).await;
+    };
+    set_compute_unit_limit(&mut tx.message, MAX_COMPUTE_UNIT_LIMIT);
+    if budget.unit_price.is_some() {
+        set_compiled_compute_unit_price(&mut tx.message, 0);
+    }
+    let simulation = rpc.simulate_transaction(tx).await;
+    if let Some(unit_price) = budget.unit_price {
+        set_compiled_compute_unit_price(&mut tx.message, unit_price);
+    }
+    let limit = match &simulation {
+        Ok(SimulateTransactionResponse {
+            err: None,
+            units_consumed: Some(units_consumed),
+            ..
+        }) => {
+            let limit =
+                units_consumed * (100 + COMPUTE_UNIT_MARGIN_PERCENT) / 100 + COMPUTE_UNIT_MARGIN;
+            limit.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+        }
+        _ => original_limit,
+    };
+    set_compute_unit_limit(&mut tx.message, limit);
+    simulation
+}
+
+/// Rewrites the `SetComputeUnitLimit` instruction of a compiled message in place.
+fn set_compute_unit_limit(message: &mut VersionedMessage, unit_limit: u32) {
+    rewrite_compute_budget_instruction(
+        message,
+        SET_COMPUTE_UNIT_LIMIT_TAG,
+        &unit_limit.to_le_bytes(),
+    );
+}
+
+/// Rewrites the `SetComputeUnitPrice` instruction of a compiled message in place.
+fn set_compiled_compute_unit_price(message: &mut VersionedMessage, unit_price: u64) {
+    rewrite_compute_budget_instruction(
+        message,
+        SET_COMPUTE_UNIT_PRICE_TAG,
+        &unit_price.to_le_bytes(),
+    );
+}
+
+fn rewrite_compute_budget_instruction(message: &mut VersionedMessage, tag: u8, value: &[u8]) {
+    let program_id_index = message
+        .static_account_keys()
+        .iter()
+        .position(|key| *key == compute_budget::ID);
+    let Some(program_id_index) = program_id_index else {
+        return;
+    };
+    let instructions: &mut Vec<CompiledInstruction> = match message {
+        VersionedMessage::Legacy(message) => &mut message.instructions,
+        VersionedMessage::V0(message) => &mut message.instructions,
+    };
+    for ix in instructions.iter_mut() {
+        if ix.program_id_index as usize == program_id_index && ix.data.first() == Some(&tag) {
+            let mut data = vec![tag];
+            data.extend_from_slice(value);
+            ix.data = data;
+        }
+    }
+}
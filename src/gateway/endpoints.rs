@@ -0,0 +1,256 @@
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::{get_value, set_key_value};
+
+use super::{GatewayError, GatewayResult};
+
+const ENDPOINTS_KEY: &str = "rpc_endpoints";
+
+/// Number of consecutive failures before failing over to the next endpoint.
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Number of slots an endpoint may trail the highest observed slot before it is considered unhealthy.
+pub const MAX_SLOT_LAG: u64 = 50;
+
+#[cfg(feature = "web")]
+const DEFAULT_RPC_URL: &str =
+    "https://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
+#[cfg(not(feature = "web"))]
+const DEFAULT_RPC_URL: &str =
+    "https://rpc.ironforge.network/mainnet?apiKey=01JR0QT6CKAF608VC1DKSE1KC3";
+
+#[cfg(feature = "web")]
+const DEFAULT_WSS_URL: &str =
+    "wss://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
+#[cfg(not(feature = "web"))]
+const DEFAULT_WSS_URL: &str =
+    "wss://rpc.ironforge.network/mainnet?apiKey=01JR0QT6CKAF608VC1DKSE1KC3";
+
+/// An RPC endpoint and its paired websocket endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Endpoint {
+    pub name: String,
+    pub rpc_url: String,
+    pub wss_url: String,
+}
+
+impl Default for Endpoint {
+    fn default() -> Self {
+        Endpoint {
+            name: "Ironforge".to_string(),
+            rpc_url: DEFAULT_RPC_URL.to_string(),
+            wss_url: DEFAULT_WSS_URL.to_string(),
+        }
+    }
+}
+
+/// Health of an endpoint as observed by the most recent health check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EndpointHealth {
+    Unknown,
+    Healthy(u64),
+    Lagging(u64),
+    Unreachable,
+}
+
+struct EndpointRegistry {
+    endpoints: Vec<Endpoint>,
+    health: Vec<EndpointHealth>,
+    active: usize,
+    failures: u32,
+}
+
+impl EndpointRegistry {
+    fn load() -> Self {
+        let endpoints = get_value::<Vec<Endpoint>>(ENDPOINTS_KEY)
+            .ok()
+            .filter(|endpoints| !endpoints.is_empty())
+            .unwrap_or_else(|| vec![Endpoint::default()]);
+        let health = vec![EndpointHealth::Unknown; endpoints.len()];
+        EndpointRegistry {
+            endpoints,
+            health,
+            active: 0,
+            failures: 0,
+        }
+    }
+
+    fn active(&self) -> &Endpoint {
+        &self.endpoints[self.active]
+    }
+
+    fn failover(&mut self) {
+        if self.endpoints.len() < 2 {
+            self.failures = 0;
+            return;
+        }
+        // Prefer the next endpoint that is not known to be unhealthy
+        let len = self.endpoints.len();
+        let next = (1..len)
+            .map(|offset| (self.active + offset) % len)
+            .find(|i| {
+                !matches!(
+                    self.health[*i],
+                    EndpointHealth::Lagging(_) | EndpointHealth::Unreachable
+                )
+            })
+            .unwrap_or((self.active + 1) % len);
+        log::warn!(
+            "Failing over from {} to {}",
+            self.endpoints[self.active].name,
+            self.endpoints[next].name
+        );
+        self.active = next;
+        self.failures = 0;
+    }
+}
+
+static REGISTRY: Lazy<Mutex<EndpointRegistry>> = Lazy::new(|| Mutex::new(EndpointRegistry::load()));
+
+/// Returns the configured endpoints, in order of preference.
+pub fn endpoints() -> Vec<Endpoint> {
+    REGISTRY.lock().unwrap().endpoints.clone()
+}
+
+/// Returns the last observed health of each configured endpoint.
+pub fn endpoint_health() -> Vec<EndpointHealth> {
+    REGISTRY.lock().unwrap().health.clone()
+}
+
+/// Returns the endpoint currently serving requests.
+pub fn active_endpoint() -> Endpoint {
+    REGISTRY.lock().unwrap().active().clone()
+}
+
+/// Returns the RPC url of the endpoint currently serving requests.
+pub fn rpc_url() -> String {
+    REGISTRY.lock().unwrap().active().rpc_url.clone()
+}
+
+/// Returns the websocket url of the endpoint currently serving requests.
+pub fn wss_url() -> String {
+    REGISTRY.lock().unwrap().active().wss_url.clone()
+}
+
+/// Replaces the configured endpoints and persists them.
+pub fn set_endpoints(endpoints: Vec<Endpoint>) -> GatewayResult<()> {
+    if endpoints.is_empty() {
+        return Err(GatewayError::NoEndpoints);
+    }
+    for endpoint in endpoints.iter() {
+        for url in [&endpoint.rpc_url, &endpoint.wss_url] {
+            if !is_url::is_url(url) {
+                log::error!("Invalid endpoint: {:?}", endpoint);
+                return Err(GatewayError::InvalidEndpoint(url.clone()));
+            }
+        }
+    }
+    if let Err(err) = set_key_value(ENDPOINTS_KEY, &endpoints) {
+        log::error!("Failed to persist endpoints: {:?}", err);
+    }
+    let mut registry = REGISTRY.lock().unwrap();
+    registry.health = vec![EndpointHealth::Unknown; endpoints.len()];
+    registry.endpoints = endpoints;
+    registry.active = 0;
+    registry.failures = 0;
+    Ok(())
+}
+
+/// Records a successful request against an endpoint.
+pub fn report_success(url: &str) {
+    let mut registry = REGISTRY.lock().unwrap();
+    if registry.active().rpc_url == url || registry.active().wss_url == url {
+        registry.failures = 0;
+    }
+}
+
+/// Records a failed request against an endpoint, failing over if it keeps erroring.
+pub fn report_failure(url: &str) {
+    let mut registry = REGISTRY.lock().unwrap();
+    if registry.active().rpc_url != url && registry.active().wss_url != url {
+        // A failover already happened
+        return;
+    }
+    registry.failures += 1;
+    if registry.failures >= MAX_CONSECUTIVE_FAILURES {
+        let active = registry.active;
+        registry.health[active] = EndpointHealth::Unreachable;
+        registry.failover();
+    }
+}
+
+/// Records the outcome of a request against an endpoint.
+pub fn report<T, E>(url: &str, result: &Result<T, E>) {
+    match result {
+        Ok(_) => report_success(url),
+        Err(_) => report_failure(url),
+    }
+}
+
+/// Polls the slot height of every configured endpoint and fails over
+/// if the active endpoint is unreachable or lagging behind the others.
+pub async fn check_health(http: &reqwest::Client) -> Vec<EndpointHealth> {
+    let endpoints = endpoints();
+    let mut slots = vec![];
+    for endpoint in endpoints.iter() {
+        slots.push(get_slot(http, &endpoint.rpc_url).await.ok());
+    }
+    let max_slot = slots.iter().flatten().max().copied().unwrap_or(0);
+    let health = slots
+        .iter()
+        .map(|slot| match slot {
+            Some(slot) if max_slot.saturating_sub(*slot) > MAX_SLOT_LAG => {
+                EndpointHealth::Lagging(*slot)
+            }
+            Some(slot) => EndpointHealth::Healthy(*slot),
+            None => EndpointHealth::Unreachable,
+        })
+        .collect::<Vec<_>>();
+
+    let mut registry = REGISTRY.lock().unwrap();
+    if registry.endpoints != endpoints {
+        // Endpoints were edited while the check was in flight
+        return health;
+    }
+    registry.health = health.clone();
+    let active = registry.active;
+    if !matches!(registry.health[active], EndpointHealth::Healthy(_)) {
+        // Switch to the healthiest endpoint, keeping preference order on ties
+        let healthiest = registry
+            .health
+            .iter()
+            .position(|h| matches!(h, EndpointHealth::Healthy(_)));
+        if let Some(healthiest) = healthiest {
+            log::warn!(
+                "Endpoint {} is unhealthy, switching to {}",
+                registry.endpoints[active].name,
+                registry.endpoints[healthiest].name
+            );
+            registry.active = healthiest;
+            registry.failures = 0;
+        }
+    } else if active != 0 && matches!(registry.health[0], EndpointHealth::Healthy(_)) {
+        // Return to the preferred endpoint once it recovers
+        registry.active = 0;
+        registry.failures = 0;
+    }
+    health
+}
+
+async fn get_slot(http: &reqwest::Client, rpc_url: &str) -> GatewayResult<u64> {
+    let req = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getSlot",
+        "params": [{ "commitment": "confirmed" }]
+    });
+    let res = http.post(rpc_url).json(&req).send().await?;
+    let res = res.json::<Value>().await?;
+    res["result"]
+        .as_u64()
+        .ok_or(GatewayError::FailedDeserialization)
+}
@@ -1,20 +1,54 @@
 use async_std::future::TimeoutError;
+use solana_sdk::pubkey::Pubkey;
 use steel::ProgramError;
 
+/// Context attached to errors raised by a dependency.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The source error and its causes, outermost first.
+    pub source: String,
+    /// The url of the endpoint the request was sent to.
+    pub endpoint: Option<String>,
+    /// The account the request was about.
+    pub account: Option<Pubkey>,
+    /// The http status code of the response.
+    pub status: Option<u16>,
+}
+
+impl ErrorContext {
+    fn from_error(err: &dyn std::error::Error) -> Self {
+        let mut source = err.to_string();
+        let mut cause = err.source();
+        while let Some(err) = cause {
+            source.push_str(": ");
+            source.push_str(&err.to_string());
+            cause = err.source();
+        }
+        ErrorContext {
+            source,
+            ..Default::default()
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum GatewayError {
-    Anyhow,
+    Anyhow(ErrorContext),
     FailedDeserialization,
     TransactionTimeout,
-    NetworkUnavailable,
+    NetworkUnavailable(ErrorContext),
     AccountNotFound,
     RetryFailed,
     TimeoutError,
     SignatureFailed,
-    SerdeJson,
+    SerdeJson(ErrorContext),
     RequestFailed,
-    ProgramBuilderFailed,
+    /// An http request failed or returned an error status.
+    Http(ErrorContext),
+    /// An rpc request was rejected by the node.
+    Rpc(ErrorContext),
+    ProgramBuilderFailed(ErrorContext),
     WalletDisconnected,
     JupSwapError,
     ParseTokenStringAmmount,
@@ -33,21 +67,178 @@ pub enum GatewayError {
     NoWalletsFound,
     InvalidWalletName,
     InsufficientFunds,
-    XAccountExists {screen_name: String, solana_address: String },
+    XAccountExists {
+        screen_name: String,
+        solana_address: String,
+    },
     InsufficientSOL,
+    SlippageExceeded,
+    StakeLocked,
+    BoostExpired,
+    AccountNotInitialized,
+    /// A pool failed the compatibility handshake, with the reason.
+    IncompatiblePool(String),
+    /// A transaction landed but failed for a reason unrelated to its instructions.
+    TransactionFailed(String),
+    /// No endpoints were given where at least one is required.
+    NoEndpoints,
+    /// An endpoint url is not a valid url, with the offending url.
+    InvalidEndpoint(String),
+    /// A program rejected a transaction for a reason that could not be decoded further.
+    ProgramFailed {
+        program: String,
+        code: Option<u32>,
+        message: Option<String>,
+    },
+}
+
+impl GatewayError {
+    /// Returns the context of errors raised by a dependency.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            GatewayError::Anyhow(context)
+            | GatewayError::NetworkUnavailable(context)
+            | GatewayError::SerdeJson(context)
+            | GatewayError::Http(context)
+            | GatewayError::Rpc(context)
+            | GatewayError::ProgramBuilderFailed(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    fn context_mut(&mut self) -> Option<&mut ErrorContext> {
+        match self {
+            GatewayError::Anyhow(context)
+            | GatewayError::NetworkUnavailable(context)
+            | GatewayError::SerdeJson(context)
+            | GatewayError::Http(context)
+            | GatewayError::Rpc(context)
+            | GatewayError::ProgramBuilderFailed(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Records the endpoint a failed request was sent to.
+    pub fn with_endpoint(mut self, endpoint: &str) -> Self {
+        if let Some(context) = self.context_mut() {
+            context.endpoint = Some(endpoint.to_string());
+        }
+        self
+    }
+
+    /// Records the account a failed request was about.
+    pub fn with_account(mut self, account: Pubkey) -> Self {
+        if let Some(context) = self.context_mut() {
+            context.account = Some(account);
+        }
+        self
+    }
+
+    /// Returns true if the same request may succeed when retried.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            GatewayError::NetworkUnavailable(_)
+            | GatewayError::TimeoutError
+            | GatewayError::TransactionTimeout
+            | GatewayError::RequestFailed => true,
+            GatewayError::Http(context) => match context.status {
+                Some(status) => status == 429 || status >= 500,
+                None => true,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns a message describing the error to the user.
+    pub fn user_message(&self) -> String {
+        match self {
+            GatewayError::InsufficientSOL => "Insufficient SOL balance".to_string(),
+            GatewayError::InsufficientFunds => "Insufficient funds".to_string(),
+            GatewayError::AccountNotFound => "Account not found".to_string(),
+            GatewayError::WalletDisconnected => "Connect a wallet to continue".to_string(),
+            GatewayError::SignatureFailed => "Failed to sign transaction".to_string(),
+            GatewayError::NetworkUnavailable(_) => {
+                "Network unavailable. Check your connection and try again.".to_string()
+            }
+            GatewayError::TimeoutError | GatewayError::TransactionTimeout => {
+                "Request timed out. Please try again.".to_string()
+            }
+            GatewayError::Http(ErrorContext {
+                status: Some(429), ..
+            }) => "Too many requests. Please wait a moment and try again.".to_string(),
+            GatewayError::Rpc(_) => {
+                "The RPC node rejected the request. Try again or switch endpoints in Settings."
+                    .to_string()
+            }
+            GatewayError::SlippageExceeded => {
+                "Price moved beyond your slippage tolerance. Please try again.".to_string()
+            }
+            GatewayError::StakeLocked => {
+                "Your stake is still locked. Try again once the lock period ends.".to_string()
+            }
+            GatewayError::BoostExpired => "This boost has expired".to_string(),
+            GatewayError::IncompatiblePool(reason) => {
+                format!("This pool is not compatible: {}", reason)
+            }
+            GatewayError::AccountNotInitialized => {
+                "A required account has not been created yet".to_string()
+            }
+            GatewayError::TransactionFailed(reason) => format!("Transaction failed: {}", reason),
+            GatewayError::NoEndpoints => "Add at least one endpoint".to_string(),
+            GatewayError::InvalidEndpoint(url) => format!("Invalid endpoint url: {}", url),
+            GatewayError::ProgramFailed {
+                program,
+                code,
+                message,
+            } => match (message, code) {
+                (Some(message), _) => format!("{} rejected the transaction: {}", program, message),
+                (None, Some(code)) => {
+                    format!("{} rejected the transaction (error {})", program, code)
+                }
+                (None, None) => format!("{} rejected the transaction", program),
+            },
+            GatewayError::JupSwapError => "No swap route available".to_string(),
+            GatewayError::XAccountExists { screen_name, .. } => {
+                format!("This wallet is already linked to @{}", screen_name)
+            }
+            GatewayError::KeyringElse
+            | GatewayError::KeyringNoEntry
+            | GatewayError::NoKeychainEntries
+            | GatewayError::SaveWalletConfig
+            | GatewayError::UpdateWalletConfig
+            | GatewayError::ConfigNotFound
+            | GatewayError::NoConfigOrKeypairFound => "Failed to access wallet storage".to_string(),
+            GatewayError::InvalidPrivateKey | GatewayError::UnableToDeriveKeypair => {
+                "Invalid private key".to_string()
+            }
+            GatewayError::NoWalletsFound => "No wallets found".to_string(),
+            GatewayError::InvalidWalletName => "Invalid wallet name".to_string(),
+            _ if self.is_retriable() => "Request failed. Please try again.".to_string(),
+            _ => "Something went wrong".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for GatewayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.user_message())
+    }
 }
 
 impl From<anyhow::Error> for GatewayError {
     fn from(value: anyhow::Error) -> Self {
         log::error!("{:?}", value);
-        Self::Anyhow
+        Self::Anyhow(ErrorContext {
+            source: format!("{:#}", value),
+            ..Default::default()
+        })
     }
 }
 
 impl From<serde_json::Error> for GatewayError {
     fn from(value: serde_json::Error) -> Self {
         log::error!("{:?}", value);
-        Self::SerdeJson
+        Self::SerdeJson(ErrorContext::from_error(&value))
     }
 }
 
@@ -100,7 +291,16 @@ impl From<jupiter_swap_api_client::ClientError> for GatewayError {
 impl From<reqwest::Error> for GatewayError {
     fn from(value: reqwest::Error) -> Self {
         log::error!("{:?}", value);
-        GatewayError::RequestFailed
+        let mut context = ErrorContext::from_error(&value);
+        context.endpoint = value.url().map(|url| url.to_string());
+        context.status = value.status().map(|status| status.as_u16());
+        if value.is_timeout() {
+            GatewayError::TimeoutError
+        } else if value.is_request() && context.status.is_none() {
+            GatewayError::NetworkUnavailable(context)
+        } else {
+            GatewayError::Http(context)
+        }
     }
 }
 
@@ -121,10 +321,16 @@ impl From<solana_client_wasm::ClientError> for GatewayError {
         {
             GatewayError::AccountNotFound
         } else if msg.starts_with("Client error: error sending request") {
-            GatewayError::NetworkUnavailable
+            GatewayError::NetworkUnavailable(ErrorContext {
+                source: msg,
+                ..Default::default()
+            })
         } else {
             log::info!("Err: {:?}", msg);
-            GatewayError::Unknown
+            GatewayError::Rpc(ErrorContext {
+                source: msg,
+                ..Default::default()
+            })
         }
     }
 }
@@ -132,15 +338,23 @@ impl From<solana_client_wasm::ClientError> for GatewayError {
 #[cfg(feature = "desktop")]
 impl From<solana_client::client_error::ClientError> for GatewayError {
     fn from(value: solana_client::client_error::ClientError) -> Self {
+        use solana_client::client_error::ClientErrorKind;
         log::error!("{:?}", value);
-        GatewayError::RequestFailed
+        let context = ErrorContext::from_error(&value);
+        match value.kind() {
+            ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) => {
+                GatewayError::NetworkUnavailable(context)
+            }
+            _ if context.source.contains("AccountNotFound") => GatewayError::AccountNotFound,
+            _ => GatewayError::Rpc(context),
+        }
     }
 }
 
 impl From<ProgramError> for GatewayError {
     fn from(value: ProgramError) -> Self {
         log::error!("err: {}", value);
-        GatewayError::ProgramBuilderFailed
+        GatewayError::ProgramBuilderFailed(ErrorContext::from_error(&value))
     }
 }
 
@@ -148,13 +362,21 @@ impl From<crate::gateway::wss::SubscriptionError> for GatewayError {
     fn from(value: crate::gateway::wss::SubscriptionError) -> Self {
         log::error!("{:?}", value);
         match value {
-            crate::gateway::wss::SubscriptionError::ConnectionError(_) => {
-                GatewayError::NetworkUnavailable
+            crate::gateway::wss::SubscriptionError::ConnectionError(msg) => {
+                GatewayError::NetworkUnavailable(ErrorContext {
+                    source: msg,
+                    ..Default::default()
+                })
             }
             crate::gateway::wss::SubscriptionError::ParseError(_) => {
                 GatewayError::FailedDeserialization
             }
-            crate::gateway::wss::SubscriptionError::RpcError(_) => GatewayError::RequestFailed,
+            crate::gateway::wss::SubscriptionError::RpcError(msg) => {
+                GatewayError::Rpc(ErrorContext {
+                    source: msg,
+                    ..Default::default()
+                })
+            }
             crate::gateway::wss::SubscriptionError::Other(_) => GatewayError::Unknown,
         }
     }
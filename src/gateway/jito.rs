@@ -0,0 +1,495 @@
+use std::sync::Mutex;
+
+use base64::Engine;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    system_instruction::SystemInstruction,
+    system_program,
+    transaction::VersionedTransaction,
+};
+
+use crate::utils::{get_value, set_key_value};
+
+use super::{
+    solana::SolanaGateway, ConfirmationProgress, Gateway, GatewayError, GatewayResult, Rpc,
+};
+
+const JITO_CONFIG_KEY: &str = "jito_config";
+
+pub const DEFAULT_BLOCK_ENGINE_URL: &str = "https://mainnet.block-engine.jito.wtf";
+
+/// Delay between bundle status checks.
+const BUNDLE_STATUS_DELAY: u64 = 2_000;
+
+pub const JITO_TIP_ADDRESSES: [Pubkey; 8] = [
+    solana_sdk::pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5"),
+    solana_sdk::pubkey!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe"),
+    solana_sdk::pubkey!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY"),
+    solana_sdk::pubkey!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49"),
+    solana_sdk::pubkey!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh"),
+    solana_sdk::pubkey!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt"),
+    solana_sdk::pubkey!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL"),
+    solana_sdk::pubkey!("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT"),
+];
+
+/// Bundle submission settings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JitoConfig {
+    /// Submit transactions as bundles instead of through the rpc. Off by default, since a
+    /// bundle still pending when its blockhash expires is not retried through the rpc.
+    pub enabled: bool,
+    pub block_engine_url: String,
+}
+
+impl Default for JitoConfig {
+    fn default() -> Self {
+        JitoConfig {
+            enabled: false,
+            block_engine_url: DEFAULT_BLOCK_ENGINE_URL.to_string(),
+        }
+    }
+}
+
+static JITO_CONFIG: Lazy<Mutex<JitoConfig>> =
+    Lazy::new(|| Mutex::new(get_value::<JitoConfig>(JITO_CONFIG_KEY).unwrap_or_default()));
+
+/// Returns the bundle submission settings.
+pub fn jito_config() -> JitoConfig {
+    JITO_CONFIG.lock().unwrap().clone()
+}
+
+/// Sets the bundle submission settings and persists them.
+pub fn set_jito_config(config: JitoConfig) -> GatewayResult<()> {
+    if !is_url::is_url(&config.block_engine_url) {
+        log::error!("Invalid block engine url: {}", config.block_engine_url);
+        return Err(GatewayError::InvalidEndpoint(config.block_engine_url));
+    }
+    if let Err(err) = set_key_value(JITO_CONFIG_KEY, &config) {
+        log::error!("Failed to persist jito config: {:?}", err);
+    }
+    *JITO_CONFIG.lock().unwrap() = config;
+    Ok(())
+}
+
+/// Status of a bundle as reported by the block engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BundleStatus {
+    Pending,
+    Landed,
+    Failed,
+    Invalid,
+}
+
+impl<R: Rpc> Gateway<R> {
+    /// Signs and submits a transaction as a bundle, falling back to rpc submission if the
+    /// bundle is rejected or fails to land. Tips are removed before submitting through the rpc,
+    /// where they buy nothing.
+    ///
+    /// `on_progress` is called on each broadcast and as confirmation notifications arrive.
+    pub async fn send_and_confirm_bundle(
+        &self,
+        signer: &Keypair,
+        mut tx: VersionedTransaction,
+        last_valid_block_height: u64,
+        mut on_progress: impl FnMut(ConfirmationProgress),
+    ) -> GatewayResult<Signature> {
+        let config = jito_config();
+        if config.enabled {
+            let signed = VersionedTransaction::try_new(tx.message.clone(), &[signer])?;
+            match self.send_bundle(&config.block_engine_url, &signed).await {
+                Ok(bundle_id) => {
+                    on_progress(ConfirmationProgress {
+                        attempt: 1,
+                        ..Default::default()
+                    });
+                    match self
+                        .confirm_bundle(
+                            &config.block_engine_url,
+                            &bundle_id,
+                            &signed,
+                            last_valid_block_height,
+                        )
+                        .await
+                    {
+                        Ok(sig) => return Ok(sig),
+                        Err(GatewayError::TransactionTimeout) => {
+                            return Err(GatewayError::TransactionTimeout)
+                        }
+                        Err(err) => log::error!("Bundle {} did not land: {:?}", bundle_id, err),
+                    }
+                }
+                Err(err) => log::error!("Failed to send bundle: {:?}", err),
+            }
+            log::info!("Falling back to rpc submission");
+        }
+        remove_tips(&mut tx.message);
+        let signed = VersionedTransaction::try_new(tx.message, &[signer])?;
+        self.rpc
+            .send_and_confirm(&signed, last_valid_block_height, on_progress)
+            .await
+    }
+
+    /// Sends a single transaction bundle and returns its id.
+    pub async fn send_bundle(
+        &self,
+        block_engine_url: &str,
+        tx: &VersionedTransaction,
+    ) -> GatewayResult<String> {
+        let bytes = bincode::serialize(tx).map_err(|_| GatewayError::BincodeSerialize)?;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+        let req = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendBundle",
+            "params": [[encoded], { "encoding": "base64" }]
+        });
+        let res = self.post_block_engine(block_engine_url, &req).await?;
+        res["result"]
+            .as_str()
+            .map(|bundle_id| bundle_id.to_string())
+            .ok_or(GatewayError::FailedDeserialization)
+    }
+
+    /// Returns the status of a recently sent bundle, if the block engine still tracks it.
+    pub async fn get_bundle_status(
+        &self,
+        block_engine_url: &str,
+        bundle_id: &str,
+    ) -> GatewayResult<Option<BundleStatus>> {
+        let req = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getInflightBundleStatuses",
+            "params": [[bundle_id]]
+        });
+        let res = self.post_block_engine(block_engine_url, &req).await?;
+        let status = match res["result"]["value"][0]["status"].as_str() {
+            Some("Pending") => BundleStatus::Pending,
+            Some("Landed") => BundleStatus::Landed,
+            Some("Failed") => BundleStatus::Failed,
+            Some("Invalid") => BundleStatus::Invalid,
+            _ => return Ok(None),
+        };
+        Ok(Some(status))
+    }
+
+    /// Polls a bundle until it lands, fails, or the transaction's blockhash expires.
+    async fn confirm_bundle(
+        &self,
+        block_engine_url: &str,
+        bundle_id: &str,
+        tx: &VersionedTransaction,
+        last_valid_block_height: u64,
+    ) -> GatewayResult<Signature> {
+        let sig = *tx.signatures.first().ok_or(GatewayError::SignatureFailed)?;
+        loop {
+            async_std::task::sleep(crate::time::Duration::from_millis(BUNDLE_STATUS_DELAY)).await;
+            match self.get_bundle_status(block_engine_url, bundle_id).await {
+                Ok(Some(BundleStatus::Landed)) => {
+                    return self.rpc.confirm_signature(sig, |_| {}).await;
+                }
+                Ok(Some(BundleStatus::Failed | BundleStatus::Invalid)) => {
+                    return Err(GatewayError::RequestFailed);
+                }
+                Ok(_) => {}
+                Err(err) => log::error!("Error fetching bundle status: {:?}", err),
+            }
+            if self.rpc.is_confirmed(sig).await {
+                return Ok(sig);
+            }
+            match self.rpc.get_block_height().await {
+                Ok(block_height) if block_height > last_valid_block_height => {
+                    return Err(GatewayError::TransactionTimeout);
+                }
+                Ok(_) => {}
+                Err(err) => log::error!("Error fetching block height: {:?}", err),
+            }
+        }
+    }
+
+    async fn post_block_engine(&self, block_engine_url: &str, req: &Value) -> GatewayResult<Value> {
+        let url = format!("{}/api/v1/bundles", block_engine_url.trim_end_matches('/'));
+        let res = self
+            .http
+            .post(url)
+            .json(req)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+        if res.get("error").is_some() {
+            log::error!("Block engine error: {:?}", res["error"]);
+            return Err(GatewayError::RequestFailed);
+        }
+        Ok(res)
+    }
+}
+
+/// Removes the jito tips from a message.
+///
+/// The tip accounts stay in the message's account keys, so a transaction using lookup tables
+/// does not need them to be fetched again.
+pub fn remove_tips(message: &mut VersionedMessage) {
+    let keys = message.static_account_keys().to_vec();
+    let is_tip = |ix: &CompiledInstruction| {
+        let is_transfer = keys.get(ix.program_id_index as usize) == Some(&system_program::ID)
+            && matches!(
+                bincode::deserialize::<SystemInstruction>(&ix.data),
+                Ok(SystemInstruction::Transfer { .. })
+            );
+        let recipient = ix
+            .accounts
+            .get(1)
+            .and_then(|index| keys.get(*index as usize));
+        is_transfer && recipient.is_some_and(|recipient| JITO_TIP_ADDRESSES.contains(recipient))
+    };
+    match message {
+        VersionedMessage::Legacy(message) => message.instructions.retain(|ix| !is_tip(ix)),
+        VersionedMessage::V0(message) => message.instructions.retain(|ix| !is_tip(ix)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::Arc,
+    };
+
+    use solana_sdk::{
+        hash::Hash,
+        message::{v0, VersionedMessage},
+        signature::{Keypair, Signer},
+        system_instruction,
+    };
+
+    use super::*;
+    use crate::gateway::{mock::MockRpc, TransactionConfirmationStatus};
+
+    /// A request received by the block engine stand-in.
+    struct Request {
+        path: String,
+        body: Value,
+    }
+
+    /// Serves one canned response per request on a local port, recording each request.
+    /// Returns the stand-in's url.
+    fn block_engine(responses: Vec<(u16, Value)>) -> (String, Arc<Mutex<Vec<Request>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        std::thread::spawn(move || {
+            for (stream, (status, body)) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut stream);
+                recorded.lock().unwrap().push(request);
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {} OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn read_request(stream: &mut TcpStream) -> Request {
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let path = request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or_default()
+            .to_string();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        Request {
+            path,
+            body: serde_json::from_slice(&body).unwrap(),
+        }
+    }
+
+    fn gateway() -> Gateway<MockRpc> {
+        Gateway {
+            rpc: MockRpc::new("http://localhost:8899".to_string()),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    fn transfer() -> VersionedTransaction {
+        let payer = Keypair::new();
+        let ix = system_instruction::transfer(&payer.pubkey(), &payer.pubkey(), 1_000);
+        let message =
+            v0::Message::try_compile(&payer.pubkey(), &[ix], &[], Hash::new_unique()).unwrap();
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap()
+    }
+
+    fn status(status: &str) -> (u16, Value) {
+        (
+            200,
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "value": [{ "status": status }] } }),
+        )
+    }
+
+    #[tokio::test]
+    async fn sends_bundle() {
+        let (url, requests) = block_engine(vec![(
+            200,
+            json!({ "jsonrpc": "2.0", "id": 1, "result": "bundle" }),
+        )]);
+        let tx = transfer();
+
+        let bundle_id = gateway().send_bundle(&format!("{}/", url), &tx).await;
+        assert_eq!(bundle_id, Ok("bundle".to_string()));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].path, "/api/v1/bundles");
+        assert_eq!(requests[0].body["method"], "sendBundle");
+        let encoded = requests[0].body["params"][0][0].as_str().unwrap();
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        assert_eq!(
+            bincode::deserialize::<VersionedTransaction>(&bytes).unwrap(),
+            tx
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_bundle_status() {
+        let (url, requests) = block_engine(vec![
+            status("Pending"),
+            status("Landed"),
+            (
+                200,
+                json!({ "jsonrpc": "2.0", "id": 1, "result": { "value": [] } }),
+            ),
+        ]);
+        let gateway = gateway();
+
+        for expected in [
+            Some(BundleStatus::Pending),
+            Some(BundleStatus::Landed),
+            None,
+        ] {
+            assert_eq!(
+                gateway.get_bundle_status(&url, "bundle").await,
+                Ok(expected)
+            );
+        }
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].body["method"], "getInflightBundleStatuses");
+        assert_eq!(requests[0].body["params"][0][0], "bundle");
+    }
+
+    #[tokio::test]
+    async fn rejects_block_engine_errors() {
+        let (url, _) = block_engine(vec![
+            (
+                200,
+                json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32602, "message": "bad" } }),
+            ),
+            (429, json!({})),
+        ]);
+        let gateway = gateway();
+        let tx = transfer();
+
+        assert_eq!(
+            gateway.send_bundle(&url, &tx).await,
+            Err(GatewayError::RequestFailed)
+        );
+        let err = gateway.send_bundle(&url, &tx).await.unwrap_err();
+        assert_eq!(err.context().and_then(|context| context.status), Some(429));
+    }
+
+    #[tokio::test]
+    async fn confirms_pending_bundle_once_signature_confirms() {
+        let (url, _) = block_engine(vec![status("Pending")]);
+        let gateway = gateway();
+        let tx = transfer();
+        gateway
+            .rpc
+            .set_signature_status(tx.signatures[0], TransactionConfirmationStatus::Confirmed);
+
+        let confirmed = gateway.confirm_bundle(&url, "bundle", &tx, 100).await;
+        assert_eq!(confirmed, Ok(tx.signatures[0]));
+    }
+
+    #[tokio::test]
+    async fn stops_on_failed_bundle() {
+        let (url, _) = block_engine(vec![status("Failed")]);
+        let tx = transfer();
+
+        let confirmed = gateway().confirm_bundle(&url, "bundle", &tx, 100).await;
+        assert_eq!(confirmed, Err(GatewayError::RequestFailed));
+    }
+
+    #[tokio::test]
+    async fn times_out_pending_bundle_after_blockhash_expiry() {
+        let (url, _) = block_engine(vec![status("Pending")]);
+        let gateway = gateway();
+        gateway.rpc.set_block_height(101);
+        let tx = transfer();
+
+        let confirmed = gateway.confirm_bundle(&url, "bundle", &tx, 100).await;
+        assert_eq!(confirmed, Err(GatewayError::TransactionTimeout));
+    }
+
+    #[test]
+    fn rejects_invalid_block_engine_url() {
+        let config = JitoConfig {
+            enabled: true,
+            block_engine_url: "not a url".to_string(),
+        };
+        assert_eq!(
+            set_jito_config(config),
+            Err(GatewayError::InvalidEndpoint("not a url".to_string()))
+        );
+        assert!(!JitoConfig::default().enabled);
+    }
+
+    #[test]
+    fn removes_tips() {
+        let payer = Keypair::new();
+        let recipient = Pubkey::new_unique();
+        let ixs = [
+            system_instruction::transfer(&payer.pubkey(), &recipient, 1_000),
+            system_instruction::transfer(&payer.pubkey(), &JITO_TIP_ADDRESSES[3], 2_000),
+        ];
+        let message =
+            v0::Message::try_compile(&payer.pubkey(), &ixs, &[], Hash::new_unique()).unwrap();
+        let mut message = VersionedMessage::V0(message);
+
+        remove_tips(&mut message);
+        let keys = message.static_account_keys();
+        let ixs = message.instructions();
+        assert_eq!(ixs.len(), 1);
+        assert_eq!(keys[ixs[0].accounts[1] as usize], recipient);
+    }
+}
@@ -4,7 +4,7 @@ use kliquidity_sdk::accounts::{GlobalConfig, WhirlpoolStrategy};
 use serde::Deserialize;
 use steel::{sysvar, Instruction, Pubkey};
 
-use super::{Gateway, GatewayResult, Rpc};
+use super::{cache::KAMINO_STRATEGY_METRICS_POLICY, Gateway, GatewayResult, Rpc};
 use crate::solana::{
     spl_associated_token_account::get_associated_token_address, spl_memo, spl_token, spl_token_2022,
 };
@@ -46,14 +46,12 @@ impl<R: Rpc> KaminoGateway for Gateway<R> {
         strategy: Pubkey,
     ) -> GatewayResult<KaminoStrategyMetrics> {
         let url = format!("https://api.kamino.finance/strategies/{strategy}/metrics/?env=mainnet-beta&status=LIVE");
-        let resp = self.http.get(url).send().await?;
-        let metrics = resp.json::<KaminoStrategyMetrics>().await?;
-        Ok(metrics)
+        self.get_cached(url, KAMINO_STRATEGY_METRICS_POLICY).await
     }
 
     async fn get_kamino_global_config(&self) -> GatewayResult<GlobalConfig> {
         let address = Pubkey::from_str("GKnHiWh3RRrE1zsNzWxRkomymHc374TvJPSTv2wPeYdB").unwrap();
-        let account_data = self.rpc.get_account_data(&address).await?;
+        let account_data = self.get_account_data(&address).await?;
         let config = GlobalConfig::from_bytes(&account_data)?;
         Ok(config)
     }
@@ -62,7 +60,7 @@ impl<R: Rpc> KaminoGateway for Gateway<R> {
         &self,
         strategy: Pubkey,
     ) -> GatewayResult<WhirlpoolStrategy> {
-        let account_data = self.rpc.get_account_data(&strategy).await?;
+        let account_data = self.get_account_data(&strategy).await?;
         let strategy = WhirlpoolStrategy::from_bytes(&account_data)?;
         Ok(strategy)
     }
@@ -0,0 +1,37 @@
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    pubkey::Pubkey,
+};
+
+use super::{Gateway, GatewayResult, Rpc};
+
+impl<R: Rpc> Gateway<R> {
+    /// Fetches address lookup tables, skipping any that do not exist or fail to deserialize.
+    pub async fn get_lookup_tables(
+        &self,
+        addresses: &[Pubkey],
+    ) -> GatewayResult<Vec<AddressLookupTableAccount>> {
+        let mut addresses = addresses.to_vec();
+        addresses.sort_unstable();
+        addresses.dedup();
+        let accounts = self.get_multiple_accounts(&addresses).await?;
+        let luts = addresses
+            .into_iter()
+            .zip(accounts)
+            .filter_map(|(key, account)| {
+                let account = account?;
+                match AddressLookupTable::deserialize(&account.data) {
+                    Ok(lut) => Some(AddressLookupTableAccount {
+                        key,
+                        addresses: lut.addresses.to_vec(),
+                    }),
+                    Err(err) => {
+                        log::error!("Invalid lookup table {}: {:?}", key, err);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Ok(luts)
+    }
+}
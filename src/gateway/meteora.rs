@@ -4,7 +4,10 @@ use meteora_vault_sdk::types::LockedProfitTracker;
 use serde::Deserialize;
 use steel::{Instruction, Pubkey};
 
-use super::{solana::SolanaGateway, spl::SplGateway, Gateway, GatewayResult, Rpc};
+use super::{
+    cache::METEORA_POOL_METRICS_POLICY, commitment::commitment, solana::SolanaGateway,
+    spl::SplGateway, Gateway, GatewayResult, Rpc,
+};
 use crate::{
     gateway::GatewayError,
     solana::{spl_associated_token_account::get_associated_token_address, spl_token},
@@ -50,19 +53,23 @@ pub trait MeteoraGateway {
 impl<R: Rpc + SplGateway + SolanaGateway> MeteoraGateway for Gateway<R> {
     async fn get_meteora_pool_metrics(&self, address: Pubkey) -> GatewayResult<MeteoraPoolMetrics> {
         let url = format!("https://damm-api.meteora.ag/pools?address={address}");
-        let resp = self.http.get(url).send().await?;
-        let metrics = resp.json::<Vec<MeteoraPoolMetrics>>().await?;
-        Ok(metrics.get(0).unwrap().clone())
+        let metrics = self
+            .get_cached::<Vec<MeteoraPoolMetrics>>(url, METEORA_POOL_METRICS_POLICY)
+            .await?;
+        metrics
+            .into_iter()
+            .next()
+            .ok_or(GatewayError::FailedDeserialization)
     }
 
     async fn get_meteora_pool(&self, address: Pubkey) -> GatewayResult<MeteoraPool> {
-        let account_data = self.rpc.get_account_data(&address).await?;
+        let account_data = self.get_account_data(&address).await?;
         let pool = MeteoraPool::from_bytes(&account_data)?;
         Ok(pool)
     }
 
     async fn get_meteora_vault(&self, address: Pubkey) -> GatewayResult<MeteoraVault> {
-        let account_data = self.rpc.get_account_data(&address).await?;
+        let account_data = self.get_account_data(&address).await?;
         let vault = MeteoraVault::from_bytes(&account_data)?;
         Ok(vault)
     }
@@ -77,11 +84,17 @@ impl<R: Rpc + SplGateway + SolanaGateway> MeteoraGateway for Gateway<R> {
         slippage_rate: u64,
     ) -> GatewayResult<u64> {
         // Get token accounts
-        let Some(pool_vault_a_lp_token) = self.rpc.get_token_account(&pool.a_vault_lp).await?
+        let Some(pool_vault_a_lp_token) = self
+            .rpc
+            .get_token_account(&pool.a_vault_lp, commitment())
+            .await?
         else {
             return Err(GatewayError::Unknown);
         };
-        let Some(pool_vault_b_lp_token) = self.rpc.get_token_account(&pool.b_vault_lp).await?
+        let Some(pool_vault_b_lp_token) = self
+            .rpc
+            .get_token_account(&pool.b_vault_lp, commitment())
+            .await?
         else {
             return Err(GatewayError::Unknown);
         };
@@ -0,0 +1,460 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use solana_sdk::{
+    account::Account, hash::Hash, pubkey::Pubkey, signature::Signature,
+    transaction::VersionedTransaction,
+};
+use steel::Clock;
+
+use super::{
+    commitment::Commitment, GatewayError, GatewayResult, Rpc, SimulateTransactionResponse,
+    TransactionConfirmationStatus, UiTokenAmount,
+};
+
+/// Number of blocks a blockhash remains valid for.
+const MAX_PROCESSING_AGE: u64 = 150;
+
+/// In-memory state backing a `MockRpc`.
+pub struct MockState {
+    pub accounts: HashMap<Pubkey, Account>,
+    pub token_accounts: HashMap<Pubkey, UiTokenAmount>,
+    pub token_supplies: HashMap<Pubkey, UiTokenAmount>,
+    pub signature_statuses: HashMap<Signature, TransactionConfirmationStatus>,
+    pub sent_transactions: Vec<VersionedTransaction>,
+    pub simulate_response: SimulateTransactionResponse,
+    pub blockhash: Hash,
+    pub block_height: u64,
+    pub clock: Clock,
+    /// Status assigned to transactions as they are sent. `None` leaves them unconfirmed.
+    pub send_status: Option<TransactionConfirmationStatus>,
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        MockState {
+            accounts: HashMap::new(),
+            token_accounts: HashMap::new(),
+            token_supplies: HashMap::new(),
+            signature_statuses: HashMap::new(),
+            sent_transactions: vec![],
+            simulate_response: SimulateTransactionResponse {
+                err: None,
+                logs: Some(vec![]),
+                units_consumed: Some(0),
+            },
+            blockhash: Hash::new_unique(),
+            block_height: 0,
+            clock: Clock::default(),
+            send_status: Some(TransactionConfirmationStatus::Confirmed),
+        }
+    }
+}
+
+/// An `Rpc` backed by an in-memory account store, for running gateways without network access.
+///
+/// Clones share the same state, so a handle kept by the caller can seed accounts
+/// and inspect sent transactions while a `Gateway<MockRpc>` is in use.
+#[derive(Clone)]
+pub struct MockRpc {
+    url: String,
+    state: Rc<RefCell<MockState>>,
+}
+
+impl MockRpc {
+    /// Returns the shared state backing this rpc.
+    pub fn state(&self) -> Rc<RefCell<MockState>> {
+        self.state.clone()
+    }
+
+    pub fn set_account(&self, pubkey: Pubkey, account: Account) {
+        self.state.borrow_mut().accounts.insert(pubkey, account);
+    }
+
+    /// Seeds an account with the given data, owned by `owner`.
+    pub fn set_account_data(&self, pubkey: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let mut state = self.state.borrow_mut();
+        let lamports = state
+            .accounts
+            .get(&pubkey)
+            .map(|account| account.lamports)
+            .unwrap_or(1_000_000);
+        state.accounts.insert(
+            pubkey,
+            Account {
+                lamports,
+                data,
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    pub fn set_balance(&self, pubkey: Pubkey, lamports: u64) {
+        let mut state = self.state.borrow_mut();
+        state
+            .accounts
+            .entry(pubkey)
+            .or_insert_with(|| Account::new(0, 0, &solana_sdk::system_program::ID))
+            .lamports = lamports;
+    }
+
+    pub fn remove_account(&self, pubkey: &Pubkey) {
+        let mut state = self.state.borrow_mut();
+        state.accounts.remove(pubkey);
+        state.token_accounts.remove(pubkey);
+    }
+
+    pub fn set_token_account(&self, pubkey: Pubkey, amount: u64, decimals: u8) {
+        self.state
+            .borrow_mut()
+            .token_accounts
+            .insert(pubkey, ui_token_amount(amount, decimals));
+    }
+
+    pub fn set_token_supply(&self, mint: Pubkey, amount: u64, decimals: u8) {
+        self.state
+            .borrow_mut()
+            .token_supplies
+            .insert(mint, ui_token_amount(amount, decimals));
+    }
+
+    pub fn set_signature_status(&self, sig: Signature, status: TransactionConfirmationStatus) {
+        self.state
+            .borrow_mut()
+            .signature_statuses
+            .insert(sig, status);
+    }
+
+    pub fn set_simulate_response(&self, response: SimulateTransactionResponse) {
+        self.state.borrow_mut().simulate_response = response;
+    }
+
+    pub fn set_blockhash(&self, blockhash: Hash) {
+        self.state.borrow_mut().blockhash = blockhash;
+    }
+
+    /// Sets the fake clock, which is served from the clock sysvar account.
+    pub fn set_clock(&self, clock: Clock) {
+        let data = bincode::serialize(&clock).unwrap_or_default();
+        self.set_account_data(solana_sdk::sysvar::clock::ID, solana_sdk::sysvar::ID, data);
+        self.state.borrow_mut().clock = clock;
+    }
+
+    pub fn set_block_height(&self, block_height: u64) {
+        self.state.borrow_mut().block_height = block_height;
+    }
+
+    /// Advances the fake clock and block height by the given number of seconds and slots.
+    pub fn advance_clock(&self, seconds: i64, slots: u64) {
+        let mut clock = self.state.borrow().clock.clone();
+        clock.unix_timestamp += seconds;
+        clock.slot += slots;
+        self.set_clock(clock);
+        self.state.borrow_mut().block_height += slots;
+    }
+
+    pub fn sent_transactions(&self) -> Vec<VersionedTransaction> {
+        self.state.borrow().sent_transactions.clone()
+    }
+}
+
+impl Rpc for MockRpc {
+    fn new(rpc_url: String) -> Self {
+        let rpc = MockRpc {
+            url: rpc_url,
+            state: Rc::new(RefCell::new(MockState::default())),
+        };
+        rpc.set_clock(Clock::default());
+        rpc
+    }
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+    async fn get_account_data(
+        &self,
+        pubkey: &Pubkey,
+        _commitment: Commitment,
+    ) -> GatewayResult<Vec<u8>> {
+        self.state
+            .borrow()
+            .accounts
+            .get(pubkey)
+            .map(|account| account.data.clone())
+            .ok_or(GatewayError::AccountNotFound)
+    }
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+        _commitment: Commitment,
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        let state = self.state.borrow();
+        Ok(pubkeys
+            .iter()
+            .map(|pubkey| state.accounts.get(pubkey).cloned())
+            .collect())
+    }
+    async fn get_balance(&self, pubkey: &Pubkey, _commitment: Commitment) -> GatewayResult<u64> {
+        Ok(self
+            .state
+            .borrow()
+            .accounts
+            .get(pubkey)
+            .map(|account| account.lamports)
+            .unwrap_or(0))
+    }
+    async fn get_latest_blockhash(&self) -> GatewayResult<Hash> {
+        Ok(self.state.borrow().blockhash)
+    }
+    async fn get_latest_blockhash_with_height(&self) -> GatewayResult<(Hash, u64)> {
+        let state = self.state.borrow();
+        Ok((state.blockhash, state.block_height + MAX_PROCESSING_AGE))
+    }
+    async fn get_block_height(&self) -> GatewayResult<u64> {
+        Ok(self.state.borrow().block_height)
+    }
+    async fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> GatewayResult<Vec<Option<TransactionConfirmationStatus>>> {
+        let state = self.state.borrow();
+        Ok(signatures
+            .iter()
+            .map(|sig| state.signature_statuses.get(sig).cloned())
+            .collect())
+    }
+    async fn get_token_account(
+        &self,
+        pubkey: &Pubkey,
+        _commitment: Commitment,
+    ) -> GatewayResult<Option<UiTokenAmount>> {
+        Ok(self.state.borrow().token_accounts.get(pubkey).cloned())
+    }
+    async fn get_token_supply(&self, mint: &Pubkey) -> GatewayResult<UiTokenAmount> {
+        self.state
+            .borrow()
+            .token_supplies
+            .get(mint)
+            .cloned()
+            .ok_or(GatewayError::AccountNotFound)
+    }
+    async fn send_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> GatewayResult<Signature> {
+        let sig = *transaction
+            .signatures
+            .first()
+            .ok_or(GatewayError::SignatureFailed)?;
+        let mut state = self.state.borrow_mut();
+        state.sent_transactions.push(transaction.clone());
+        if let Some(status) = state.send_status.clone() {
+            state.signature_statuses.insert(sig, status);
+        }
+        Ok(sig)
+    }
+    async fn simulate_transaction(
+        &self,
+        _transaction: &VersionedTransaction,
+    ) -> GatewayResult<SimulateTransactionResponse> {
+        Ok(self.state.borrow().simulate_response.clone())
+    }
+}
+
+fn ui_token_amount(amount: u64, decimals: u8) -> UiTokenAmount {
+    let ui_amount = amount as f64 / 10f64.powi(decimals as i32);
+    UiTokenAmount {
+        ui_amount: Some(ui_amount),
+        decimals,
+        amount: amount.to_string(),
+        ui_amount_string: ui_amount.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use async_std::task::block_on;
+    use solana_sdk::{
+        address_lookup_table::{
+            self,
+            state::{AddressLookupTable, LookupTableMeta},
+        },
+        compute_budget::ComputeBudgetInstruction,
+        message::{v0, VersionedMessage},
+        signature::{Keypair, Signer},
+        system_instruction,
+    };
+
+    use super::*;
+    use crate::{
+        gateway::{
+            compute_budget::{size_compute_unit_limit, ComputeBudget},
+            solana::SolanaGateway,
+            spl::SplGateway,
+            Gateway,
+        },
+        solana::spl_associated_token_account::get_associated_token_address,
+    };
+
+    fn gateway() -> (Gateway<MockRpc>, MockRpc) {
+        let rpc = MockRpc::new("http://localhost:8899".to_string());
+        let gateway = Gateway {
+            rpc: rpc.clone(),
+            http: reqwest::Client::new(),
+        };
+        (gateway, rpc)
+    }
+
+    fn transfer(payer: &Keypair, blockhash: Hash) -> VersionedTransaction {
+        let ixs = [
+            ComputeBudgetInstruction::set_compute_unit_limit(500_000),
+            ComputeBudgetInstruction::set_compute_unit_price(1_000),
+            system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000),
+        ];
+        let message = v0::Message::try_compile(&payer.pubkey(), &ixs, &[], blockhash).unwrap();
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[payer]).unwrap()
+    }
+
+    #[test]
+    fn reads_seeded_accounts() {
+        let (gateway, rpc) = gateway();
+        let owner = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let missing = Pubkey::new_unique();
+        rpc.set_account_data(address, owner, vec![1, 2, 3]);
+        rpc.set_balance(address, 42);
+
+        let account = block_on(gateway.get_account(&address)).unwrap();
+        assert_eq!(account.data, vec![1, 2, 3]);
+        assert_eq!(account.owner, owner);
+        assert_eq!(account.lamports, 42);
+
+        let system_account = Account::new(7, 0, &solana_sdk::system_program::ID);
+        let system_address = Pubkey::new_unique();
+        rpc.set_account(system_address, system_account.clone());
+        let accounts =
+            block_on(gateway.get_multiple_accounts(&[address, missing, system_address])).unwrap();
+        assert_eq!(accounts, vec![Some(account), None, Some(system_account)]);
+
+        rpc.remove_account(&address);
+        assert_eq!(
+            block_on(gateway.get_account_data(&address)),
+            Err(GatewayError::AccountNotFound)
+        );
+        assert_eq!(
+            block_on(rpc.get_balance(&address, Commitment::Confirmed)),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn serves_fake_clock() {
+        let (_, rpc) = gateway();
+        rpc.set_clock(Clock {
+            slot: 10,
+            unix_timestamp: 1_000,
+            ..Default::default()
+        });
+        rpc.set_block_height(7);
+        rpc.advance_clock(60, 150);
+
+        let clock = block_on(rpc.get_clock()).unwrap();
+        assert_eq!(clock.slot, 160);
+        assert_eq!(clock.unix_timestamp, 1_060);
+        assert_eq!(rpc.state().borrow().clock.unix_timestamp, 1_060);
+        assert_eq!(block_on(rpc.get_block_height()), Ok(157));
+    }
+
+    #[test]
+    fn serves_token_accounts() {
+        let (_, rpc) = gateway();
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        rpc.set_token_account(get_associated_token_address(&owner, &mint), 1_500_000, 6);
+        rpc.set_token_supply(mint, 2_000_000, 6);
+
+        let balance = block_on(rpc.get_token_balance(&owner, &mint)).unwrap();
+        assert_eq!(balance.ui_amount, Some(1.5));
+        assert_eq!(balance.amount, "1500000");
+        let supply = block_on(rpc.get_token_supply(&mint)).unwrap();
+        assert_eq!(supply.ui_amount, Some(2.0));
+        assert_eq!(
+            block_on(rpc.get_token_balance(&Pubkey::new_unique(), &mint)),
+            Err(GatewayError::AccountNotFound)
+        );
+    }
+
+    #[test]
+    fn tracks_signature_statuses() {
+        let (_, rpc) = gateway();
+        let payer = Keypair::new();
+        let blockhash = Hash::new_unique();
+        rpc.set_blockhash(blockhash);
+        assert_eq!(block_on(rpc.get_latest_blockhash()), Ok(blockhash));
+
+        // sent transactions stay unconfirmed until a status is seeded
+        rpc.state().borrow_mut().send_status = None;
+        let tx = transfer(&payer, blockhash);
+        let sig = block_on(rpc.send_transaction(&tx)).unwrap();
+        assert_eq!(rpc.sent_transactions(), vec![tx]);
+        assert!(!block_on(rpc.is_confirmed(sig)));
+
+        rpc.set_signature_status(sig, TransactionConfirmationStatus::Processed);
+        assert!(!block_on(rpc.is_confirmed(sig)));
+        rpc.set_signature_status(sig, TransactionConfirmationStatus::Finalized);
+        assert!(block_on(rpc.is_confirmed(sig)));
+    }
+
+    #[test]
+    fn sizes_compute_unit_limit_from_simulation() {
+        let (_, rpc) = gateway();
+        let payer = Keypair::new();
+        rpc.set_simulate_response(SimulateTransactionResponse {
+            err: None,
+            logs: Some(vec![]),
+            units_consumed: Some(10_000),
+        });
+
+        let mut tx = transfer(&payer, Hash::new_unique());
+        block_on(size_compute_unit_limit(&rpc, &mut tx)).unwrap();
+        let budget = ComputeBudget::from_message(&tx.message);
+        // 15% margin plus a flat 1,000 units
+        assert_eq!(budget.unit_limit, Some(12_500));
+        assert_eq!(budget.unit_price, Some(1_000));
+    }
+
+    #[test]
+    fn builds_lookup_tables() {
+        let (gateway, rpc) = gateway();
+        let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let lut = AddressLookupTable {
+            meta: LookupTableMeta::default(),
+            addresses: Cow::Owned(addresses.clone()),
+        };
+        let lut_address = Pubkey::new_unique();
+        rpc.set_account_data(
+            lut_address,
+            address_lookup_table::program::ID,
+            lut.serialize_for_tests().unwrap(),
+        );
+        let invalid_address = Pubkey::new_unique();
+        rpc.set_account_data(
+            invalid_address,
+            address_lookup_table::program::ID,
+            vec![0; 4],
+        );
+
+        // missing and invalid tables are skipped
+        let luts = block_on(gateway.get_lookup_tables(&[
+            lut_address,
+            invalid_address,
+            Pubkey::new_unique(),
+        ]))
+        .unwrap();
+        assert_eq!(luts.len(), 1);
+        assert_eq!(luts[0].key, lut_address);
+        assert_eq!(luts[0].addresses, addresses);
+    }
+}
@@ -1,23 +1,38 @@
+pub mod batch;
+pub mod cache;
+pub mod commitment;
+pub mod compute_budget;
+pub mod endpoints;
 mod error;
+#[cfg(not(feature = "web"))]
+pub mod jito;
 pub mod kamino;
+pub mod lookup_table;
 pub mod meteora;
+#[cfg(test)]
+pub mod mock;
 pub mod ore;
 pub mod pool;
+pub mod priority_fee;
+pub mod program_error;
 pub mod solana;
+pub mod solo;
 pub mod spl;
 mod utils;
 pub mod wss;
 
+use commitment::Commitment;
 pub use error::*;
-use serde_json::{json, Value};
 #[cfg(not(feature = "web"))]
 use solana_client::nonblocking::rpc_client::RpcClient;
-
+use std::{cell::RefCell, rc::Rc};
 
 #[cfg(feature = "web")]
 use solana_client_wasm::WasmClient;
 
 use solana_sdk::{
+    account::Account,
+    commitment_config::CommitmentConfig,
     hash::Hash,
     pubkey::Pubkey,
     signature::Signature,
@@ -28,16 +43,6 @@ pub use utils::SimulateTransactionResponse;
 pub use utils::*;
 pub use wss::*;
 
-#[cfg(feature = "web")]
-pub const RPC_URL: &str = "https://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
-#[cfg(not(feature = "web"))]
-pub const RPC_URL: &str = "https://rpc.ironforge.network/mainnet?apiKey=01JR0QT6CKAF608VC1DKSE1KC3";
-
-#[cfg(feature = "web")]
-pub const WSS_URL: &str = "wss://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
-#[cfg(not(feature = "web"))]
-pub const WSS_URL: &str = "wss://rpc.ironforge.network/mainnet?apiKey=01JR0QT6CKAF608VC1DKSE1KC3";
-
 pub struct Gateway<R: Rpc> {
     pub rpc: R,
     pub http: reqwest::Client,
@@ -50,60 +55,36 @@ impl<R: Rpc> Gateway<R> {
             http: reqwest::Client::new(),
         }
     }
-
-    pub async fn _get_recent_priority_fee_estimate(
-        &self,
-        tx: &VersionedTransaction,
-    ) -> GatewayResult<u64> {
-        match bincode::serialize(&tx) {
-            Ok(tx_bytes) => {
-                let tx_bs58 = bs58::encode(tx_bytes).into_string();
-                let req = json!({
-                    "jsonrpc": "2.0",
-                    "id": "priority-fee-estimate",
-                    "method": "getPriorityFeeEstimate",
-                    "params": [{
-                        "transaction": tx_bs58,
-                        "options": {
-                            "recommended": true
-                        }
-                    }]
-                });
-                if let Ok(res) = self.http.post(RPC_URL.to_string()).json(&req).send().await {
-                    if let Ok(res) = res.json::<Value>().await {
-                        // Get dynamic fee estimate in microlamports
-                        let dynamic_fee_estimate = res["result"]["priorityFeeEstimate"]
-                            .as_f64()
-                            .map(|fee| fee as u64)
-                            .unwrap_or(0);
-                        return Ok(dynamic_fee_estimate);
-                    } else {
-                        log::error!("Failed to parse priority fee json");
-                        return Err(GatewayError::Unknown);
-                    }
-                } else {
-                    log::error!("Failed to send priority fee estimate request");
-                    return Err(GatewayError::Unknown);
-                }
-            }
-            Err(e) => {
-                log::error!("err serializing tx: {}", e);
-                Err(GatewayError::Unknown)
-            }
-        }
-    }
 }
 
 pub trait Rpc {
     fn new(rpc_url: String) -> Self;
-    async fn get_account_data(&self, pubkey: &Pubkey) -> GatewayResult<Vec<u8>>;
-    async fn get_balance(&self, pubkey: &Pubkey) -> GatewayResult<u64>;
+    fn url(&self) -> String;
+    async fn get_account_data(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<u8>>;
+    /// Fetches up to `batch::MAX_MULTIPLE_ACCOUNTS` accounts in one request.
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<Option<Account>>>;
+    async fn get_balance(&self, pubkey: &Pubkey, commitment: Commitment) -> GatewayResult<u64>;
     async fn get_latest_blockhash(&self) -> GatewayResult<Hash>;
+    /// Returns the latest blockhash and the last block height at which it is valid.
+    async fn get_latest_blockhash_with_height(&self) -> GatewayResult<(Hash, u64)>;
+    async fn get_block_height(&self) -> GatewayResult<u64>;
     async fn get_signature_statuses(
         &self,
         signatures: &[Signature],
     ) -> GatewayResult<Vec<Option<TransactionConfirmationStatus>>>;
-    async fn get_token_account(&self, pubkey: &Pubkey) -> GatewayResult<Option<UiTokenAmount>>;
+    async fn get_token_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Option<UiTokenAmount>>;
     async fn get_token_supply(&self, mint: &Pubkey) -> GatewayResult<UiTokenAmount>;
     async fn send_transaction(
         &self,
@@ -116,27 +97,114 @@ pub trait Rpc {
 }
 
 #[cfg(not(feature = "web"))]
-pub struct NativeRpc(RpcClient);
+pub struct NativeRpc {
+    url: RefCell<String>,
+    client: RefCell<Rc<RpcClient>>,
+}
+
+#[cfg(not(feature = "web"))]
+impl NativeRpc {
+    /// Returns a client for the active endpoint, rebuilding it after a failover.
+    fn client(&self) -> (String, Rc<RpcClient>) {
+        let active = endpoints::rpc_url();
+        if *self.url.borrow() != active {
+            *self.client.borrow_mut() = Rc::new(RpcClient::new(active.clone()));
+            *self.url.borrow_mut() = active;
+        }
+        (self.url.borrow().clone(), self.client.borrow().clone())
+    }
+
+    /// Reports the outcome of a request, counting only transport errors against the endpoint.
+    fn report<T>(url: &str, res: &Result<T, solana_client::client_error::ClientError>) {
+        use solana_client::client_error::ClientErrorKind;
+        match res {
+            Err(err) if matches!(err.kind(), ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_)) => {
+                endpoints::report_failure(url)
+            }
+            _ => endpoints::report_success(url),
+        }
+    }
+
+    /// Converts a client error, recording the endpoint it came from.
+    fn error(url: &str, err: solana_client::client_error::ClientError) -> GatewayError {
+        GatewayError::from(err).with_endpoint(url)
+    }
+}
 
 #[cfg(not(feature = "web"))]
 impl Rpc for NativeRpc {
     fn new(rpc_url: String) -> Self {
-        NativeRpc(RpcClient::new(rpc_url))
+        NativeRpc {
+            client: RefCell::new(Rc::new(RpcClient::new(rpc_url.clone()))),
+            url: RefCell::new(rpc_url),
+        }
     }
-    async fn get_account_data(&self, pubkey: &Pubkey) -> GatewayResult<Vec<u8>> {
-        self.0.get_account_data(pubkey).await.map_err(From::from)
+    fn url(&self) -> String {
+        self.client().0
+    }
+    async fn get_account_data(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<u8>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_account_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        let account = res.map_err(|err| Self::error(&url, err))?.value;
+        account
+            .map(|account| account.data)
+            .ok_or(GatewayError::AccountNotFound)
+    }
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_multiple_accounts_with_commitment(pubkeys, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        Ok(res.map_err(|err| Self::error(&url, err))?.value)
     }
-    async fn get_balance(&self, pubkey: &Pubkey) -> GatewayResult<u64> {
-        self.0.get_balance(pubkey).await.map_err(From::from)
+    async fn get_balance(&self, pubkey: &Pubkey, commitment: Commitment) -> GatewayResult<u64> {
+        let (url, client) = self.client();
+        let res = client
+            .get_balance_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        Ok(res.map_err(|err| Self::error(&url, err))?.value)
     }
     async fn get_latest_blockhash(&self) -> GatewayResult<Hash> {
-        self.0.get_latest_blockhash().await.map_err(From::from)
+        let (url, client) = self.client();
+        let res = client.get_latest_blockhash().await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
+    }
+    async fn get_latest_blockhash_with_height(&self) -> GatewayResult<(Hash, u64)> {
+        let (url, client) = self.client();
+        let res = client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
+    }
+    async fn get_block_height(&self) -> GatewayResult<u64> {
+        let (url, client) = self.client();
+        let res = client.get_block_height().await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
     }
     async fn get_signature_statuses(
         &self,
         signatures: &[Signature],
     ) -> GatewayResult<Vec<Option<TransactionConfirmationStatus>>> {
-        let vec = self.0.get_signature_statuses(signatures).await?.value;
+        let (url, client) = self.client();
+        let res = client.get_signature_statuses(signatures).await;
+        Self::report(&url, &res);
+        let vec = res.map_err(|err| Self::error(&url, err))?.value;
         let vec = vec
             .into_iter()
             .map(|opt| {
@@ -163,8 +231,17 @@ impl Rpc for NativeRpc {
             .collect();
         Ok(vec)
     }
-    async fn get_token_account(&self, pubkey: &Pubkey) -> GatewayResult<Option<UiTokenAmount>> {
-        let option = self.0.get_token_account(pubkey).await?;
+    async fn get_token_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Option<UiTokenAmount>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_token_account_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        let option = res.map_err(|err| Self::error(&url, err))?.value;
         let option = option.map(|ta| UiTokenAmount {
             ui_amount: ta.token_amount.ui_amount,
             decimals: ta.token_amount.decimals,
@@ -174,7 +251,10 @@ impl Rpc for NativeRpc {
         Ok(option)
     }
     async fn get_token_supply(&self, mint: &Pubkey) -> GatewayResult<UiTokenAmount> {
-        let ta = self.0.get_token_supply(mint).await?;
+        let (url, client) = self.client();
+        let res = client.get_token_supply(mint).await;
+        Self::report(&url, &res);
+        let ta = res.map_err(|err| Self::error(&url, err))?;
         let ta = UiTokenAmount {
             ui_amount: ta.ui_amount,
             decimals: ta.decimals,
@@ -187,17 +267,20 @@ impl Rpc for NativeRpc {
         &self,
         transaction: &VersionedTransaction,
     ) -> GatewayResult<Signature> {
-        self.0
-            .send_transaction(transaction)
-            .await
-            .map_err(From::from)
+        let (url, client) = self.client();
+        let res = client.send_transaction(transaction).await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
     }
 
     async fn simulate_transaction(
         &self,
         transaction: &VersionedTransaction,
     ) -> GatewayResult<SimulateTransactionResponse> {
-        match self.0.simulate_transaction(transaction).await {
+        let (url, client) = self.client();
+        let res = client.simulate_transaction(transaction).await;
+        Self::report(&url, &res);
+        match res {
             Ok(response) => Ok(SimulateTransactionResponse {
                 err: response.value.err,
                 logs: response.value.logs,
@@ -205,34 +288,120 @@ impl Rpc for NativeRpc {
             }),
             Err(err) => {
                 log::error!("Simulation error: {:?}", err);
-                Err(From::from(err))
+                Err(Self::error(&url, err))
             }
         }
     }
 }
 
 #[cfg(feature = "web")]
-pub struct WebRpc(WasmClient);
+pub struct WebRpc {
+    url: RefCell<String>,
+    client: RefCell<Rc<WasmClient>>,
+}
+
+#[cfg(feature = "web")]
+impl WebRpc {
+    /// Returns a client for the active endpoint, rebuilding it after a failover.
+    fn client(&self) -> (String, Rc<WasmClient>) {
+        let active = endpoints::rpc_url();
+        if *self.url.borrow() != active {
+            *self.client.borrow_mut() = Rc::new(WasmClient::new(active.as_str()));
+            *self.url.borrow_mut() = active;
+        }
+        (self.url.borrow().clone(), self.client.borrow().clone())
+    }
+
+    /// Reports the outcome of a request, counting only transport errors against the endpoint.
+    fn report<T>(url: &str, res: &Result<T, solana_client_wasm::ClientError>) {
+        match res {
+            Err(err) if err.to_string().starts_with("Client error: error sending request") => {
+                endpoints::report_failure(url)
+            }
+            _ => endpoints::report_success(url),
+        }
+    }
+
+    /// Converts a client error, recording the endpoint it came from.
+    fn error(url: &str, err: solana_client_wasm::ClientError) -> GatewayError {
+        GatewayError::from(err).with_endpoint(url)
+    }
+}
 
 #[cfg(feature = "web")]
 impl Rpc for WebRpc {
     fn new(rpc_url: String) -> Self {
-        WebRpc(WasmClient::new(rpc_url.as_str()))
+        WebRpc {
+            client: RefCell::new(Rc::new(WasmClient::new(rpc_url.as_str()))),
+            url: RefCell::new(rpc_url),
+        }
     }
-    async fn get_account_data(&self, pubkey: &Pubkey) -> GatewayResult<Vec<u8>> {
-        self.0.get_account_data(pubkey).await.map_err(From::from)
+    fn url(&self) -> String {
+        self.client().0
     }
-    async fn get_balance(&self, pubkey: &Pubkey) -> GatewayResult<u64> {
-        self.0.get_balance(pubkey).await.map_err(From::from)
+    async fn get_account_data(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<u8>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_account_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        let account = res.map_err(|err| Self::error(&url, err))?;
+        account
+            .map(|account| account.data)
+            .ok_or(GatewayError::AccountNotFound)
+    }
+    async fn get_multiple_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment: Commitment,
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_multiple_accounts_with_commitment(pubkeys, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
+    }
+    async fn get_balance(&self, pubkey: &Pubkey, commitment: Commitment) -> GatewayResult<u64> {
+        let (url, client) = self.client();
+        let res = client
+            .get_balance_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
     }
     async fn get_latest_blockhash(&self) -> GatewayResult<Hash> {
-        self.0.get_latest_blockhash().await.map_err(From::from)
+        let (url, client) = self.client();
+        let res = client.get_latest_blockhash().await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
+    }
+    async fn get_latest_blockhash_with_height(&self) -> GatewayResult<(Hash, u64)> {
+        let (url, client) = self.client();
+        let res = client
+            .get_latest_blockhash_with_config(CommitmentConfig::confirmed())
+            .await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
+    }
+    async fn get_block_height(&self) -> GatewayResult<u64> {
+        let (url, client) = self.client();
+        let res = client.get_block_height().await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
     }
     async fn get_signature_statuses(
         &self,
         signatures: &[Signature],
     ) -> GatewayResult<Vec<Option<TransactionConfirmationStatus>>> {
-        let vec = self.0.get_signature_statuses(signatures).await?;
+        let (url, client) = self.client();
+        let res = client.get_signature_statuses(signatures).await;
+        Self::report(&url, &res);
+        let vec = res.map_err(|err| Self::error(&url, err))?;
         let vec = vec.into_iter().map(|opt| {
             if let Some(status) = opt {
                 if let Some(status) = status.confirmation_status {
@@ -250,8 +419,17 @@ impl Rpc for WebRpc {
         }).collect();
         Ok(vec)
     }
-    async fn get_token_account(&self, pubkey: &Pubkey) -> GatewayResult<Option<UiTokenAmount>> {
-        let option = self.0.get_token_account(pubkey).await?;
+    async fn get_token_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment: Commitment,
+    ) -> GatewayResult<Option<UiTokenAmount>> {
+        let (url, client) = self.client();
+        let res = client
+            .get_token_account_with_commitment(pubkey, commitment.into())
+            .await;
+        Self::report(&url, &res);
+        let option = res.map_err(|err| Self::error(&url, err))?;
         let option = option.map(|ta| UiTokenAmount {
             ui_amount: ta.token_amount.ui_amount,
             decimals: ta.token_amount.decimals,
@@ -261,7 +439,10 @@ impl Rpc for WebRpc {
         Ok(option)
     }
     async fn get_token_supply(&self, mint: &Pubkey) -> GatewayResult<UiTokenAmount> {
-        let ta = self.0.get_token_supply(mint).await?;
+        let (url, client) = self.client();
+        let res = client.get_token_supply(mint).await;
+        Self::report(&url, &res);
+        let ta = res.map_err(|err| Self::error(&url, err))?;
         let ta = UiTokenAmount {
             ui_amount: ta.ui_amount,
             decimals: ta.decimals,
@@ -274,16 +455,19 @@ impl Rpc for WebRpc {
         &self,
         transaction: &VersionedTransaction,
     ) -> GatewayResult<Signature> {
-        self.0
-            .send_versioned_transaction(transaction)
-            .await
-            .map_err(From::from)
+        let (url, client) = self.client();
+        let res = client.send_versioned_transaction(transaction).await;
+        Self::report(&url, &res);
+        res.map_err(|err| Self::error(&url, err))
     }
     async fn simulate_transaction(
         &self,
         transaction: &VersionedTransaction,
     ) -> GatewayResult<SimulateTransactionResponse> {
-        match self.0.simulate_transaction(transaction).await {
+        let (url, client) = self.client();
+        let res = client.simulate_transaction(transaction).await;
+        Self::report(&url, &res);
+        match res {
             Ok(response) => Ok(SimulateTransactionResponse {
                 err: response.err,
                 logs: response.logs,
@@ -291,7 +475,7 @@ impl Rpc for WebRpc {
             }),
             Err(err) => {
                 log::error!("Simulation error: {:?}", err);
-                Err(From::from(err))
+                Err(Self::error(&url, err))
             }
         }
     }
@@ -9,7 +9,10 @@ use solana_sdk::signature::Signature;
 use std::str::FromStr;
 use steel::AccountDeserialize;
 
-use super::{Gateway, GatewayError, GatewayResult, Rpc};
+use super::{
+    cache::{BOOST_YIELD_POLICY, ORE_HOLDERS_POLICY},
+    Gateway, GatewayError, GatewayResult, Rpc,
+};
 
 const ORE_API_URL: &str = "https://api.ore.supply";
 // const ORE_API_URL: &str = "http://localhost:3000";
@@ -62,54 +65,28 @@ pub trait OreGateway {
 
 impl<R: Rpc> OreGateway for Gateway<R> {
     async fn get_boost(&self, address: Pubkey) -> GatewayResult<Boost> {
-        let data = self
-            .rpc
-            .get_account_data(&address)
-            .await
-            .map_err(GatewayError::from)?;
+        let data = self.get_account_data(&address).await?;
         Ok(*Boost::try_from_bytes(&data)?)
     }
 
     async fn get_boost_config(&self, address: Pubkey) -> GatewayResult<BoostConfig> {
-        let data = self
-            .rpc
-            .get_account_data(&address)
-            .await
-            .map_err(GatewayError::from)?;
+        let data = self.get_account_data(&address).await?;
         Ok(*BoostConfig::try_from_bytes(&data)?)
     }
 
     async fn get_stake(&self, address: Pubkey) -> GatewayResult<Stake> {
-        let data = self
-            .rpc
-            .get_account_data(&address)
-            .await
-            .map_err(GatewayError::from)?;
+        let data = self.get_account_data(&address).await?;
         Ok(*Stake::try_from_bytes(&data)?)
     }
 
     async fn get_boost_yield_7d(&self, boost_address: Pubkey) -> GatewayResult<f64> {
         let get_url = format!("{}/boosts/{}/yield", ORE_API_URL, boost_address);
-        let resp = self
-            .http
-            .get(get_url)
-            .send()
-            .await
-            .map_err(GatewayError::from)?;
-        let yield_7d = resp.json::<f64>().await.map_err(GatewayError::from)?;
-        Ok(yield_7d)
+        self.get_cached(get_url, BOOST_YIELD_POLICY).await
     }
 
     async fn get_ore_holders(&self) -> GatewayResult<u64> {
         let get_url = format!("{}/holders", ORE_API_URL);
-        let resp = self
-            .http
-            .get(get_url)
-            .send()
-            .await
-            .map_err(GatewayError::from)?;
-        let holders = resp.json::<u64>().await.map_err(GatewayError::from)?;
-        Ok(holders)
+        self.get_cached(get_url, ORE_HOLDERS_POLICY).await
     }
 
     async fn get_ore_top_holders(&self) -> GatewayResult<Vec<TopHolder>> {
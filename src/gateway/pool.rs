@@ -1,16 +1,29 @@
-use ore_pool_api::state::Member;
+use ore_miner_types::pool::PoolClient;
+use ore_pool_api::state::{member_pda, Member, Pool};
 use ore_pool_types::{
-    BalanceUpdate, ContributePayloadV2, Member as MemberRecord, MemberChallenge, RegisterPayload,
-    UpdateBalancePayload,
+    BalanceUpdate, Member as MemberRecord, MemberChallenge, RegisterPayload, UpdateBalancePayload,
 };
 use solana_sdk::{hash::Hash, pubkey::Pubkey, transaction::Transaction};
 use steel::AccountDeserialize;
 
-use crate::hooks::MiningEvent;
+use crate::{
+    hooks::MiningEvent,
+    time::{Duration, Instant},
+};
 
 use super::{Gateway, GatewayError, GatewayResult, Rpc};
 
+/// Consecutive failed requests after which polling for a challenge gives up.
+const MAX_POLL_ERRORS: u32 = 5;
+
 pub trait PoolGateway {
+    /// Checks that a pool server and its on-chain pool account are compatible with the app.
+    async fn check_pool(
+        &self,
+        authority: Pubkey,
+        pool_address: Pubkey,
+        pool_url: String,
+    ) -> GatewayResult<()>;
     async fn commit_claim(
         &self,
         authority: Pubkey,
@@ -35,6 +48,11 @@ pub trait PoolGateway {
         authority: Pubkey,
         pool_url: String,
     ) -> GatewayResult<MemberRecord>;
+    /// Measures the round trip time of a request to the pool's challenge endpoint. Fails if
+    /// the pool answers with an error status.
+    async fn get_pool_latency(&self, pool_url: String) -> GatewayResult<Duration>;
+    /// Polls until the pool serves a challenge newer than `last_hash_at`, giving up after
+    /// repeated request failures.
     async fn poll_new_challenge(
         &self,
         authority: Pubkey,
@@ -56,23 +74,83 @@ pub trait PoolGateway {
 }
 
 impl<R: Rpc> PoolGateway for Gateway<R> {
+    async fn check_pool(
+        &self,
+        authority: Pubkey,
+        pool_address: Pubkey,
+        pool_url: String,
+    ) -> GatewayResult<()> {
+        // The address must hold a pool account
+        let account = match self.get_account(&pool_address).await {
+            Ok(account) => account,
+            Err(GatewayError::AccountNotFound) => {
+                return Err(GatewayError::IncompatiblePool(
+                    "pool account not found".to_string(),
+                ))
+            }
+            Err(err) => return Err(err),
+        };
+        if account.owner != ore_pool_api::ID || Pool::try_from_bytes(&account.data).is_err() {
+            return Err(GatewayError::IncompatiblePool(
+                "address is not a pool account".to_string(),
+            ));
+        }
+
+        // The server must hand out challenges
+        if let Err(err) = self.get_challenge(authority, pool_url.clone()).await {
+            log::error!("Pool handshake failed to get challenge: {:?}", err);
+            return Err(GatewayError::IncompatiblePool(
+                "server did not return a challenge".to_string(),
+            ));
+        }
+
+        // Members of the pool must be known to the server
+        match self.get_member(member_pda(authority, pool_address).0).await {
+            Ok(member) => {
+                if member.pool != pool_address {
+                    return Err(GatewayError::IncompatiblePool(
+                        "member account belongs to another pool".to_string(),
+                    ));
+                }
+                match self.get_member_record(authority, pool_url).await {
+                    Ok(record) if record.authority == authority.to_string() => {}
+                    Ok(_) => {
+                        return Err(GatewayError::IncompatiblePool(
+                            "server returned another member's record".to_string(),
+                        ))
+                    }
+                    Err(err) => {
+                        log::error!("Pool handshake failed to get member record: {:?}", err);
+                        return Err(GatewayError::IncompatiblePool(
+                            "server did not return your member record".to_string(),
+                        ));
+                    }
+                }
+            }
+            Err(GatewayError::AccountNotFound) => {
+                // Not a member yet, so the server only needs to respond
+                if let Err(GatewayError::NetworkUnavailable(_) | GatewayError::TimeoutError) =
+                    self.get_member_record(authority, pool_url).await
+                {
+                    return Err(GatewayError::IncompatiblePool(
+                        "server did not respond to member requests".to_string(),
+                    ));
+                }
+            }
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
+
     async fn get_challenge(
         &self,
         authority: Pubkey,
         pool_url: String,
     ) -> GatewayResult<MemberChallenge> {
-        let get_url = format!("{}/challenge/{}", pool_url, authority);
-        let resp = self
-            .http
-            .get(get_url)
-            .send()
-            .await
-            .map_err(GatewayError::from)?;
-        let challenge = resp
-            .json::<MemberChallenge>()
+        PoolClient::new(self.http.clone())
+            .get_challenge(&pool_url, authority)
             .await
-            .map_err(GatewayError::from)?;
-        Ok(challenge)
+            .map_err(GatewayError::from)
     }
 
     async fn get_latest_event(
@@ -110,11 +188,7 @@ impl<R: Rpc> PoolGateway for Gateway<R> {
     }
 
     async fn get_member(&self, address: Pubkey) -> GatewayResult<Member> {
-        let data = self
-            .rpc
-            .get_account_data(&address)
-            .await
-            .map_err(GatewayError::from)?;
+        let data = self.get_account_data(&address).await?;
         Ok(*Member::try_from_bytes(&data)?)
     }
 
@@ -123,18 +197,22 @@ impl<R: Rpc> PoolGateway for Gateway<R> {
         authority: Pubkey,
         pool_url: String,
     ) -> GatewayResult<MemberRecord> {
-        let get_url = format!("{}/member/{}", pool_url, authority);
-        let resp = self
-            .http
+        PoolClient::new(self.http.clone())
+            .get_member_record(&pool_url, authority)
+            .await
+            .map_err(GatewayError::from)
+    }
+
+    async fn get_pool_latency(&self, pool_url: String) -> GatewayResult<Duration> {
+        let get_url = format!("{}/challenge", pool_url);
+        let start = Instant::now();
+        self.http
             .get(get_url)
             .send()
             .await
+            .and_then(|resp| resp.error_for_status())
             .map_err(GatewayError::from)?;
-        let member_record = resp
-            .json::<MemberRecord>()
-            .await
-            .map_err(GatewayError::from)?;
-        Ok(member_record)
+        Ok(start.elapsed())
     }
 
     async fn poll_new_challenge(
@@ -143,6 +221,7 @@ impl<R: Rpc> PoolGateway for Gateway<R> {
         pool_url: String,
         last_hash_at: i64,
     ) -> GatewayResult<MemberChallenge> {
+        let mut errors = 0;
         loop {
             log::info!("Polling...");
             match self.get_challenge(authority, pool_url.clone()).await {
@@ -151,9 +230,14 @@ impl<R: Rpc> PoolGateway for Gateway<R> {
                 }
                 Ok(_) => {
                     log::info!("Same challenge, retry...");
+                    errors = 0;
                 }
                 Err(err) => {
                     log::error!("Error polling challenge: {:?}", err);
+                    errors += 1;
+                    if errors >= MAX_POLL_ERRORS {
+                        return Err(err);
+                    }
                 }
             }
             async_std::task::sleep(std::time::Duration::from_secs(1)).await;
@@ -166,18 +250,10 @@ impl<R: Rpc> PoolGateway for Gateway<R> {
         pool_url: String,
         solution: &drillx::Solution,
     ) -> GatewayResult<()> {
-        let post_url = format!("{}/contribute", pool_url);
-        let payload = ContributePayloadV2 {
-            authority,
-            solution: *solution,
-        };
-        self.http
-            .post(post_url)
-            .json(&payload)
-            .send()
+        PoolClient::new(self.http.clone())
+            .post_solution(&pool_url, authority, *solution)
             .await
-            .map_err(GatewayError::from)?;
-        Ok(())
+            .map_err(GatewayError::from)
     }
 
     async fn commit_claim(
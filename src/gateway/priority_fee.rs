@@ -0,0 +1,169 @@
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::utils::{get_value, set_key_value};
+
+use super::{endpoints, Gateway, GatewayError, GatewayResult, Rpc};
+
+const PRIORITY_FEE_TIER_KEY: &str = "priority_fee_tier";
+
+/// Upper bound on any estimated compute unit price, in microlamports.
+pub const MAX_PRIORITY_FEE: u64 = 1_000_000;
+
+/// User-selectable policy for pricing compute units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriorityFeeTier {
+    None,
+    Low,
+    #[default]
+    Medium,
+    High,
+    /// A fixed compute unit price, in microlamports.
+    Custom(u64),
+}
+
+impl PriorityFeeTier {
+    pub fn label(&self) -> String {
+        match self {
+            PriorityFeeTier::None => "None".to_string(),
+            PriorityFeeTier::Low => "Low".to_string(),
+            PriorityFeeTier::Medium => "Medium".to_string(),
+            PriorityFeeTier::High => "High".to_string(),
+            PriorityFeeTier::Custom(_) => "Custom".to_string(),
+        }
+    }
+
+    /// Priority level understood by the `getPriorityFeeEstimate` rpc method.
+    fn priority_level(&self) -> &'static str {
+        match self {
+            PriorityFeeTier::Low => "Low",
+            PriorityFeeTier::High => "High",
+            _ => "Medium",
+        }
+    }
+
+    /// Percentile of recent prioritization fees used when no estimate api is available.
+    fn percentile(&self) -> usize {
+        match self {
+            PriorityFeeTier::Low => 25,
+            PriorityFeeTier::High => 75,
+            _ => 50,
+        }
+    }
+}
+
+static PRIORITY_FEE_TIER: Lazy<Mutex<PriorityFeeTier>> = Lazy::new(|| {
+    Mutex::new(get_value::<PriorityFeeTier>(PRIORITY_FEE_TIER_KEY).unwrap_or_default())
+});
+
+/// Returns the selected priority fee tier.
+pub fn priority_fee_tier() -> PriorityFeeTier {
+    *PRIORITY_FEE_TIER.lock().unwrap()
+}
+
+/// Sets the priority fee tier and persists it.
+pub fn set_priority_fee_tier(tier: PriorityFeeTier) {
+    if let Err(err) = set_key_value(PRIORITY_FEE_TIER_KEY, &tier) {
+        log::error!("Failed to persist priority fee tier: {:?}", err);
+    }
+    *PRIORITY_FEE_TIER.lock().unwrap() = tier;
+}
+
+impl<R: Rpc> Gateway<R> {
+    /// Estimates a compute unit price, in microlamports, for a transaction writing to the given accounts.
+    pub async fn get_priority_fee(
+        &self,
+        tier: PriorityFeeTier,
+        writable_accounts: &[Pubkey],
+    ) -> GatewayResult<u64> {
+        let fee = match tier {
+            PriorityFeeTier::None => return Ok(0),
+            PriorityFeeTier::Custom(fee) => return Ok(fee),
+            _ => match self
+                .get_priority_fee_estimate(tier, writable_accounts)
+                .await
+            {
+                Ok(fee) => fee,
+                Err(_) => {
+                    self.get_recent_prioritization_fee(tier, writable_accounts)
+                        .await?
+                }
+            },
+        };
+        Ok(fee.min(MAX_PRIORITY_FEE))
+    }
+
+    async fn get_priority_fee_estimate(
+        &self,
+        tier: PriorityFeeTier,
+        writable_accounts: &[Pubkey],
+    ) -> GatewayResult<u64> {
+        let account_keys = writable_accounts
+            .iter()
+            .map(|pubkey| pubkey.to_string())
+            .collect::<Vec<_>>();
+        let req = json!({
+            "jsonrpc": "2.0",
+            "id": "priority-fee-estimate",
+            "method": "getPriorityFeeEstimate",
+            "params": [{
+                "accountKeys": account_keys,
+                "options": {
+                    "priorityLevel": tier.priority_level()
+                }
+            }]
+        });
+        let res = self.post_rpc(&req).await?;
+        res["result"]["priorityFeeEstimate"]
+            .as_f64()
+            .map(|fee| fee as u64)
+            .ok_or(GatewayError::FailedDeserialization)
+    }
+
+    async fn get_recent_prioritization_fee(
+        &self,
+        tier: PriorityFeeTier,
+        writable_accounts: &[Pubkey],
+    ) -> GatewayResult<u64> {
+        let account_keys = writable_accounts
+            .iter()
+            .map(|pubkey| pubkey.to_string())
+            .collect::<Vec<_>>();
+        let req = json!({
+            "jsonrpc": "2.0",
+            "id": "recent-prioritization-fees",
+            "method": "getRecentPrioritizationFees",
+            "params": [account_keys]
+        });
+        let res = self.post_rpc(&req).await?;
+        let Some(entries) = res["result"].as_array() else {
+            return Err(GatewayError::FailedDeserialization);
+        };
+        let mut fees = entries
+            .iter()
+            .filter_map(|entry| entry["prioritizationFee"].as_u64())
+            .collect::<Vec<_>>();
+        if fees.is_empty() {
+            return Ok(0);
+        }
+        fees.sort_unstable();
+        let index = (fees.len() - 1) * tier.percentile() / 100;
+        Ok(fees[index])
+    }
+
+    async fn post_rpc(&self, req: &Value) -> GatewayResult<Value> {
+        let rpc_url = self.rpc.url();
+        let res = self.http.post(rpc_url.clone()).json(req).send().await;
+        endpoints::report(&rpc_url, &res);
+        let res = res?.json::<Value>().await?;
+        if res.get("error").is_some() {
+            log::error!("Rpc error: {:?}", res["error"]);
+            return Err(GatewayError::RequestFailed);
+        }
+        Ok(res)
+    }
+}
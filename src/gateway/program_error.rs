@@ -0,0 +1,545 @@
+use ore_api::error::OreError;
+use ore_api_v2::error::OreError as OreV2Error;
+use ore_boost_api::error::BoostError;
+use ore_pool_api::error::PoolError;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    system_program,
+    transaction::{TransactionError, VersionedTransaction},
+};
+
+use crate::solana::{spl_token, spl_token_2022};
+
+use super::{GatewayError, SimulateTransactionResponse};
+
+const JUPITER_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUJoi5QNyVTaV4");
+const METEORA_POOLS_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+const METEORA_VAULT_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi");
+const KAMINO_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("6LtLpnUFNByNXLyCoK9wA2MykKAmQNZKBdY8s47dehDc");
+
+/// Anchor framework error raised when an account expected to exist is uninitialized.
+const ANCHOR_ACCOUNT_NOT_INITIALIZED: u32 = 3012;
+
+/// Programs whose failures can be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum KnownProgram {
+    System,
+    SplToken,
+    Ore,
+    /// The ORE v2 mining program, mined against in solo mode.
+    OreV2,
+    OreBoost,
+    OrePool,
+    Jupiter,
+    Meteora,
+    Kamino,
+}
+
+impl KnownProgram {
+    fn from_id(program_id: &Pubkey) -> Option<Self> {
+        let program = if *program_id == system_program::ID {
+            KnownProgram::System
+        } else if *program_id == spl_token::ID || *program_id == spl_token_2022::ID {
+            KnownProgram::SplToken
+        } else if *program_id == ore_api::ID {
+            KnownProgram::Ore
+        } else if *program_id == ore_api_v2::ID {
+            KnownProgram::OreV2
+        } else if *program_id == ore_boost_api::ID {
+            KnownProgram::OreBoost
+        } else if *program_id == ore_pool_api::ID {
+            KnownProgram::OrePool
+        } else if *program_id == JUPITER_PROGRAM_ID {
+            KnownProgram::Jupiter
+        } else if *program_id == METEORA_POOLS_PROGRAM_ID || *program_id == METEORA_VAULT_PROGRAM_ID
+        {
+            KnownProgram::Meteora
+        } else if *program_id == KAMINO_PROGRAM_ID {
+            KnownProgram::Kamino
+        } else {
+            return None;
+        };
+        Some(program)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            KnownProgram::System => "System program",
+            KnownProgram::SplToken => "Token program",
+            KnownProgram::Ore | KnownProgram::OreV2 => "ORE",
+            KnownProgram::OreBoost => "ORE boost",
+            KnownProgram::OrePool => "ORE pool",
+            KnownProgram::Jupiter => "Jupiter",
+            KnownProgram::Meteora => "Meteora",
+            KnownProgram::Kamino => "Kamino",
+        }
+    }
+
+    fn is_anchor(&self) -> bool {
+        matches!(
+            self,
+            KnownProgram::Jupiter | KnownProgram::Meteora | KnownProgram::Kamino
+        )
+    }
+}
+
+/// Decodes the reason a simulated transaction would fail, if it would.
+pub fn decode_simulation_error(
+    tx: &VersionedTransaction,
+    simulation: &SimulateTransactionResponse,
+) -> Option<GatewayError> {
+    let logs = simulation.logs.clone().unwrap_or_default();
+    let err = simulation.err.as_ref()?;
+    match err {
+        TransactionError::InsufficientFundsForFee
+        | TransactionError::InsufficientFundsForRent { .. }
+        | TransactionError::InstructionError(..) => {}
+        _ => {
+            // Failures unrelated to the transaction's instructions, such as an expired
+            // blockhash, are left for submission to surface
+            log::warn!("Simulation failed: {:?}", err);
+            return None;
+        }
+    }
+    log::error!("Simulation failed: {:?} {:?}", err, logs);
+    Some(decode_transaction_error(Some(tx), err, &logs))
+}
+
+/// Decodes the reason a transaction failed, from its logs if they are available.
+pub fn decode_transaction_error(
+    tx: Option<&VersionedTransaction>,
+    err: &TransactionError,
+    logs: &[String],
+) -> GatewayError {
+    match err {
+        TransactionError::InsufficientFundsForFee
+        | TransactionError::InsufficientFundsForRent { .. } => GatewayError::InsufficientSOL,
+        TransactionError::InstructionError(index, instruction_error) => {
+            let program_id = tx.and_then(|tx| {
+                let keys = tx.message.static_account_keys();
+                tx.message
+                    .instructions()
+                    .get(*index as usize)
+                    .and_then(|ix| keys.get(ix.program_id_index as usize))
+                    .copied()
+            });
+            let logs = program_id
+                .map(|program_id| instruction_logs(logs, &program_id))
+                .unwrap_or_else(|| logs.to_vec());
+            // Errors are raised by the innermost failing program
+            let failed_program = logs
+                .iter()
+                .find_map(|line| failed_program_id(line))
+                .or(program_id);
+            decode_instruction_error(failed_program, instruction_error, &logs)
+        }
+        err => GatewayError::TransactionFailed(err.to_string()),
+    }
+}
+
+fn decode_instruction_error(
+    program_id: Option<Pubkey>,
+    instruction_error: &InstructionError,
+    logs: &[String],
+) -> GatewayError {
+    let program = program_id.as_ref().and_then(KnownProgram::from_id);
+    let code = match instruction_error {
+        InstructionError::Custom(code) => Some(*code),
+        InstructionError::UninitializedAccount => return GatewayError::AccountNotInitialized,
+        InstructionError::InsufficientFunds => return GatewayError::InsufficientSOL,
+        _ => None,
+    };
+
+    // Error codes of the failing program
+    if let (Some(program), Some(code)) = (program, code) {
+        if let Some(decoded) = decode_custom_error(program, code) {
+            return decoded;
+        }
+    }
+
+    // Messages logged by the failing program
+    let message = anchor_error_name(logs).or_else(|| program_message(logs));
+    if let (Some(program), Some(message)) = (program, message.as_deref()) {
+        if let Some(decoded) = decode_message(program, message) {
+            return decoded;
+        }
+    }
+
+    let program = match (program, program_id) {
+        (Some(program), _) => program.name().to_string(),
+        (None, Some(program_id)) => program_id.to_string(),
+        (None, None) => "Unknown program".to_string(),
+    };
+    GatewayError::ProgramFailed {
+        program,
+        code,
+        message: message.or_else(|| code.is_none().then(|| instruction_error.to_string())),
+    }
+}
+
+/// Maps a custom error code onto a typed error, using the error enum of the program that
+/// raised it.
+fn decode_custom_error(program: KnownProgram, code: u32) -> Option<GatewayError> {
+    match program {
+        KnownProgram::System if code == 1 => Some(GatewayError::InsufficientSOL),
+        KnownProgram::SplToken if code == 1 => Some(GatewayError::InsufficientFunds),
+        KnownProgram::SplToken if code == 9 => Some(GatewayError::AccountNotInitialized),
+        KnownProgram::Ore => Some(match ore_error(code)? {
+            OreError::ClaimTooLarge => GatewayError::InsufficientFunds,
+            err => program_failed(program, code, err),
+        }),
+        KnownProgram::OreV2 => Some(match ore_v2_error(code)? {
+            OreV2Error::ClaimTooLarge => GatewayError::InsufficientFunds,
+            err => program_failed(program, code, err),
+        }),
+        KnownProgram::OreBoost => Some(match boost_error(code)? {
+            BoostError::StakeLocked => GatewayError::StakeLocked,
+            BoostError::BoostExpired => GatewayError::BoostExpired,
+        }),
+        KnownProgram::OrePool => Some(program_failed(program, code, pool_error(code)?)),
+        program if program.is_anchor() && code == ANCHOR_ACCOUNT_NOT_INITIALIZED => {
+            Some(GatewayError::AccountNotInitialized)
+        }
+        _ => None,
+    }
+}
+
+/// An error decoded from a program's error enum that has no typed equivalent.
+fn program_failed(program: KnownProgram, code: u32, err: impl ToString) -> GatewayError {
+    GatewayError::ProgramFailed {
+        program: program.name().to_string(),
+        code: Some(code),
+        message: Some(err.to_string()),
+    }
+}
+
+fn ore_error(code: u32) -> Option<OreError> {
+    [
+        OreError::NeedsReset,
+        OreError::HashInvalid,
+        OreError::HashTooEasy,
+        OreError::ClaimTooLarge,
+        OreError::ClockInvalid,
+        OreError::Spam,
+        OreError::MaxSupply,
+        OreError::AuthFailed,
+    ]
+    .into_iter()
+    .find(|err| *err as u32 == code)
+}
+
+fn ore_v2_error(code: u32) -> Option<OreV2Error> {
+    [
+        OreV2Error::NeedsReset,
+        OreV2Error::HashInvalid,
+        OreV2Error::HashTooEasy,
+        OreV2Error::ClaimTooLarge,
+        OreV2Error::ClockInvalid,
+        OreV2Error::Spam,
+        OreV2Error::MaxSupply,
+        OreV2Error::AuthFailed,
+    ]
+    .into_iter()
+    .find(|err| *err as u32 == code)
+}
+
+fn boost_error(code: u32) -> Option<BoostError> {
+    [BoostError::StakeLocked, BoostError::BoostExpired]
+        .into_iter()
+        .find(|err| *err as u32 == code)
+}
+
+fn pool_error(code: u32) -> Option<PoolError> {
+    [PoolError::Dummy]
+        .into_iter()
+        .find(|err| *err as u32 == code)
+}
+
+/// Maps an error name or message logged by a program onto a typed error, for failures whose
+/// error code could not be decoded. Each pattern only applies to the programs it describes, so
+/// an expired blockhash or a locked token account is not mistaken for an expired boost or a
+/// locked stake.
+fn decode_message(program: KnownProgram, message: &str) -> Option<GatewayError> {
+    let message = message.to_lowercase();
+    match program {
+        KnownProgram::Jupiter | KnownProgram::Meteora | KnownProgram::Kamino
+            if message.contains("slippage") =>
+        {
+            return Some(GatewayError::SlippageExceeded)
+        }
+        KnownProgram::OreBoost if message.contains("locked") => {
+            return Some(GatewayError::StakeLocked)
+        }
+        KnownProgram::OreBoost if message.contains("expired") => {
+            return Some(GatewayError::BoostExpired)
+        }
+        _ => {}
+    }
+    if message.contains("not initialized")
+        || message.contains("notinitialized")
+        || message.contains("uninitialized")
+    {
+        Some(GatewayError::AccountNotInitialized)
+    } else if message.contains("insufficient funds") || message.contains("insufficientfunds") {
+        Some(GatewayError::InsufficientFunds)
+    } else {
+        None
+    }
+}
+
+/// Returns the log lines of the last top-level invocation of a program.
+fn instruction_logs(logs: &[String], program_id: &Pubkey) -> Vec<String> {
+    let invoke = format!("Program {} invoke [1]", program_id);
+    let start = logs.iter().rposition(|line| *line == invoke).unwrap_or(0);
+    logs[start..].to_vec()
+}
+
+/// Parses the program id out of a `Program <id> failed: ...` line.
+fn failed_program_id(line: &str) -> Option<Pubkey> {
+    let rest = line.strip_prefix("Program ")?;
+    let (program_id, _) = rest.split_once(" failed: ")?;
+    program_id.parse().ok()
+}
+
+/// Parses the error name out of an anchor `Error Code: <name>.` log line.
+fn anchor_error_name(logs: &[String]) -> Option<String> {
+    logs.iter().find_map(|line| {
+        let (_, rest) = line.split_once("Error Code: ")?;
+        let (name, _) = rest.split_once('.')?;
+        Some(name.to_string())
+    })
+}
+
+/// Returns the last error message a program logged before failing.
+fn program_message(logs: &[String]) -> Option<String> {
+    logs.iter()
+        .rev()
+        .filter_map(|line| line.strip_prefix("Program log: "))
+        .find(|message| message.to_lowercase().contains("error"))
+        .map(|message| message.trim_start_matches("Error: ").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        instruction::Instruction,
+        message::{v0, VersionedMessage},
+        signature::Keypair,
+        signer::Signer,
+        system_instruction,
+    };
+
+    use super::*;
+
+    /// An unsigned transaction calling `program_id` as its only instruction.
+    fn transaction(program_id: Pubkey) -> VersionedTransaction {
+        let payer = Keypair::new();
+        let ix = Instruction::new_with_bytes(program_id, &[], vec![]);
+        let message =
+            v0::Message::try_compile(&payer.pubkey(), &[ix], &[], Default::default()).unwrap();
+        VersionedTransaction {
+            signatures: vec![Default::default()],
+            message: VersionedMessage::V0(message),
+        }
+    }
+
+    /// Logs of a top-level program that fails with a custom error code.
+    fn failed_logs(program_id: Pubkey, messages: &[&str], code: u32) -> Vec<String> {
+        let mut logs = vec![format!("Program {} invoke [1]", program_id)];
+        logs.extend(
+            messages
+                .iter()
+                .map(|message| format!("Program log: {}", message)),
+        );
+        logs.push(format!(
+            "Program {} consumed 4512 of 200000 compute units",
+            program_id
+        ));
+        logs.push(format!(
+            "Program {} failed: custom program error: {:#x}",
+            program_id, code
+        ));
+        logs
+    }
+
+    fn decode(program_id: Pubkey, code: u32, logs: &[String]) -> GatewayError {
+        let tx = transaction(program_id);
+        let err = TransactionError::InstructionError(0, InstructionError::Custom(code));
+        decode_transaction_error(Some(&tx), &err, logs)
+    }
+
+    #[test]
+    fn decodes_system_insufficient_lamports() {
+        let logs = failed_logs(
+            system_program::ID,
+            &["Transfer: insufficient lamports 0, need 1000"],
+            1,
+        );
+        assert_eq!(
+            decode(system_program::ID, 1, &logs),
+            GatewayError::InsufficientSOL
+        );
+    }
+
+    #[test]
+    fn decodes_token_errors() {
+        let logs = failed_logs(
+            spl_token::ID,
+            &["Instruction: Transfer", "Error: insufficient funds"],
+            1,
+        );
+        assert_eq!(
+            decode(spl_token::ID, 1, &logs),
+            GatewayError::InsufficientFunds
+        );
+        let logs = failed_logs(spl_token::ID, &["Error: UninitializedState"], 9);
+        assert_eq!(
+            decode(spl_token::ID, 9, &logs),
+            GatewayError::AccountNotInitialized
+        );
+    }
+
+    #[test]
+    fn decodes_ore_errors() {
+        let code = OreError::ClaimTooLarge as u32;
+        let logs = failed_logs(ore_api::ID, &["Instruction: Claim"], code);
+        assert_eq!(
+            decode(ore_api::ID, code, &logs),
+            GatewayError::InsufficientFunds
+        );
+
+        let code = OreV2Error::HashTooEasy as u32;
+        let logs = failed_logs(ore_api_v2::ID, &["Instruction: Mine"], code);
+        assert_eq!(
+            decode(ore_api_v2::ID, code, &logs),
+            GatewayError::ProgramFailed {
+                program: "ORE".to_string(),
+                code: Some(code),
+                message: Some(OreV2Error::HashTooEasy.to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_boost_errors() {
+        let code = BoostError::StakeLocked as u32;
+        let logs = failed_logs(ore_boost_api::ID, &["Instruction: Withdraw"], code);
+        assert_eq!(
+            decode(ore_boost_api::ID, code, &logs),
+            GatewayError::StakeLocked
+        );
+
+        let code = BoostError::BoostExpired as u32;
+        let logs = failed_logs(ore_boost_api::ID, &["Instruction: Deposit"], code);
+        assert_eq!(
+            decode(ore_boost_api::ID, code, &logs),
+            GatewayError::BoostExpired
+        );
+    }
+
+    #[test]
+    fn decodes_pool_errors() {
+        let code = PoolError::Dummy as u32;
+        let logs = failed_logs(ore_pool_api::ID, &["Instruction: Claim"], code);
+        assert_eq!(
+            decode(ore_pool_api::ID, code, &logs),
+            GatewayError::ProgramFailed {
+                program: "ORE pool".to_string(),
+                code: Some(code),
+                message: Some(PoolError::Dummy.to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn falls_back_to_logged_messages() {
+        let code = 0xff;
+        let logs = failed_logs(ore_boost_api::ID, &["Error: stake is locked"], code);
+        assert_eq!(
+            decode(ore_boost_api::ID, code, &logs),
+            GatewayError::StakeLocked
+        );
+    }
+
+    #[test]
+    fn decodes_anchor_errors() {
+        let logs = failed_logs(
+            JUPITER_PROGRAM_ID,
+            &["AnchorError occurred. Error Code: SlippageToleranceExceeded. Error Number: 6001. Error Message: Slippage tolerance exceeded."],
+            6001,
+        );
+        assert_eq!(
+            decode(JUPITER_PROGRAM_ID, 6001, &logs),
+            GatewayError::SlippageExceeded
+        );
+
+        let logs = failed_logs(
+            KAMINO_PROGRAM_ID,
+            &["AnchorError caused by account: obligation. Error Code: AccountNotInitialized. Error Number: 3012. Error Message: The program expected this account to be already initialized."],
+            ANCHOR_ACCOUNT_NOT_INITIALIZED,
+        );
+        assert_eq!(
+            decode(KAMINO_PROGRAM_ID, ANCHOR_ACCOUNT_NOT_INITIALIZED, &logs),
+            GatewayError::AccountNotInitialized
+        );
+    }
+
+    #[test]
+    fn blames_the_innermost_failing_program() {
+        // a swap whose token transfer fails
+        let logs = [
+            format!("Program {} invoke [1]", JUPITER_PROGRAM_ID),
+            "Program log: Instruction: Route".to_string(),
+            format!("Program {} invoke [2]", spl_token::ID),
+            "Program log: Instruction: Transfer".to_string(),
+            "Program log: Error: insufficient funds".to_string(),
+            format!(
+                "Program {} failed: custom program error: 0x1",
+                spl_token::ID
+            ),
+            format!(
+                "Program {} failed: custom program error: 0x1",
+                JUPITER_PROGRAM_ID
+            ),
+        ];
+        assert_eq!(
+            decode(JUPITER_PROGRAM_ID, 1, &logs),
+            GatewayError::InsufficientFunds
+        );
+    }
+
+    #[test]
+    fn reports_unknown_programs() {
+        let program_id = Pubkey::new_unique();
+        let logs = failed_logs(program_id, &[], 42);
+        assert_eq!(
+            decode(program_id, 42, &logs),
+            GatewayError::ProgramFailed {
+                program: program_id.to_string(),
+                code: Some(42),
+                message: None,
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_fee_failures() {
+        let payer = Keypair::new();
+        let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+        let message =
+            v0::Message::try_compile(&payer.pubkey(), &[ix], &[], Default::default()).unwrap();
+        let tx = VersionedTransaction {
+            signatures: vec![Default::default()],
+            message: VersionedMessage::V0(message),
+        };
+        assert_eq!(
+            decode_transaction_error(Some(&tx), &TransactionError::InsufficientFundsForFee, &[]),
+            GatewayError::InsufficientSOL
+        );
+    }
+}
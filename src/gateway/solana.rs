@@ -1,53 +1,168 @@
-use solana_sdk::signature::Signature;
+use solana_sdk::{
+    signature::Signature,
+    transaction::{TransactionError, VersionedTransaction},
+};
 use steel::Clock;
 
-use super::{GatewayError, GatewayResult, Rpc, TransactionConfirmationStatus};
+use super::{
+    commitment::commitment, program_error::decode_transaction_error, ConfirmationProgress,
+    GatewayError, GatewayResult, Rpc, SignatureWatcher, TransactionConfirmationStatus,
+};
 
 const CONFIRM_RETRIES: usize = 20;
 const CONFIRM_DELAY: u64 = 1_500;
+/// Delay between rebroadcasts of an unconfirmed transaction.
+const REBROADCAST_DELAY: u64 = 2_000;
+/// Upper bound on rebroadcasts, should the block height be unavailable.
+const MAX_REBROADCASTS: u8 = 90;
 
 pub trait SolanaGateway {
     async fn get_clock(&self) -> GatewayResult<Clock>;
-    async fn confirm_signature(&self, sig: Signature) -> GatewayResult<Signature>;
+    async fn confirm_signature(
+        &self,
+        sig: Signature,
+        on_progress: impl FnMut(ConfirmationProgress),
+    ) -> GatewayResult<Signature>;
+    async fn send_and_confirm(
+        &self,
+        tx: &VersionedTransaction,
+        last_valid_block_height: u64,
+        on_progress: impl FnMut(ConfirmationProgress),
+    ) -> GatewayResult<Signature>;
+    async fn is_confirmed(&self, sig: Signature) -> bool;
 }
 
 impl<R: Rpc> SolanaGateway for R {
     async fn get_clock(&self) -> GatewayResult<Clock> {
         let data = self
-            .get_account_data(&solana_sdk::sysvar::clock::ID)
+            .get_account_data(&solana_sdk::sysvar::clock::ID, commitment())
             .await
             .map_err(GatewayError::from)?;
         bincode::deserialize::<Clock>(&data).or(Err(GatewayError::FailedDeserialization))
     }
 
-    async fn confirm_signature(&self, sig: Signature) -> GatewayResult<Signature> {
-        // Confirm tx
+    /// Waits for an already sent transaction to confirm.
+    ///
+    /// Confirmation is pushed over a signature subscription, falling back to polling
+    /// while the socket is down.
+    async fn confirm_signature(
+        &self,
+        sig: Signature,
+        mut on_progress: impl FnMut(ConfirmationProgress),
+    ) -> GatewayResult<Signature> {
+        let mut watcher = SignatureWatcher::open(&sig);
+        // The transaction may have confirmed before the subscription was opened
+        if self.is_confirmed(sig).await {
+            return Ok(sig);
+        }
+        let mut progress = ConfirmationProgress {
+            attempt: 1,
+            ..Default::default()
+        };
         for retry in 0..CONFIRM_RETRIES {
-            // Delay before confirming
-            async_std::task::sleep(crate::time::Duration::from_millis(CONFIRM_DELAY)).await;
-            // Fetch transaction status
-            match self.get_signature_statuses(&[sig]).await {
-                Ok(signature_statuses) => {
-                    for signature_status in signature_statuses {
-                        if let Some(signature_status) = signature_status.as_ref() {
-                            match signature_status {
-                                TransactionConfirmationStatus::Processed => {}
-                                TransactionConfirmationStatus::Confirmed
-                                | TransactionConfirmationStatus::Finalized => {
-                                    log::info!("Confirmed: true");
-                                    return Ok(sig);
-                                }
-                            }
-                        }
-                    }
+            if wait_for_confirmation(
+                self,
+                &mut watcher,
+                sig,
+                CONFIRM_DELAY,
+                &mut progress,
+                &mut on_progress,
+            )
+            .await
+            .map_err(|err| decode_transaction_error(None, &err, &[]))?
+            {
+                log::info!("Confirmed: true");
+                return Ok(sig);
+            }
+            log::info!("retry: {}", retry);
+        }
+        return Err(GatewayError::TransactionTimeout);
+    }
+
+    /// Sends a signed transaction and rebroadcasts it until it confirms or its blockhash expires.
+    ///
+    /// `on_progress` is called on each broadcast and as confirmation notifications arrive.
+    async fn send_and_confirm(
+        &self,
+        tx: &VersionedTransaction,
+        last_valid_block_height: u64,
+        mut on_progress: impl FnMut(ConfirmationProgress),
+    ) -> GatewayResult<Signature> {
+        let sig = *tx.signatures.first().ok_or(GatewayError::SignatureFailed)?;
+        // Subscribe before the first broadcast, so no notification is missed
+        let mut watcher = SignatureWatcher::open(&sig);
+        let mut progress = ConfirmationProgress::default();
+        while progress.attempt < MAX_REBROADCASTS {
+            // Broadcast
+            match self.send_transaction(tx).await {
+                Ok(_) => {
+                    progress.attempt += 1;
+                    on_progress(progress);
                 }
-                // Handle confirmation errors
+                // Rejections of the first broadcast will not resolve themselves
+                Err(err) if progress.attempt == 0 && !err.is_retriable() => return Err(err),
                 Err(err) => {
-                    log::error!("Error confirming: {:?}", err);
+                    progress.attempt += 1;
+                    log::error!("Failed to broadcast transaction: {:?}", err);
                 }
             }
-            log::info!("retry: {}", retry);
+
+            // Wait for confirmation
+            if wait_for_confirmation(
+                self,
+                &mut watcher,
+                sig,
+                REBROADCAST_DELAY,
+                &mut progress,
+                &mut on_progress,
+            )
+            .await
+            .map_err(|err| decode_transaction_error(Some(tx), &err, &[]))?
+            {
+                return Ok(sig);
+            }
+
+            // Stop once the blockhash has expired
+            match self.get_block_height().await {
+                Ok(block_height) if block_height > last_valid_block_height => {
+                    // The transaction may have landed in the last blocks it was valid for
+                    if self.is_confirmed(sig).await {
+                        return Ok(sig);
+                    }
+                    return Err(GatewayError::TransactionTimeout);
+                }
+                Ok(_) => {}
+                Err(err) => log::error!("Error fetching block height: {:?}", err),
+            }
+        }
+        Err(GatewayError::TransactionTimeout)
+    }
+
+    async fn is_confirmed(&self, sig: Signature) -> bool {
+        matches!(
+            self.get_signature_statuses(&[sig]).await.as_deref(),
+            Ok([Some(
+                TransactionConfirmationStatus::Confirmed | TransactionConfirmationStatus::Finalized
+            )])
+        )
+    }
+}
+
+/// Waits up to `delay_ms` for a confirmation notification, or polls once the delay
+/// has passed if the socket is down. Fails if the transaction confirmed with an error.
+async fn wait_for_confirmation<R: Rpc>(
+    rpc: &R,
+    watcher: &mut SignatureWatcher,
+    sig: Signature,
+    delay_ms: u64,
+    progress: &mut ConfirmationProgress,
+    on_progress: &mut impl FnMut(ConfirmationProgress),
+) -> Result<bool, TransactionError> {
+    match watcher.wait(delay_ms, progress, on_progress).await {
+        Some(confirmed) => confirmed,
+        None => {
+            async_std::task::sleep(crate::time::Duration::from_millis(delay_ms)).await;
+            Ok(rpc.is_confirmed(sig).await)
         }
-        return Err(GatewayError::TransactionTimeout);
     }
 }
@@ -0,0 +1,43 @@
+use ore_api_v2::{
+    consts::{BUS_ADDRESSES, CONFIG_ADDRESS},
+    state::{proof_pda, Bus, Config, Proof},
+};
+use solana_sdk::pubkey::Pubkey;
+use steel::AccountDeserialize;
+
+use super::{Gateway, GatewayError, GatewayResult, Rpc};
+
+/// Accounts of the ORE mining program, for mining without a pool.
+pub trait SoloGateway {
+    async fn get_proof(&self, authority: Pubkey) -> GatewayResult<Proof>;
+    async fn get_mining_config(&self) -> GatewayResult<Config>;
+    /// Returns the bus with the most rewards left in the current epoch.
+    async fn find_bus(&self) -> GatewayResult<Pubkey>;
+}
+
+impl<R: Rpc> SoloGateway for Gateway<R> {
+    async fn get_proof(&self, authority: Pubkey) -> GatewayResult<Proof> {
+        let data = self.get_account_data(&proof_pda(authority).0).await?;
+        Ok(*Proof::try_from_bytes(&data)?)
+    }
+
+    async fn get_mining_config(&self) -> GatewayResult<Config> {
+        let data = self.get_account_data(&CONFIG_ADDRESS).await?;
+        Ok(*Config::try_from_bytes(&data)?)
+    }
+
+    async fn find_bus(&self) -> GatewayResult<Pubkey> {
+        let accounts = self.get_multiple_accounts(&BUS_ADDRESSES).await?;
+        BUS_ADDRESSES
+            .iter()
+            .zip(accounts)
+            .filter_map(|(address, account)| {
+                let account = account?;
+                let bus = Bus::try_from_bytes(&account.data).ok()?;
+                Some((*address, bus.rewards))
+            })
+            .max_by_key(|(_, rewards)| *rewards)
+            .map(|(address, _)| address)
+            .ok_or(GatewayError::AccountNotFound)
+    }
+}
@@ -1,7 +1,7 @@
 use ore_api::consts::MINT_ADDRESS;
 use solana_sdk::{program_pack::Pack, pubkey::Pubkey};
 
-use super::{GatewayError, GatewayResult, Rpc, UiTokenAmount};
+use super::{commitment::commitment, GatewayError, GatewayResult, Rpc, UiTokenAmount};
 use crate::solana::{
     spl_associated_token_account::get_associated_token_address, spl_token::state::Mint,
 };
@@ -25,14 +25,14 @@ impl<R: Rpc> SplGateway for R {
         mint: &Pubkey,
     ) -> GatewayResult<UiTokenAmount> {
         let ata_address = get_associated_token_address(owner, &mint);
-        let Some(token_account) = self.get_token_account(&ata_address).await? else {
+        let Some(token_account) = self.get_token_account(&ata_address, commitment()).await? else {
             return Err(GatewayError::AccountNotFound.into());
         };
         Ok(token_account)
     }
 
     async fn get_mint(&self, mint: &Pubkey) -> GatewayResult<Mint> {
-        let mint = self.get_account_data(mint).await?;
+        let mint = self.get_account_data(mint, commitment()).await?;
         let mint = Mint::unpack(&mint.as_slice())?;
         Ok(mint)
     }
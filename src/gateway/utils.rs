@@ -21,13 +21,11 @@ where
         match timeout(TIMEOUT, f()).await {
             Ok(Ok(result)) => return Ok(result),
             Ok(Err(e)) if attempt < MAX_RETRIES - 1 => {
-                match e {
-                    GatewayError::AccountNotFound => return Err(e),
-                    _ => {
-                        async_std::task::sleep(backoff).await;
-                        backoff *= 2; // Exponential backoff
-                    }
+                if !e.is_retriable() {
+                    return Err(e);
                 }
+                async_std::task::sleep(backoff).await;
+                backoff *= 2; // Exponential backoff
             }
             Ok(Err(e)) => return Err(e),
             Err(_) if attempt < MAX_RETRIES - 1 => {
@@ -0,0 +1,149 @@
+use std::sync::Mutex;
+
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    FutureExt, StreamExt,
+};
+use once_cell::sync::Lazy;
+use solana_sdk::{signature::Signature, transaction::TransactionError};
+
+use crate::time::sleep;
+
+/// Progress of a submitted transaction toward confirmation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConfirmationProgress {
+    /// Number of broadcasts made so far.
+    pub attempt: u8,
+    /// Slots elapsed since the transaction was first watched.
+    pub slots: u64,
+    /// Whether the transaction landed in a block that is not yet confirmed.
+    pub processed: bool,
+}
+
+/// A request to follow a signature on the shared WebSocket.
+#[derive(Debug)]
+pub struct WatchRequest {
+    pub signature: Signature,
+    /// Receives the signature's events until the watcher is dropped.
+    pub events: UnboundedSender<SignatureEvent>,
+}
+
+/// An event of a watched signature, sent by the WebSocket worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureEvent {
+    /// The signature is subscribed on the current connection.
+    Subscribed,
+    /// The connection dropped. The signature is subscribed again once it is restored.
+    Disconnected,
+    /// The cluster reached a new slot.
+    Slot(u64),
+    /// The transaction landed in a block that is not yet confirmed.
+    Processed,
+    /// The transaction confirmed, with its error if it failed.
+    Confirmed(Option<TransactionError>),
+}
+
+static WATCH_REQUESTS: Lazy<Mutex<Option<UnboundedSender<WatchRequest>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Routes signature watchers to the WebSocket worker that serves them.
+pub fn set_signature_worker(requests: UnboundedSender<WatchRequest>) {
+    *WATCH_REQUESTS.lock().unwrap() = Some(requests);
+}
+
+/// Follows a submitted transaction through the shared WebSocket, using signature and slot
+/// subscriptions.
+pub struct SignatureWatcher {
+    events: Option<UnboundedReceiver<SignatureEvent>>,
+    subscribed: bool,
+    first_slot: Option<u64>,
+}
+
+impl SignatureWatcher {
+    /// Subscribes to a signature. While the socket is unavailable the watcher is disconnected,
+    /// and callers are expected to poll instead.
+    pub fn open(signature: &Signature) -> Self {
+        let (tx, rx) = mpsc::unbounded();
+        let request = WatchRequest {
+            signature: *signature,
+            events: tx,
+        };
+        let sent = match WATCH_REQUESTS.lock().unwrap().as_ref() {
+            Some(requests) => requests.unbounded_send(request).is_ok(),
+            None => false,
+        };
+        if !sent {
+            log::warn!("Signature subscription unavailable, polling instead");
+        }
+        SignatureWatcher {
+            events: sent.then_some(rx),
+            subscribed: false,
+            first_slot: None,
+        }
+    }
+
+    /// Waits up to `timeout_ms` for the transaction to confirm, updating `progress` as
+    /// notifications arrive. Returns the transaction error if it confirmed but failed, or
+    /// None if the socket is down.
+    pub async fn wait(
+        &mut self,
+        timeout_ms: u64,
+        progress: &mut ConfirmationProgress,
+        on_progress: &mut impl FnMut(ConfirmationProgress),
+    ) -> Option<Result<bool, TransactionError>> {
+        // Catch up on events received while polling
+        while let Some(Ok(Some(event))) = self.events.as_mut().map(|events| events.try_next()) {
+            if let Some(confirmed) = self.handle(event, progress, on_progress) {
+                return Some(confirmed);
+            }
+        }
+        let timeout = sleep(timeout_ms).fuse();
+        futures::pin_mut!(timeout);
+        loop {
+            if !self.subscribed {
+                return None;
+            }
+            let events = self.events.as_mut()?;
+            let event = futures::select! {
+                _ = timeout => return Some(Ok(false)),
+                event = events.next() => event,
+            };
+            let Some(event) = event else {
+                log::error!("Signature subscription dropped, polling instead");
+                self.events = None;
+                return None;
+            };
+            if let Some(confirmed) = self.handle(event, progress, on_progress) {
+                return Some(confirmed);
+            }
+        }
+    }
+
+    /// Applies an event, returning the outcome once the transaction confirmed.
+    fn handle(
+        &mut self,
+        event: SignatureEvent,
+        progress: &mut ConfirmationProgress,
+        on_progress: &mut impl FnMut(ConfirmationProgress),
+    ) -> Option<Result<bool, TransactionError>> {
+        match event {
+            SignatureEvent::Subscribed => self.subscribed = true,
+            SignatureEvent::Disconnected => self.subscribed = false,
+            SignatureEvent::Slot(slot) => {
+                let first_slot = *self.first_slot.get_or_insert(slot);
+                progress.slots = slot.saturating_sub(first_slot);
+                on_progress(*progress);
+            }
+            SignatureEvent::Processed => {
+                progress.processed = true;
+                on_progress(*progress);
+            }
+            SignatureEvent::Confirmed(Some(err)) => {
+                log::error!("Transaction failed: {:?}", err);
+                return Some(Err(err));
+            }
+            SignatureEvent::Confirmed(None) => return Some(Ok(true)),
+        }
+        None
+    }
+}
@@ -1,18 +1,24 @@
+mod confirmation;
 #[cfg(not(feature = "web"))]
 mod native;
 #[cfg(feature = "web")]
 mod web;
 
+pub use confirmation::*;
 #[cfg(not(feature = "web"))]
 pub use native::*;
 #[cfg(feature = "web")]
 pub use web::*;
 
 use async_trait::async_trait;
+use base64::{prelude::BASE64_STANDARD, Engine};
 use serde::{Deserialize, Serialize};
 use serde_json;
+use solana_sdk::{account::Account, transaction::TransactionError};
 use std::fmt::Debug;
 
+use super::commitment::{commitment, Commitment};
+
 ////////////////////////////////////////////////////////////////////////////
 // JSON‑RPC Types
 ////////////////////////////////////////////////////////////////////////////
@@ -49,10 +55,27 @@ pub(super) struct JsonRpcResponseWithError<T> {
 ////////////////////////////////////////////////////////////////////////////
 // Account Subscription Request/Response Types
 ////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Serialize, Deserialize)]
-pub(super) struct AccountSubscribeConfig {
+/// Subscriptions with equal configs on the same account share one server stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccountSubscribeConfig {
     pub encoding: String,
-    pub commitment: String,
+    pub commitment: Commitment,
+}
+
+impl AccountSubscribeConfig {
+    pub fn new(commitment: Commitment) -> Self {
+        Self {
+            encoding: "base64".to_string(),
+            commitment,
+        }
+    }
+}
+
+/// Subscribes at the app-wide commitment.
+impl Default for AccountSubscribeConfig {
+    fn default() -> Self {
+        Self::new(commitment())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////
@@ -93,11 +116,94 @@ pub struct AccountNotificationParams {
     pub subscription: u64,
 }
 
+impl AccountNotificationParams {
+    /// Wraps a fetched account as if it had been pushed by the server,
+    /// so polled accounts can be handled like notifications. The slot is left unset.
+    pub fn from_account(account: &Account, subscription: u64) -> Self {
+        AccountNotificationParams {
+            result: AccountNotificationResult {
+                context: AccountContext { slot: 0 },
+                value: AccountNotification {
+                    data: vec![BASE64_STANDARD.encode(&account.data), "base64".to_string()],
+                    executable: account.executable,
+                    lamports: account.lamports,
+                    owner: account.owner.to_string(),
+                    rent_epoch: account.rent_epoch,
+                    space: Some(account.data.len() as u64),
+                },
+            },
+            subscription,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////
+// Signature And Slot Subscription Types
+////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct SignatureSubscribeConfig {
+    pub commitment: Commitment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmationNotification {
+    /// The signature reached the commitment of the subscription, with the transaction error if it failed.
+    Signature {
+        subscription: u64,
+        err: Option<TransactionError>,
+    },
+    /// The cluster reached a new slot.
+    Slot(u64),
+}
+
+/// A notification pushed by the server for any kind of subscription.
+#[derive(Debug, Clone)]
+pub enum Notification {
+    Account(AccountNotificationParams),
+    Confirmation(ConfirmationNotification),
+}
+
+/// Parses a notification, ignoring responses and any other message.
+pub(super) fn parse_notification(text: &str) -> Option<Notification> {
+    let message = serde_json::from_str::<serde_json::Value>(text).ok()?;
+    let params = &message["params"];
+    match message["method"].as_str()? {
+        "accountNotification" => {
+            match serde_json::from_value::<AccountNotificationEnvelope>(message.clone()) {
+                Ok(notification) => Some(Notification::Account(notification.params)),
+                Err(e) => {
+                    log::error!("Failed to parse notification: {}, text: {}", e, text);
+                    None
+                }
+            }
+        }
+        "signatureNotification" => {
+            let value = &params["result"]["value"];
+            // Received notifications carry a string rather than the execution result
+            if !value.is_object() {
+                return None;
+            }
+            Some(Notification::Confirmation(
+                ConfirmationNotification::Signature {
+                    subscription: params["subscription"].as_u64()?,
+                    err: serde_json::from_value(value["err"].clone()).ok().flatten(),
+                },
+            ))
+        }
+        "slotNotification" => Some(Notification::Confirmation(ConfirmationNotification::Slot(
+            params["result"]["slot"].as_u64()?,
+        ))),
+        method => {
+            log::info!("Ignoring {} notification", method);
+            None
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////
 // Subscription Trait
 ////////////////////////////////////////////////////////////////////////////
 
-// TODO: reconnect logic
 #[cfg_attr(not(feature = "web"), async_trait)]
 #[cfg_attr(feature = "web", async_trait(?Send))]
 pub trait AccountSubscribe: Sized {
@@ -106,14 +212,29 @@ pub trait AccountSubscribe: Sized {
     async fn subscribe(
         &mut self,
         account: &str,
+        config: &AccountSubscribeConfig,
         request_id: u64,
     ) -> Result<Self::SubscriptionId, SubscriptionError>;
     async fn unsubscribe(
         &mut self,
         subscription: Self::SubscriptionId,
     ) -> Result<(), SubscriptionError>;
-    async fn next_notification(&mut self)
-        -> Result<AccountNotificationEnvelope, SubscriptionError>;
+    /// Waits for the next notification of any subscription on the connection.
+    async fn next_notification(&mut self) -> Result<Notification, SubscriptionError>;
+}
+
+#[cfg_attr(not(feature = "web"), async_trait)]
+#[cfg_attr(feature = "web", async_trait(?Send))]
+pub trait SignatureSubscribe: Sized {
+    async fn signature_subscribe(
+        &mut self,
+        signature: &str,
+        commitment: Commitment,
+        request_id: u64,
+    ) -> Result<u64, SubscriptionError>;
+    async fn signature_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError>;
+    async fn slot_subscribe(&mut self, request_id: u64) -> Result<u64, SubscriptionError>;
+    async fn slot_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError>;
 }
 
 #[derive(Debug)]
@@ -135,4 +256,11 @@ impl std::fmt::Display for SubscriptionError {
     }
 }
 
+impl SubscriptionError {
+    /// Whether the error means the underlying socket is no longer usable.
+    pub fn is_connection_error(&self) -> bool {
+        matches!(self, Self::ConnectionError(_) | Self::Other(_))
+    }
+}
+
 impl std::error::Error for SubscriptionError {}
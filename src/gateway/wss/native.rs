@@ -14,20 +14,27 @@ use tokio_tungstenite::{
 };
 use tungstenite::client::IntoClientRequest;
 
-use crate::gateway::WSS_URL;
+use crate::gateway::{commitment::Commitment, endpoints};
 
 use super::{
-    AccountNotificationEnvelope, AccountSubscribe, AccountSubscribeConfig, JsonRpcRequest,
-    JsonRpcResponse, JsonRpcResponseWithError, SubscriptionError,
+    parse_notification, AccountSubscribe, AccountSubscribeConfig, JsonRpcRequest, JsonRpcResponse,
+    JsonRpcResponseWithError, Notification, SignatureSubscribe, SignatureSubscribeConfig,
+    SubscriptionError,
 };
 
 /// WebSocket client for account subscriptions
 pub struct AccountSubscribeGateway {
+    url: String,
     writer: SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
     reader: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
 }
 
 impl AccountSubscribeGateway {
+    /// Returns the url of the endpoint this connection was opened against.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
     async fn send_request<T: Serialize>(
         &mut self,
         request: &JsonRpcRequest<T>,
@@ -73,31 +80,60 @@ impl AccountSubscribeGateway {
             "WebSocket stream ended unexpectedly".to_string(),
         ))
     }
+
+    /// Closes a subscription with the unsubscribe method of its kind.
+    async fn unsubscribe_with(
+        &mut self,
+        method: &str,
+        subscription: u64,
+    ) -> Result<(), SubscriptionError> {
+        let request_id = fastrand::u64(..);
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: method.to_string(),
+            params: (subscription,),
+        };
+        self.send_request(&request).await?;
+        let result = self.handle_response::<bool>(request_id).await?;
+        if result {
+            Ok(())
+        } else {
+            Err(SubscriptionError::RpcError(
+                "Unsubscribe failed".to_string(),
+            ))
+        }
+    }
 }
 
 #[async_trait]
 impl AccountSubscribe for AccountSubscribeGateway {
     type SubscriptionId = u64;
     async fn connect() -> Result<Self, SubscriptionError> {
-        let url_parsed = WSS_URL
+        let url = endpoints::wss_url();
+        let url_parsed = url
+            .as_str()
             .into_client_request()
             .map_err(|e| SubscriptionError::Other(e.to_string()))?;
-        let (ws_stream, _) = connect_async(url_parsed)
-            .await
-            .map_err(|e| SubscriptionError::ConnectionError(e.to_string()))?;
+        let (ws_stream, _) = connect_async(url_parsed).await.map_err(|e| {
+            endpoints::report_failure(&url);
+            SubscriptionError::ConnectionError(e.to_string())
+        })?;
+        endpoints::report_success(&url);
         let (writer, reader) = ws_stream.split();
-        Ok(Self { writer, reader })
+        Ok(Self {
+            url,
+            writer,
+            reader,
+        })
     }
 
     async fn subscribe(
         &mut self,
         account: &str,
+        config: &AccountSubscribeConfig,
         request_id: u64,
     ) -> Result<Self::SubscriptionId, SubscriptionError> {
-        let config = AccountSubscribeConfig {
-            encoding: "base64".to_string(),
-            commitment: "confirmed".to_string(),
-        };
         log::info!("request id: {}", request_id);
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
@@ -113,44 +149,16 @@ impl AccountSubscribe for AccountSubscribeGateway {
         &mut self,
         subscription: Self::SubscriptionId,
     ) -> Result<(), SubscriptionError> {
-        let request_id = fastrand::u64(..);
-        let request = JsonRpcRequest {
-            jsonrpc: "2.0".to_string(),
-            id: request_id,
-            method: "accountUnsubscribe".to_string(),
-            params: (subscription,),
-        };
-        self.send_request(&request).await?;
-        let result = self.handle_response::<bool>(request_id).await?;
-        if result {
-            log::info!("unsubcribed!");
-            Ok(())
-        } else {
-            Err(SubscriptionError::RpcError(
-                "Unsubscribe failed".to_string(),
-            ))
-        }
+        self.unsubscribe_with("accountUnsubscribe", subscription)
+            .await
     }
 
-    async fn next_notification(
-        &mut self,
-    ) -> Result<AccountNotificationEnvelope, SubscriptionError> {
+    async fn next_notification(&mut self) -> Result<Notification, SubscriptionError> {
         while let Some(msg) = self.reader.next().await {
             match msg {
                 Ok(Message::Text(text)) => {
-                    match serde_json::from_str::<AccountNotificationEnvelope>(&text) {
-                        Ok(notification) => {
-                            if notification.method == "accountNotification" {
-                                return Ok(notification);
-                            } else {
-                                log::info!("Ignoring non-account notification: {:?}", notification);
-                                continue;
-                            }
-                        }
-                        Err(e) => {
-                            log::error!("Failed to parse notification: {}, text: {}", e, text);
-                            continue;
-                        }
+                    if let Some(notification) = parse_notification(&text) {
+                        return Ok(notification);
                     }
                 }
                 Ok(msg) => {
@@ -165,3 +173,43 @@ impl AccountSubscribe for AccountSubscribeGateway {
         ))
     }
 }
+
+#[async_trait]
+impl SignatureSubscribe for AccountSubscribeGateway {
+    async fn signature_subscribe(
+        &mut self,
+        signature: &str,
+        commitment: Commitment,
+        request_id: u64,
+    ) -> Result<u64, SubscriptionError> {
+        let config = SignatureSubscribeConfig { commitment };
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: "signatureSubscribe".to_string(),
+            params: (signature.to_string(), config),
+        };
+        self.send_request(&request).await?;
+        self.handle_response(request_id).await
+    }
+
+    async fn signature_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError> {
+        self.unsubscribe_with("signatureUnsubscribe", subscription)
+            .await
+    }
+
+    async fn slot_subscribe(&mut self, request_id: u64) -> Result<u64, SubscriptionError> {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: "slotSubscribe".to_string(),
+            params: [(); 0],
+        };
+        self.send_request(&request).await?;
+        self.handle_response(request_id).await
+    }
+
+    async fn slot_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError> {
+        self.unsubscribe_with("slotUnsubscribe", subscription).await
+    }
+}
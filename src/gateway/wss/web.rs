@@ -5,20 +5,27 @@ use gloo_net::websocket::Message as GlooMessage;
 use serde::Serialize;
 use serde_json;
 
-use crate::gateway::WSS_URL;
+use crate::gateway::{commitment::Commitment, endpoints};
 
 use super::{
-    AccountNotificationEnvelope, AccountSubscribe, AccountSubscribeConfig, JsonRpcRequest,
-    JsonRpcResponse, JsonRpcResponseWithError, SubscriptionError,
+    parse_notification, AccountSubscribe, AccountSubscribeConfig, JsonRpcRequest, JsonRpcResponse,
+    JsonRpcResponseWithError, Notification, SignatureSubscribe, SignatureSubscribeConfig,
+    SubscriptionError,
 };
 
 /// WebSocket client for account subscriptions
 pub struct AccountSubscribeGateway {
+    url: String,
     writer: futures_util::stream::SplitSink<WebSocket, GlooMessage>,
     reader: futures_util::stream::SplitStream<WebSocket>,
 }
 
 impl AccountSubscribeGateway {
+    /// Returns the url of the endpoint this connection was opened against.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
     async fn send_request<T: Serialize>(
         &mut self,
         request: &JsonRpcRequest<T>,
@@ -62,6 +69,30 @@ impl AccountSubscribeGateway {
             "WebSocket stream ended unexpectedly".to_string(),
         ))
     }
+
+    /// Closes a subscription with the unsubscribe method of its kind.
+    async fn unsubscribe_with(
+        &mut self,
+        method: &str,
+        subscription: u64,
+    ) -> Result<(), SubscriptionError> {
+        let request_id = fastrand::u64(..);
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: method.to_string(),
+            params: (subscription,),
+        };
+        self.send_request(&request).await?;
+        let result = self.handle_response::<bool>(request_id).await?;
+        if result {
+            Ok(())
+        } else {
+            Err(SubscriptionError::RpcError(
+                "Unsubscribe failed".to_string(),
+            ))
+        }
+    }
 }
 
 #[cfg(feature = "web")]
@@ -70,21 +101,26 @@ impl AccountSubscribe for AccountSubscribeGateway {
     type SubscriptionId = u64;
 
     async fn connect() -> Result<Self, SubscriptionError> {
-        let ws = WebSocket::open(WSS_URL)
-            .map_err(|e| SubscriptionError::ConnectionError(format!("{:?}", e)))?;
+        let url = endpoints::wss_url();
+        let ws = WebSocket::open(url.as_str()).map_err(|e| {
+            endpoints::report_failure(&url);
+            SubscriptionError::ConnectionError(format!("{:?}", e))
+        })?;
+        endpoints::report_success(&url);
         let (writer, reader) = ws.split();
-        Ok(Self { writer, reader })
+        Ok(Self {
+            url,
+            writer,
+            reader,
+        })
     }
 
     async fn subscribe(
         &mut self,
         account: &str,
+        config: &AccountSubscribeConfig,
         request_id: u64,
     ) -> Result<Self::SubscriptionId, SubscriptionError> {
-        let config = AccountSubscribeConfig {
-            encoding: "base64".to_string(),
-            commitment: "confirmed".to_string(),
-        };
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
             id: request_id,
@@ -100,44 +136,16 @@ impl AccountSubscribe for AccountSubscribeGateway {
         &mut self,
         subscription: Self::SubscriptionId,
     ) -> Result<(), SubscriptionError> {
-        let request_id = fastrand::u64(..);
-        let request = JsonRpcRequest {
-            jsonrpc: "2.0".to_string(),
-            id: request_id,
-            method: "accountUnsubscribe".to_string(),
-            params: (subscription,),
-        };
-
-        self.send_request(&request).await?;
-        let result = self.handle_response::<bool>(request_id).await?;
-        if result {
-            Ok(())
-        } else {
-            Err(SubscriptionError::RpcError(
-                "Unsubscribe failed".to_string(),
-            ))
-        }
+        self.unsubscribe_with("accountUnsubscribe", subscription)
+            .await
     }
 
-    async fn next_notification(
-        &mut self,
-    ) -> Result<AccountNotificationEnvelope, SubscriptionError> {
+    async fn next_notification(&mut self) -> Result<Notification, SubscriptionError> {
         while let Some(msg) = self.reader.next().await {
             match msg {
                 Ok(GlooMessage::Text(text)) => {
-                    match serde_json::from_str::<AccountNotificationEnvelope>(&text) {
-                        Ok(notification) => {
-                            if notification.method == "accountNotification" {
-                                return Ok(notification);
-                            } else {
-                                log::info!("Ignoring non-account notification: {:?}", notification);
-                                continue;
-                            }
-                        }
-                        Err(e) => {
-                            log::error!("Failed to parse notification: {}, text: {}", e, text);
-                            continue;
-                        }
+                    if let Some(notification) = parse_notification(&text) {
+                        return Ok(notification);
                     }
                 }
                 Ok(msg) => {
@@ -152,3 +160,44 @@ impl AccountSubscribe for AccountSubscribeGateway {
         ))
     }
 }
+
+#[cfg(feature = "web")]
+#[async_trait(?Send)]
+impl SignatureSubscribe for AccountSubscribeGateway {
+    async fn signature_subscribe(
+        &mut self,
+        signature: &str,
+        commitment: Commitment,
+        request_id: u64,
+    ) -> Result<u64, SubscriptionError> {
+        let config = SignatureSubscribeConfig { commitment };
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: "signatureSubscribe".to_string(),
+            params: (signature.to_string(), config),
+        };
+        self.send_request(&request).await?;
+        self.handle_response(request_id).await
+    }
+
+    async fn signature_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError> {
+        self.unsubscribe_with("signatureUnsubscribe", subscription)
+            .await
+    }
+
+    async fn slot_subscribe(&mut self, request_id: u64) -> Result<u64, SubscriptionError> {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: "slotSubscribe".to_string(),
+            params: [(); 0],
+        };
+        self.send_request(&request).await?;
+        self.handle_response(request_id).await
+    }
+
+    async fn slot_unsubscribe(&mut self, subscription: u64) -> Result<(), SubscriptionError> {
+        self.unsubscribe_with("slotUnsubscribe", subscription).await
+    }
+}
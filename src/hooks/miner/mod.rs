@@ -1,17 +1,29 @@
 mod use_miner;
+mod use_miner_benchmark;
 mod use_miner_events;
 #[cfg(not(feature = "web"))]
 mod use_miner_native;
 #[cfg(feature = "web")]
 mod use_miner_web;
 mod use_mining_loop;
+mod use_mining_mode;
 mod use_pool;
+mod use_pool_failover;
+#[cfg(not(feature = "web"))]
+mod use_solo_mining_loop;
+mod use_solution_queue;
 
 pub use use_miner::*;
+pub use use_miner_benchmark::*;
 pub use use_miner_events::*;
 #[cfg(not(feature = "web"))]
 pub use use_miner_native::*;
 #[cfg(feature = "web")]
 pub use use_miner_web::*;
 pub use use_mining_loop::*;
+pub use use_mining_mode::*;
 pub use use_pool::*;
+pub use use_pool_failover::*;
+#[cfg(not(feature = "web"))]
+pub use use_solo_mining_loop::*;
+pub use use_solution_queue::*;
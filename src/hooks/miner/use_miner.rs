@@ -2,6 +2,8 @@ use dioxus::prelude::*;
 
 use ore_miner_types::{InputMessage, OutputMessage};
 
+use crate::time::Instant;
+
 type FromMiner = Signal<OutputMessage>;
 type ToMiner = Coroutine<InputMessage>;
 
@@ -11,6 +13,15 @@ pub fn use_miner() -> (FromMiner, ToMiner) {
     (from, to)
 }
 
+/// Stats and time remaining reported by the miner. Kept apart from the main channel, so
+/// frequent reports never overwrite a solution before it is handled.
+#[derive(Clone, Copy)]
+pub struct MinerProgress(pub Signal<OutputMessage>);
+
+pub fn use_miner_progress() -> Signal<OutputMessage> {
+    use_context::<MinerProgress>().0
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum MinerStatus {
     Stopped,
@@ -41,6 +52,110 @@ pub fn use_miner_is_active() -> Memo<bool> {
     })
 }
 
+/// Progress of the work in progress, as last reported by the miner.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MinerStats {
+    pub hashes: u64,
+    pub best_difficulty: u32,
+    /// Hashes computed on each core.
+    pub per_core: Vec<u64>,
+    /// Hashes per second since the previous report.
+    pub hashrate: f64,
+}
+
+/// Pauses and resumes the miner, and tracks its stats.
+#[derive(Clone, Copy)]
+pub struct MinerControl {
+    paused: Signal<bool>,
+    stats: Signal<Option<MinerStats>>,
+    to_miner: Coroutine<InputMessage>,
+}
+
+impl MinerControl {
+    pub fn is_paused(&self) -> bool {
+        *self.paused.read()
+    }
+
+    pub fn stats(&self) -> Option<MinerStats> {
+        self.stats.cloned()
+    }
+
+    /// Stops hashing until resumed. Challenges still expire on schedule while paused.
+    pub fn pause(&mut self) {
+        self.to_miner.send(InputMessage::Pause);
+        self.paused.set(true);
+    }
+
+    pub fn resume(&mut self) {
+        self.to_miner.send(InputMessage::Resume);
+        self.paused.set(false);
+    }
+}
+
+pub fn use_miner_control_provider() {
+    let (_from_miner, to_miner) = use_miner();
+    let progress = use_miner_progress();
+    let mut control = use_context_provider(|| MinerControl {
+        paused: Signal::new(false),
+        stats: Signal::new(None),
+        to_miner,
+    });
+
+    // Stop hashing as soon as the miner stops, rather than at the cutoff
+    let miner_status = use_miner_status();
+    use_effect(move || {
+        if *miner_status.read() != MinerStatus::Stopped {
+            return;
+        }
+        to_miner.send(InputMessage::Cancel);
+        if *control.paused.peek() {
+            control.resume();
+        }
+        control.stats.set(None);
+    });
+
+    // Derive the hashrate from consecutive reports
+    let mut last_report = use_signal::<Option<(u64, Instant)>>(|| None);
+    use_effect(move || {
+        let OutputMessage::Stats {
+            hashes,
+            best_difficulty,
+            per_core,
+        } = progress.cloned()
+        else {
+            return;
+        };
+        if *miner_status.peek() == MinerStatus::Stopped {
+            return;
+        }
+        let now = Instant::now();
+        let hashrate = match *last_report.peek() {
+            Some((last_hashes, last_at)) if hashes >= last_hashes => {
+                let seconds = now.duration_since(last_at).as_secs_f64().max(0.001);
+                (hashes - last_hashes) as f64 / seconds
+            }
+            // New work restarts the count
+            _ => control
+                .stats
+                .peek()
+                .as_ref()
+                .map(|stats| stats.hashrate)
+                .unwrap_or_default(),
+        };
+        last_report.set(Some((hashes, now)));
+        control.stats.set(Some(MinerStats {
+            hashes,
+            best_difficulty,
+            per_core,
+            hashrate,
+        }));
+    });
+}
+
+pub fn use_miner_control() -> MinerControl {
+    use_context()
+}
+
 pub fn use_miner_cores_provider() {
     use_context_provider(|| Signal::new(1usize));
 }
@@ -50,7 +165,7 @@ pub fn use_miner_cores() -> Signal<usize> {
 }
 
 pub fn _use_miner_cpu_utilization() -> Signal<Vec<f32>> {
-    let (from_miner, _to_miner) = use_miner();
+    let from_miner = use_miner_progress();
     let mut signal = use_signal(|| vec![]);
 
     // Always update the signal when we receive CPU utilization data
@@ -106,6 +221,7 @@ pub fn use_system_cpu_utilization() -> Signal<Vec<f32>> {
     // Web implementation with simulated values
     let mut signal = use_signal(|| vec![0.0; 12]).clone();
     let miner_is_active = use_miner_is_active();
+    let cores = use_miner_cores();
 
     // Set up web cpu usage with simulated values
     use_future(move || {
@@ -115,11 +231,14 @@ pub fn use_system_cpu_utilization() -> Signal<Vec<f32>> {
 
                 // Miner is active
                 if *miner_is_active.read() {
-                    // First core runs at ~70% (with some variation)
-                    new_values[0] = 65.0 + (rand::random::<f32>() * 10.0);
+                    // Each worker's core runs at ~70% (with some variation)
+                    let busy = cores.cloned().clamp(1, new_values.len() - 1);
+                    for i in 0..busy {
+                        new_values[i] = 65.0 + (rand::random::<f32>() * 10.0);
+                    }
 
                     // 2-3 additional cores hover around 10-30%
-                    for i in 1..4 {
+                    for i in busy..(busy + 3).min(new_values.len()) {
                         new_values[i] = 10.0 + (rand::random::<f32>() * 20.0);
                     }
                 } else {
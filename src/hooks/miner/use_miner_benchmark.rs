@@ -0,0 +1,140 @@
+use dioxus::prelude::*;
+use ore_miner_types::{
+    benchmark::{recommend_cores, BenchmarkSample},
+    InputMessage, OutputMessage,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hooks::use_miner,
+    time::{SystemTime, UNIX_EPOCH},
+    utils::{get_value, set_key_value},
+};
+
+const MINER_BENCHMARKS_KEY: &str = "miner_benchmarks";
+
+/// Completed benchmark runs kept in the history.
+const MAX_BENCHMARK_RUNS: usize = 10;
+
+/// Seconds spent hashing on each core count.
+const BENCHMARK_SECONDS: u64 = 5;
+
+/// Hashrates measured across core counts in one benchmark.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    pub timestamp: u64,
+    pub samples: Vec<BenchmarkSample>,
+}
+
+impl BenchmarkRun {
+    pub fn recommended_cores(&self) -> Option<usize> {
+        recommend_cores(&self.samples)
+    }
+
+    /// Hashrate measured on the given core count.
+    pub fn hashrate(&self, cores: usize) -> Option<f64> {
+        self.samples
+            .iter()
+            .find(|sample| sample.cores == cores)
+            .map(|sample| sample.hashrate())
+    }
+
+    pub fn best_difficulty(&self) -> u32 {
+        self.samples
+            .iter()
+            .map(|sample| sample.best_difficulty)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The benchmark in progress and the history of completed ones.
+#[derive(Clone, Copy)]
+pub struct MinerBenchmark {
+    /// Completed runs, newest first.
+    history: Signal<Vec<BenchmarkRun>>,
+    /// Run in progress, with the number of core counts it measures.
+    live: Signal<Option<(BenchmarkRun, usize)>>,
+}
+
+impl MinerBenchmark {
+    pub fn history(&self) -> Vec<BenchmarkRun> {
+        self.history.cloned()
+    }
+
+    pub fn latest(&self) -> Option<BenchmarkRun> {
+        self.history.read().first().cloned()
+    }
+
+    /// The run in progress and the number of core counts it measures.
+    pub fn live(&self) -> Option<(BenchmarkRun, usize)> {
+        self.live.cloned()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.live.read().is_some()
+    }
+
+    /// Measures the hashrate on each core count up to `max_cores`.
+    pub fn start(&mut self, to_miner: Coroutine<InputMessage>, max_cores: usize) {
+        if self.live.peek().is_some() {
+            return;
+        }
+        // the native miner measures at most u8::MAX core counts
+        let max_cores = max_cores.clamp(1, u8::MAX as usize);
+        let run = BenchmarkRun {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            samples: vec![],
+        };
+        self.live.set(Some((run, max_cores)));
+        to_miner.send(InputMessage::Benchmark {
+            seconds: BENCHMARK_SECONDS,
+            cores: max_cores,
+        });
+    }
+
+    fn record(&mut self, sample: BenchmarkSample) {
+        let Some((mut run, expected)) = self.live.peek().clone() else {
+            return;
+        };
+        run.samples.push(sample);
+        if run.samples.len() < expected {
+            self.live.set(Some((run, expected)));
+            return;
+        }
+
+        // Save the completed run
+        let mut history = self.history.peek().clone();
+        history.insert(0, run);
+        history.truncate(MAX_BENCHMARK_RUNS);
+        if let Err(err) = set_key_value(MINER_BENCHMARKS_KEY, &history) {
+            log::error!("Failed to persist miner benchmarks: {:?}", err);
+        }
+        self.history.set(history);
+        self.live.set(None);
+    }
+}
+
+pub fn use_miner_benchmark_provider() {
+    let mut benchmark = use_context_provider(|| MinerBenchmark {
+        history: Signal::new(
+            get_value::<Vec<BenchmarkRun>>(MINER_BENCHMARKS_KEY).unwrap_or_default(),
+        ),
+        live: Signal::new(None),
+    });
+
+    // Collect samples from the miner
+    let (from_miner, _to_miner) = use_miner();
+    use_effect(move || {
+        if let OutputMessage::Benchmark(sample) = *from_miner.read() {
+            benchmark.record(sample);
+        }
+    });
+}
+
+pub fn use_miner_benchmark() -> MinerBenchmark {
+    use_context()
+}
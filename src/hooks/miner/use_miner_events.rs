@@ -4,15 +4,22 @@ use solana_sdk::signature::Signature;
 use std::collections::VecDeque;
 
 pub fn use_miner_events_provider() {
-    use_context_provider(|| Signal::new(VecDeque::<MiningEvent>::new()));
+    use_context_provider(|| Signal::new(VecDeque::<MinerEvent>::new()));
 }
 
-pub fn use_miner_events() -> Signal<VecDeque<MiningEvent>> {
+pub fn use_miner_events() -> Signal<VecDeque<MinerEvent>> {
     use_context()
 }
 
-impl MiningEvent {
-    pub fn add_to_signal(event: MiningEvent) {
+/// An entry in the mining activity log.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MinerEvent {
+    Mining(MiningEvent),
+    PoolSwitch(PoolSwitchEvent),
+}
+
+impl MinerEvent {
+    fn add_to_signal(event: MinerEvent) {
         let mut events = use_miner_events();
         let mut new_events = events.read().clone();
 
@@ -27,6 +34,19 @@ impl MiningEvent {
         *events.write() = new_events;
     }
 }
+
+impl MiningEvent {
+    pub fn add_to_signal(event: MiningEvent) {
+        MinerEvent::add_to_signal(MinerEvent::Mining(event));
+    }
+}
+
+impl PoolSwitchEvent {
+    pub fn add_to_signal(event: PoolSwitchEvent) {
+        MinerEvent::add_to_signal(MinerEvent::PoolSwitch(event));
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct MiningEvent {
     pub signature: Signature,
@@ -38,3 +58,20 @@ pub struct MiningEvent {
     pub member_difficulty: u64,
     pub member_reward: u64,
 }
+
+/// The miner moved to another pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolSwitchEvent {
+    pub from: String,
+    pub to: String,
+    pub reason: PoolSwitchReason,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolSwitchReason {
+    /// The pool being mined kept failing.
+    Failover,
+    /// The selected pool is healthy again.
+    Recovered,
+}
@@ -2,11 +2,19 @@ use std::sync::Arc;
 
 use dioxus::prelude::*;
 
-use anyhow::Result;
-use drillx::{equix, Solution};
 use futures::StreamExt;
-use ore_miner_types::{InputMessage, OutputMessage};
-use tokio::sync::Mutex;
+use ore_miner_types::{
+    benchmark::BenchmarkSample,
+    hash::{benchmark, find_hash_par, MinerControl},
+    nonce::{nonce_indices, solo_nonce_indices},
+    InputMessage, OutputMessage,
+};
+use tokio::sync::{mpsc::UnboundedReceiver, Mutex};
+
+use crate::hooks::MinerProgress;
+
+/// Work in progress: the controls of its threads and the channel they report on.
+type Job = (MinerControl, UnboundedReceiver<OutputMessage>);
 
 /// two way channel between us and miner
 pub fn use_miner_provider() {
@@ -15,78 +23,115 @@ pub fn use_miner_provider() {
     let sys = Arc::new(Mutex::new(sys));
     // from miner receiver
     let mut from_miner = use_context_provider(|| Signal::new(OutputMessage::Init));
+    // from miner progress reports
+    let mut progress = use_context_provider(|| MinerProgress(Signal::new(OutputMessage::Init))).0;
     // to miner sender
     let _to_miner: Coroutine<InputMessage> = use_coroutine(
         move |mut rx: dioxus::prelude::UnboundedReceiver<InputMessage>| {
             let sys = Arc::clone(&sys);
             async move {
-                // build continuous solutions channel
-                let (sender, mut receiver) =
-                    tokio::sync::mpsc::unbounded_channel::<OutputMessage>();
-                // poll for messages from controller
-                while let Some(msg) = rx.next().await {
-                    // spawn miner
-                    let sender = sender.clone();
-                    tokio::spawn(async move {
-                        let device_id = 0;
-                        let cores = msg.cores as u8;
-                        let challenge = msg.challenge.challenge;
-                        // build nonce space
-                        match nonce_indices(&msg.member, &msg.challenge, cores, device_id) {
-                            Ok(nonce_indices) => {
-                                // spawn miner threads
-                                if let Err(err) = find_hash_par(
-                                    &challenge.challenge,
-                                    challenge.lash_hash_at,
-                                    nonce_indices.as_slice(),
-                                    msg.cutoff_time as u64,
-                                    challenge.min_difficulty as u32,
-                                    cores,
-                                    &sender,
-                                )
-                                .await
-                                {
-                                    log::error!("{:?}", err);
+                let mut job: Option<Job> = None;
+                let mut paused = false;
+                let mut best_difficulty = 0;
+                let mut benchmark_run: Option<UnboundedReceiver<BenchmarkSample>> = None;
+                loop {
+                    tokio::select! {
+                        // poll for messages from controller
+                        msg = rx.next() => {
+                            let Some(msg) = msg else {
+                                break;
+                            };
+                            match msg {
+                                InputMessage::Pause => {
+                                    paused = true;
+                                    if let Some((control, _)) = &job {
+                                        control.pause();
+                                    }
+                                }
+                                InputMessage::Resume => {
+                                    paused = false;
+                                    if let Some((control, _)) = &job {
+                                        control.resume();
+                                    }
+                                }
+                                InputMessage::Cancel => {
+                                    if let Some((control, _)) = job.take() {
+                                        control.cancel();
+                                    }
+                                }
+                                InputMessage::Benchmark { seconds, cores } => {
+                                    if benchmark_run.is_some() {
+                                        log::warn!("benchmark already running");
+                                    } else {
+                                        // the benchmark needs every core to itself
+                                        if let Some((control, _)) = job.take() {
+                                            control.cancel();
+                                        }
+                                        benchmark_run = Some(start_benchmark(seconds, cores));
+                                    }
+                                }
+                                msg if benchmark_run.is_some() => {
+                                    log::warn!("benchmark running, ignoring {:?}", msg);
+                                }
+                                msg => {
+                                    // new work replaces the work in progress
+                                    if let Some((control, _)) = job.take() {
+                                        control.cancel();
+                                    }
+                                    match start_job(msg, paused).await {
+                                        Ok(new_job) => {
+                                            job = Some(new_job);
+                                            best_difficulty = 0;
+                                        }
+                                        Err(err) => log::error!("{:?}", err),
+                                    }
                                 }
-                            }
-                            Err(err) => {
-                                log::error!("{:?}", err);
                             }
                         }
-                    });
-                    // listen for solutions from miner
-                    let mut best_difficulty = 0;
-                    while let Some(msg) = receiver.recv().await {
-                        // submit best solutions
-                        if let OutputMessage::Solution(solution) = msg {
-                            let difficulty = solution.to_hash().difficulty();
-                            // submit
-                            if difficulty.gt(&best_difficulty) {
-                                from_miner.set(msg.clone());
-                                best_difficulty = difficulty;
+                        // listen for solutions from miner
+                        Some(msg) = next_output(&mut job) => {
+                            match msg {
+                                // submit best solutions
+                                OutputMessage::Solution(solution) => {
+                                    let difficulty = solution.to_hash().difficulty();
+                                    if difficulty.gt(&best_difficulty) {
+                                        from_miner.set(msg);
+                                        best_difficulty = difficulty;
+                                    }
+                                }
+                                // done once expired
+                                OutputMessage::Expired(_) => {
+                                    from_miner.set(msg);
+                                    job = None;
+                                }
+                                // time remaining
+                                OutputMessage::TimeRemaining(seconds, _) => {
+                                    // sleep to allow solution submissions to process
+                                    tokio::time::sleep(tokio::time::Duration::from_millis(200))
+                                        .await;
+                                    // check cpu utilization
+                                    let cpus = {
+                                        let mut sys = sys.lock().await;
+                                        sys.refresh_cpu_usage();
+                                        sys.cpus()
+                                            .into_iter()
+                                            .map(|cpu| cpu.cpu_usage())
+                                            .collect::<Vec<_>>()
+                                    };
+                                    // send cpu utilization
+                                    let msg = OutputMessage::TimeRemaining(seconds, cpus);
+                                    progress.set(msg);
+                                }
+                                OutputMessage::Stats { .. } => progress.set(msg),
+                                _ => {}
                             }
                         }
-                        // exit if expired
-                        if let OutputMessage::Expired(_) = msg {
-                            from_miner.set(msg.clone());
-                            break;
-                        }
-                        // time remaining
-                        if let OutputMessage::TimeRemaining(seconds, _) = msg {
-                            // sleep to allow solution submissions to process
-                            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-                            // check cpu utilization
-                            let cpus = {
-                                let mut sys = sys.lock().await;
-                                sys.refresh_cpu_usage();
-                                sys.cpus()
-                                    .into_iter()
-                                    .map(|cpu| cpu.cpu_usage())
-                                    .collect::<Vec<_>>()
-                            };
-                            // send cpu utilization
-                            let msg = OutputMessage::TimeRemaining(seconds, cpus);
-                            from_miner.set(msg);
+                        // listen for benchmark samples
+                        sample = next_sample(&mut benchmark_run), if benchmark_run.is_some() => {
+                            match sample {
+                                Some(sample) => from_miner.set(OutputMessage::Benchmark(sample)),
+                                None => benchmark_run = None,
+                            }
                         }
                     }
                 }
@@ -95,118 +140,121 @@ pub fn use_miner_provider() {
     );
 }
 
-async fn find_hash_par(
-    challenge: &[u8; 32],
-    last_hash_at: i64,
-    nonce_indices: &[u64],
-    cutoff_time: u64,
-    min_difficulty: u32,
-    cores: u8,
-    solutions_channel: &tokio::sync::mpsc::UnboundedSender<OutputMessage>,
-) -> Result<()> {
-    // get cores
-    let core_ids = core_affinity::get_core_ids().ok_or(anyhow::anyhow!("failed to query cores"))?;
-    let core_ids = core_ids.into_iter().filter(|id| id.id < (cores as usize));
-    // distribute
-    for core_id in core_ids {
-        let challenge = *challenge;
-        std::thread::spawn({
-            // init drillx solver
-            let mut memory = drillx::equix::SolverMemory::new();
-            let solutions_channel = solutions_channel.clone();
-            let nonce = nonce_indices[core_id.id];
-            move || {
-                // pin to core
-                let _ = core_affinity::set_for_current(core_id);
-                // start hashing
-                let timer = std::time::Instant::now();
-                let mut nonce = nonce;
-                let mut best_difficulty = 0;
-                loop {
-                    // get hashes
-                    let hxs = solve(&mut memory, &challenge, &nonce.to_le_bytes());
-                    // look for best difficulty score in all hashes
-                    for hx in hxs {
-                        let difficulty = hx.difficulty();
-                        if difficulty.gt(&best_difficulty) {
-                            best_difficulty = difficulty;
-                            // continously submit best solution to pool
-                            if difficulty.ge(&min_difficulty) {
-                                let diggest = hx.d;
-                                let nonce = nonce.to_le_bytes();
-                                let solution = Solution {
-                                    d: diggest,
-                                    n: nonce,
-                                };
-                                let solution = OutputMessage::Solution(solution);
-                                if let Err(err) = solutions_channel.send(solution) {
-                                    log::error!("{:?}", err);
-                                }
-                            }
-                        }
-                    }
-                    // exit if time has elapsed
-                    if nonce % 100 == 0 {
-                        if timer.elapsed().as_secs().ge(&cutoff_time) {
-                            // send expiration message
-                            if core_id.id == 0 {
-                                let expired = OutputMessage::Expired(last_hash_at);
-                                if let Err(err) = solutions_channel.send(expired) {
-                                    log::error!("{:?}", err);
-                                }
-                            }
-                            break;
-                        } else if core_id.id == 0 {
-                            let remaining = cutoff_time.saturating_sub(timer.elapsed().as_secs());
-                            if let Err(err) = solutions_channel
-                                .send(OutputMessage::TimeRemaining(remaining as i64, vec![]))
-                            {
-                                log::error!("{:?}", err);
-                            }
-                        }
+/// Spawns the miner threads for a challenge.
+async fn start_job(msg: InputMessage, paused: bool) -> anyhow::Result<Job> {
+    let device_id = 0;
+    // build nonce space
+    let (challenge, last_hash_at, nonce_indices, cutoff_time, min_difficulty, cores) = match msg {
+        InputMessage::Pool {
+            member,
+            challenge,
+            cutoff_time,
+            cores,
+        } => {
+            let cores = cores as u8;
+            let nonce_indices = nonce_indices(&member, &challenge, cores, device_id)?;
+            let challenge = challenge.challenge;
+            (
+                challenge.challenge,
+                challenge.lash_hash_at,
+                nonce_indices,
+                cutoff_time,
+                challenge.min_difficulty as u32,
+                cores,
+            )
+        }
+        InputMessage::Solo {
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            cores,
+        } => {
+            let cores = cores as u8;
+            (
+                challenge,
+                last_hash_at,
+                solo_nonce_indices(cores),
+                cutoff_time,
+                min_difficulty,
+                cores,
+            )
+        }
+        InputMessage::Nonces {
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            nonce,
+        } => (
+            challenge,
+            last_hash_at,
+            vec![nonce],
+            cutoff_time,
+            min_difficulty as u32,
+            1,
+        ),
+        msg => return Err(anyhow::anyhow!("not a challenge: {:?}", msg)),
+    };
+    // spawn miner threads
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<OutputMessage>();
+    let control = MinerControl::new(cores, paused);
+    find_hash_par(
+        &challenge,
+        last_hash_at,
+        nonce_indices.as_slice(),
+        cutoff_time as u64,
+        min_difficulty,
+        cores,
+        &control,
+        &sender,
+    )
+    .await?;
+    Ok((control, receiver))
+}
+
+/// Measures each core count in turn on a background task, reporting samples on the returned
+/// channel. The channel closes once every core count is measured.
+fn start_benchmark(seconds: u64, cores: usize) -> UnboundedReceiver<BenchmarkSample> {
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<BenchmarkSample>();
+    let duration = tokio::time::Duration::from_secs(seconds);
+    tokio::spawn(async move {
+        for n in 1..=cores.min(u8::MAX as usize) as u8 {
+            let sample = match tokio::task::spawn_blocking(move || benchmark(n, duration)).await {
+                Ok(sample) => sample,
+                Err(err) => {
+                    log::error!("{:?}", err);
+                    // an empty sample still completes the run
+                    BenchmarkSample {
+                        cores: n as usize,
+                        hashes: 0,
+                        elapsed_ms: 0,
+                        best_difficulty: 0,
                     }
-                    nonce = nonce.wrapping_add(1);
                 }
+            };
+            if sender.send(sample).is_err() {
+                break;
             }
-        });
-    }
-    Ok(())
+        }
+    });
+    receiver
 }
 
-fn nonce_indices(
-    member: &ore_pool_types::Member,
-    challenge: &ore_pool_types::MemberChallenge,
-    cores: u8,
-    device_id: u8,
-) -> Result<Vec<u64>> {
-    // build nonce indices
-    let cores = cores as u64;
-    let num_total_members = challenge.num_total_members.max(1);
-    let member_search_space_size = u64::MAX.saturating_div(num_total_members);
-    let device_search_space_size =
-        member_search_space_size.saturating_div(challenge.num_devices as u64);
-    // check device id doesn't go beyond pool limit
-    if device_id > challenge.num_devices {
-        return Err(anyhow::anyhow!("too many devices"));
+/// Waits for the next sample from the benchmark in progress, if any.
+async fn next_sample(
+    benchmark_run: &mut Option<UnboundedReceiver<BenchmarkSample>>,
+) -> Option<BenchmarkSample> {
+    match benchmark_run {
+        Some(receiver) => receiver.recv().await,
+        None => std::future::pending().await,
     }
-    // calculate bounds on nonce space
-    let left_bound = member_search_space_size.saturating_mul(member.id as u64)
-        + (device_id as u64).saturating_mul(device_search_space_size);
-    // split nonce-device space for muliple cores
-    let range_per_core = device_search_space_size.saturating_div(cores);
-    let mut nonce_indices = Vec::with_capacity(cores as usize);
-    for n in 0..(cores) {
-        let index = left_bound.saturating_add(n.saturating_mul(range_per_core));
-        nonce_indices.push(index);
-    }
-    Ok(nonce_indices)
 }
 
-#[inline(always)]
-fn solve(
-    mem: &mut equix::SolverMemory,
-    challenge: &[u8; 32],
-    nonce: &[u8; 8],
-) -> Vec<drillx::Hash> {
-    drillx::hashes_with_memory(mem, challenge, nonce)
+/// Waits for the next message from the work in progress, if any.
+async fn next_output(job: &mut Option<Job>) -> Option<OutputMessage> {
+    match job {
+        Some((_, receiver)) => receiver.recv().await,
+        None => std::future::pending().await,
+    }
 }
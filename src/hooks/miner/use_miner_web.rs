@@ -1,41 +1,310 @@
 use dioxus::prelude::*;
-use futures::StreamExt;
+use futures::{stream, StreamExt};
 use gloo_utils::window;
-use gloo_worker::Spawnable;
+use gloo_worker::{Spawnable, WorkerBridge};
 use js_sys::Array;
-use ore_miner_types::OutputMessage;
+use ore_miner_types::{
+    benchmark::BenchmarkSample,
+    nonce::{nonce_indices, solo_nonce_indices},
+    InputMessage, OutputMessage, PROTOCOL_VERSION,
+};
 use ore_miner_web::Miner;
 use web_sys::{Blob, BlobPropertyBag, Url};
 
+use crate::hooks::MinerProgress;
+
 const JS: Asset = asset!(
     "/public/miner.js",
     AssetOptions::Js(JsAssetOptions::new().with_minify(false))
 );
 const WASM: Asset = asset!("/public/miner_bg.wasm");
 
-/// two way channel between us and miner (web worker)
+/// A message from the app, or from the worker at the given index.
+enum WorkerEvent {
+    Input(InputMessage),
+    Output(usize, OutputMessage),
+}
+
+/// A challenge split across workers.
+struct Job {
+    workers: usize,
+    last_hash_at: i64,
+    best_difficulty: u32,
+    /// Hashes reported by each worker.
+    per_core: Vec<u64>,
+    stats_best_difficulty: u32,
+}
+
+/// A benchmark measuring one worker count at a time.
+struct Benchmark {
+    seconds: u64,
+    max_cores: usize,
+    cores: usize,
+    samples: Vec<BenchmarkSample>,
+}
+
+/// two way channel between us and miner (one web worker per core)
 pub fn use_miner_provider() {
     // from miner receiver
     let mut from_miner = use_context_provider(|| Signal::new(OutputMessage::Init));
+    // from miner progress reports
+    let mut progress = use_context_provider(|| MinerProgress(Signal::new(OutputMessage::Init))).0;
     // to miner sender
-    let _to_miner = use_coroutine(move |mut rx| async move {
+    let _to_miner = use_coroutine(move |rx| async move {
+        // merge messages from us and from every worker into one queue
+        let (worker_tx, worker_rx) = futures::channel::mpsc::unbounded::<(usize, OutputMessage)>();
+        let mut events = stream::select(
+            rx.map(WorkerEvent::Input),
+            worker_rx.map(|(index, msg)| WorkerEvent::Output(index, msg)),
+        );
+        let shim_url = shim_url();
+        let mut workers: Vec<WorkerBridge<Miner>> = vec![];
+        let mut job: Option<Job> = None;
+        let mut benchmark: Option<Benchmark> = None;
+        while let Some(event) = events.next().await {
+            match event {
+                WorkerEvent::Input(msg) => {
+                    log::info!("to workers: {:?}", msg);
+                    match msg {
+                        InputMessage::Pause => {
+                            workers.iter().for_each(|w| w.send(InputMessage::Pause));
+                        }
+                        InputMessage::Resume => {
+                            workers.iter().for_each(|w| w.send(InputMessage::Resume));
+                        }
+                        InputMessage::Cancel => {
+                            job = None;
+                            workers.iter().for_each(|w| w.send(InputMessage::Cancel));
+                        }
+                        InputMessage::Benchmark { seconds, cores } => {
+                            let max_cores = cores.max(1);
+                            spawn_workers(&mut workers, max_cores, &worker_tx, &shim_url);
+                            workers[0].send(InputMessage::Benchmark { seconds, cores: 1 });
+                            benchmark = Some(Benchmark {
+                                seconds,
+                                max_cores,
+                                cores: 1,
+                                samples: vec![],
+                            });
+                        }
+                        msg if benchmark.is_some() => {
+                            log::warn!("benchmark running, ignoring {:?}", msg);
+                        }
+                        msg => match split(msg) {
+                            Ok((last_hash_at, msgs)) => {
+                                let count = msgs.len();
+                                spawn_workers(&mut workers, count, &worker_tx, &shim_url);
+                                for (worker, msg) in workers.iter().zip(msgs) {
+                                    worker.send(msg);
+                                }
+                                // workers left over from a larger core count sit idle
+                                for worker in workers.iter().skip(count) {
+                                    worker.send(InputMessage::Cancel);
+                                }
+                                job = Some(Job {
+                                    workers: count,
+                                    last_hash_at,
+                                    best_difficulty: 0,
+                                    per_core: vec![0; count],
+                                    stats_best_difficulty: 0,
+                                });
+                            }
+                            Err(err) => log::error!("{:?}", err),
+                        },
+                    }
+                }
+                WorkerEvent::Output(index, msg) => match msg {
+                    // the worker is built separately and may be stale
+                    OutputMessage::Ready(version) => {
+                        if version != PROTOCOL_VERSION {
+                            log::error!(
+                                "Miner worker implements protocol version {}, expected {}",
+                                version,
+                                PROTOCOL_VERSION
+                            );
+                        }
+                    }
+                    OutputMessage::Benchmark(sample) => {
+                        let Some(current) = &mut benchmark else {
+                            continue;
+                        };
+                        current.samples.push(sample);
+                        if current.samples.len() < current.cores {
+                            continue;
+                        }
+                        // the workers ran side by side, so their hashes add up
+                        let combined = BenchmarkSample {
+                            cores: current.cores,
+                            hashes: current.samples.iter().map(|s| s.hashes).sum(),
+                            elapsed_ms: current
+                                .samples
+                                .iter()
+                                .map(|s| s.elapsed_ms)
+                                .max()
+                                .unwrap_or(0),
+                            best_difficulty: current
+                                .samples
+                                .iter()
+                                .map(|s| s.best_difficulty)
+                                .max()
+                                .unwrap_or(0),
+                        };
+                        from_miner.set(OutputMessage::Benchmark(combined));
+                        if current.cores < current.max_cores {
+                            current.cores += 1;
+                            current.samples.clear();
+                            for worker in workers.iter().take(current.cores) {
+                                worker.send(InputMessage::Benchmark {
+                                    seconds: current.seconds,
+                                    cores: 1,
+                                });
+                            }
+                        } else {
+                            benchmark = None;
+                        }
+                    }
+                    msg => {
+                        let Some(current) = &mut job else {
+                            continue;
+                        };
+                        if index >= current.workers {
+                            continue;
+                        }
+                        match msg {
+                            // submit best solutions
+                            OutputMessage::Solution(solution) => {
+                                let difficulty = solution.to_hash().difficulty();
+                                if difficulty > current.best_difficulty {
+                                    current.best_difficulty = difficulty;
+                                    from_miner.set(msg);
+                                }
+                            }
+                            // every worker expires, the first one ends the job
+                            OutputMessage::Expired(last_hash_at) => {
+                                if last_hash_at == current.last_hash_at {
+                                    job = None;
+                                    from_miner.set(msg);
+                                }
+                            }
+                            // all workers share the cutoff
+                            OutputMessage::TimeRemaining(..) => {
+                                if index == 0 {
+                                    progress.set(msg);
+                                }
+                            }
+                            OutputMessage::Stats {
+                                hashes,
+                                best_difficulty,
+                                ..
+                            } => {
+                                current.per_core[index] = hashes;
+                                current.stats_best_difficulty =
+                                    current.stats_best_difficulty.max(best_difficulty);
+                                // report once per round of stats
+                                if index == 0 {
+                                    progress.set(OutputMessage::Stats {
+                                        hashes: current.per_core.iter().sum(),
+                                        best_difficulty: current.stats_best_difficulty,
+                                        per_core: current.per_core.clone(),
+                                    });
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                },
+            }
+        }
+    });
+}
+
+/// Spawns workers until there are `count` of them.
+fn spawn_workers(
+    workers: &mut Vec<WorkerBridge<Miner>>,
+    count: usize,
+    worker_tx: &futures::channel::mpsc::UnboundedSender<(usize, OutputMessage)>,
+    shim_url: &str,
+) {
+    while workers.len() < count {
+        let index = workers.len();
+        let worker_tx = worker_tx.clone();
         // callback for miner to send messages back to us
-        let mut spawner = Miner::spawner();
-        let miner = spawner
+        let worker = Miner::spawner()
             .callback(move |msg| {
-                log::info!("from worker: {:?}", msg);
-                wasm_bindgen_futures::spawn_local(async move {
-                    from_miner.set(msg);
-                })
+                log::info!("from worker {}: {:?}", index, msg);
+                if let Err(err) = worker_tx.unbounded_send((index, msg)) {
+                    log::error!("{:?}", err);
+                }
             })
-            .spawn_with_loader(shim_url().as_str());
+            .spawn_with_loader(shim_url);
+        workers.push(worker);
+    }
+}
 
-        // queue for miner to listen for messages from us
-        while let Some(msg) = rx.next().await {
-            log::info!("to worker: {:?}", msg);
-            miner.send(msg);
+/// Splits a challenge into one message per worker, each searching its own nonce range.
+/// Returns the challenge's last hash along with the messages.
+fn split(msg: InputMessage) -> anyhow::Result<(i64, Vec<InputMessage>)> {
+    let device_id = 0;
+    let (challenge, last_hash_at, min_difficulty, cutoff_time, nonce_indices) = match msg {
+        InputMessage::Pool {
+            member,
+            challenge,
+            cutoff_time,
+            cores,
+        } => {
+            let cores = cores.clamp(1, u8::MAX as usize) as u8;
+            let nonce_indices = nonce_indices(&member, &challenge, cores, device_id)?;
+            let challenge = challenge.challenge;
+            (
+                challenge.challenge,
+                challenge.lash_hash_at,
+                challenge.min_difficulty,
+                cutoff_time,
+                nonce_indices,
+            )
         }
-    });
+        InputMessage::Solo {
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            cores,
+        } => {
+            let cores = cores.clamp(1, u8::MAX as usize) as u8;
+            (
+                challenge,
+                last_hash_at,
+                min_difficulty as u64,
+                cutoff_time,
+                solo_nonce_indices(cores),
+            )
+        }
+        InputMessage::Nonces {
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            nonce,
+        } => (
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            vec![nonce],
+        ),
+        msg => return Err(anyhow::anyhow!("not a challenge: {:?}", msg)),
+    };
+    let msgs = nonce_indices
+        .into_iter()
+        .map(|nonce| InputMessage::Nonces {
+            challenge,
+            last_hash_at,
+            min_difficulty,
+            cutoff_time,
+            nonce,
+        })
+        .collect();
+    Ok((last_hash_at, msgs))
 }
 
 fn shim_url() -> String {
@@ -9,12 +9,24 @@ use crate::{
     gateway::{pool::PoolGateway, GatewayResult},
     hooks::{
         use_gateway, use_member_record, use_member_record_balance, use_miner, use_miner_is_active,
-        use_miner_status, use_pool_url, use_wallet, GetPubkey, MinerStatus, MiningEvent,
+        use_miner_status, use_mining_mode, use_pool_failover, use_pool_failover_loop, use_pool_url,
+        use_solution_queue, use_wallet, GetPubkey, MinerStatus, MiningEvent, MiningMode,
+        SubmissionOutcome,
     },
+    time::sleep,
 };
 
 use super::use_miner_cores;
 
+/// Interval between checks for solutions due for submission.
+const SUBMIT_POLL_INTERVAL_MS: u64 = 100;
+
+/// Delay before polling a failing pool again, doubled after each failure.
+const CHALLENGE_RETRY_MS: u64 = 1_000;
+
+/// Upper bound of the delay between polls of a failing pool.
+const MAX_CHALLENGE_RETRY_MS: u64 = 30_000;
+
 pub fn use_mining_loop() {
     // Miner pub/sub channels
     let (from_miner, mut to_miner) = use_miner();
@@ -24,6 +36,14 @@ pub fn use_mining_loop() {
     let challenge = use_next_challenge(last_hash_at);
     use_challenge_dispatch(challenge, to_miner);
     use_solution_contribute(last_hash_at, from_miner);
+    use_solution_submit();
+
+    // Switch pools while the current one keeps failing
+    use_pool_failover_loop();
+
+    // Mine against the ORE program directly
+    #[cfg(not(feature = "web"))]
+    super::use_solo_mining_loop();
 
     // Restart miner coroutine
     let is_active = use_miner_is_active();
@@ -34,7 +54,10 @@ pub fn use_mining_loop() {
     });
 }
 
-fn use_next_challenge(last_hash_at: Signal<i64>) -> Resource<GatewayResult<MemberChallenge>> {
+/// Polls the pool for the next challenge. Resolves to None outside of pool mining.
+fn use_next_challenge(
+    last_hash_at: Signal<i64>,
+) -> Resource<Option<GatewayResult<MemberChallenge>>> {
     let pool_url = use_pool_url();
     let member_record = use_member_record();
 
@@ -47,39 +70,64 @@ fn use_next_challenge(last_hash_at: Signal<i64>) -> Resource<GatewayResult<Membe
 
     let is_active = use_miner_is_active();
     let mut miner_status = use_miner_status();
+    let mut failover = use_pool_failover();
+    let mining_mode = use_mining_mode();
 
     use_resource(move || async move {
+        if *mining_mode.read() != MiningMode::Pool {
+            return None;
+        }
         let Some(member_authority) = member_authority.cloned() else {
-            return Err(crate::gateway::GatewayError::AccountNotFound);
+            return Some(Err(crate::gateway::GatewayError::AccountNotFound));
         };
         let Some(pool_url) = pool_url.cloned() else {
-            return Err(crate::gateway::GatewayError::AccountNotFound);
+            return Some(Err(crate::gateway::GatewayError::AccountNotFound));
         };
         if !*is_active.read() {
-            return Err(crate::gateway::GatewayError::Unknown);
+            return Some(Err(crate::gateway::GatewayError::Unknown));
         }
 
         let last_hash_at = *last_hash_at.read();
 
         miner_status.set(MinerStatus::FetchingChallenge);
-        use_gateway()
-            .poll_new_challenge(member_authority, pool_url, last_hash_at)
-            .await
+
+        // Keep polling until the pool recovers or the miner fails over to another one
+        let mut retry_ms = CHALLENGE_RETRY_MS;
+        loop {
+            match use_gateway()
+                .poll_new_challenge(member_authority, pool_url.clone(), last_hash_at)
+                .await
+            {
+                Ok(challenge) => {
+                    failover.record_success();
+                    return Some(Ok(challenge));
+                }
+                Err(err) => {
+                    log::error!("Error fetching challenge: {:?}", err);
+                    failover.record_failure();
+                    sleep(retry_ms).await;
+                    retry_ms = (retry_ms * 2).min(MAX_CHALLENGE_RETRY_MS);
+                }
+            }
+        }
     })
 }
 
 fn use_challenge_dispatch(
-    challenge: Resource<GatewayResult<MemberChallenge>>,
+    challenge: Resource<Option<GatewayResult<MemberChallenge>>>,
     to_miner: Coroutine<InputMessage>,
 ) -> Effect {
     let mut miner_status = use_miner_status();
     let miner_cores = use_miner_cores();
     let is_active = use_miner_is_active();
     let member_record = use_member_record();
+    let mut solution_queue = use_solution_queue();
     use_effect(move || {
         if *is_active.read() {
             if let Some(Ok(member_record)) = member_record.cloned() {
-                if let Some(Ok(challenge)) = challenge.cloned() {
+                if let Some(Some(Ok(challenge))) = challenge.cloned() {
+                    // Queue solutions for this challenge from now on
+                    solution_queue.start_round(&challenge);
                     // spawn(async move {
                     //     if let Ok(cutoff_time) = use_gateway()
                     //         .get_cutoff(
@@ -90,7 +138,7 @@ fn use_challenge_dispatch(
                     //         .await
                     //     {
                     //         miner_status.set(MinerStatus::Hashing);
-                    //         to_miner.send(ore_miner_types::InputMessage {
+                    //         to_miner.send(ore_miner_types::InputMessage::Pool {
                     //             member: member_record,
                     //             challenge,
                     //             cutoff_time,
@@ -111,10 +159,14 @@ fn use_solution_contribute(
     let wallet = use_wallet();
     let pool_url = use_pool_url();
     let mut member_record_balance = use_member_record_balance();
-    let mut miner_status = use_miner_status();
     let is_active = use_miner_is_active();
+    let mining_mode = use_mining_mode();
+    let mut solution_queue = use_solution_queue();
     use_effect(move || {
         // Check status
+        if *mining_mode.peek() != MiningMode::Pool {
+            return;
+        }
         let Ok(pubkey) = wallet.pubkey() else {
             return;
         };
@@ -128,35 +180,8 @@ fn use_solution_contribute(
         match *from_miner.read() {
             OutputMessage::Solution(solution) => {
                 log::info!("received solution");
-                // Submit solution
-                spawn(async move {
-                    log::info!("submitting solution");
-                    // Submitting
-                    miner_status.set(MinerStatus::SubmittingSolution);
-                    if let Err(err) = use_gateway()
-                        .post_solution(pubkey, pool_url.clone(), &solution)
-                        .await
-                    {
-                        log::error!("Error posting solution: {:?}", err);
-                    }
-                    // Set miner status back to hashing
-                    // continuous submissions ...
-                    miner_status.set(MinerStatus::Hashing);
-                    // Restart member balance for pending rewards
-                    member_record_balance.restart();
-                    // Get latest mine event
-                    match use_gateway()
-                        .get_latest_event(pubkey, pool_url.clone())
-                        .await
-                    {
-                        Ok(latest_event) => {
-                            MiningEvent::add_to_signal(latest_event);
-                        }
-                        Err(err) => {
-                            log::error!("Error getting latest event: {:?}", err);
-                        }
-                    }
-                });
+                // Queue solution for submission
+                solution_queue.push(solution);
             }
             OutputMessage::Expired(lha) => {
                 // Update last hash at
@@ -186,3 +211,73 @@ fn use_solution_contribute(
         }
     })
 }
+
+/// Submits queued solutions to the pool, retrying failed ones until the round ends.
+fn use_solution_submit() {
+    let wallet = use_wallet();
+    let pool_url = use_pool_url();
+    let mut member_record_balance = use_member_record_balance();
+    let mut miner_status = use_miner_status();
+    let mut failover = use_pool_failover();
+    let is_active = use_miner_is_active();
+    let mut solution_queue = use_solution_queue();
+    use_future(move || async move {
+        loop {
+            let (Ok(pubkey), Some(pool_url)) = (wallet.pubkey(), pool_url.cloned()) else {
+                sleep(SUBMIT_POLL_INTERVAL_MS).await;
+                continue;
+            };
+            let Some(queued) = solution_queue.next() else {
+                sleep(SUBMIT_POLL_INTERVAL_MS).await;
+                continue;
+            };
+            log::info!("submitting solution");
+            // Submitting, queued solutions are still submitted after the miner stops
+            let active = *is_active.peek();
+            if active {
+                miner_status.set(MinerStatus::SubmittingSolution);
+            }
+            let outcome = match use_gateway()
+                .post_solution(pubkey, pool_url.clone(), &queued.solution)
+                .await
+            {
+                Ok(()) => {
+                    failover.record_success();
+                    SubmissionOutcome::Accepted
+                }
+                Err(err) if err.is_retriable() => {
+                    log::error!("Error posting solution: {:?}", err);
+                    failover.record_failure();
+                    SubmissionOutcome::Failed
+                }
+                Err(err) => {
+                    log::error!("Pool rejected solution: {:?}", err);
+                    SubmissionOutcome::Rejected
+                }
+            };
+            solution_queue.record(queued, outcome);
+            // Set miner status back to hashing
+            // continuous submissions ...
+            if active && *miner_status.peek() == MinerStatus::SubmittingSolution {
+                miner_status.set(MinerStatus::Hashing);
+            }
+            if outcome != SubmissionOutcome::Accepted {
+                continue;
+            }
+            // Restart member balance for pending rewards
+            member_record_balance.restart();
+            // Get latest mine event
+            match use_gateway()
+                .get_latest_event(pubkey, pool_url.clone())
+                .await
+            {
+                Ok(latest_event) => {
+                    MiningEvent::add_to_signal(latest_event);
+                }
+                Err(err) => {
+                    log::error!("Error getting latest event: {:?}", err);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,49 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{get_value, set_key_value};
+
+const MINING_MODE_KEY: &str = "mining_mode";
+
+/// Whether the miner contributes to a pool or mines against the ORE program directly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MiningMode {
+    #[default]
+    Pool,
+    /// Only available on desktop, where the miner can sign with the user's own keypair.
+    Solo,
+}
+
+impl MiningMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MiningMode::Pool => "Pool",
+            MiningMode::Solo => "Solo",
+        }
+    }
+}
+
+pub fn use_mining_mode_provider() {
+    use_context_provider(|| {
+        let mode = get_value::<MiningMode>(MINING_MODE_KEY).unwrap_or_default();
+        // The browser miner can only contribute to pools
+        if cfg!(feature = "web") {
+            Signal::new(MiningMode::Pool)
+        } else {
+            Signal::new(mode)
+        }
+    });
+}
+
+pub fn use_mining_mode() -> Signal<MiningMode> {
+    use_context()
+}
+
+/// Sets the mining mode and persists it.
+pub fn set_mining_mode(mut mining_mode: Signal<MiningMode>, mode: MiningMode) {
+    if let Err(err) = set_key_value(MINING_MODE_KEY, &mode) {
+        log::error!("Failed to persist mining mode: {:?}", err);
+    }
+    mining_mode.set(mode);
+}
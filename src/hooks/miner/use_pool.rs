@@ -1,11 +1,101 @@
 use dioxus::prelude::*;
+use solana_sdk::pubkey::Pubkey;
 
-use crate::config::{Pool, FIRST_POOL, SECOND_POOL};
+use super::use_pool_failover;
+use crate::{
+    config::{Pool, FIRST_POOL, LISTED_POOLS, SECOND_POOL},
+    utils::{get_value, set_key_value},
+};
+
+const SELECTED_POOL_KEY: &str = "selected_pool";
+const CUSTOM_POOLS_KEY: &str = "custom_pools";
+
+pub fn use_pool_provider() {
+    let custom_pools = use_context_provider(|| {
+        Signal::new(get_value::<Vec<Pool>>(CUSTOM_POOLS_KEY).unwrap_or_default())
+    });
+    use_context_provider(|| {
+        // Fall back to the default pool if the saved one is no longer available
+        let pool = get_value::<String>(SELECTED_POOL_KEY)
+            .ok()
+            .and_then(|address| {
+                LISTED_POOLS
+                    .iter()
+                    .chain(custom_pools.peek().iter())
+                    .find(|pool| pool.address.to_string() == address)
+                    .cloned()
+            })
+            .unwrap_or_else(|| SECOND_POOL.clone());
+        Signal::new(pool)
+    });
+}
+
+/// The pool selected for mining.
+pub fn use_selected_pool() -> Signal<Pool> {
+    use_context()
+}
+
+/// Pools added by the user, in addition to the listed pools.
+pub fn use_custom_pools() -> Signal<Vec<Pool>> {
+    use_context()
+}
+
+/// Every pool that can be selected, listed pools first.
+pub fn use_pools() -> Memo<Vec<Pool>> {
+    let custom_pools = use_custom_pools();
+    use_memo(move || {
+        LISTED_POOLS
+            .iter()
+            .chain(custom_pools.read().iter())
+            .cloned()
+            .collect()
+    })
+}
+
+/// Selects the pool to mine with and persists the choice.
+pub fn select_pool(mut selected_pool: Signal<Pool>, pool: Pool) {
+    if let Err(err) = set_key_value(SELECTED_POOL_KEY, &pool.address.to_string()) {
+        log::error!("Failed to persist selected pool: {:?}", err);
+    }
+    selected_pool.set(pool);
+}
+
+/// Adds a custom pool and persists it. The pool should have passed `PoolGateway::check_pool`.
+pub fn add_custom_pool(mut custom_pools: Signal<Vec<Pool>>, pool: Pool) {
+    custom_pools.write().push(pool);
+    persist_custom_pools(custom_pools);
+}
+
+/// Removes a custom pool, falling back to the default pool if it was selected.
+pub fn remove_custom_pool(
+    mut custom_pools: Signal<Vec<Pool>>,
+    selected_pool: Signal<Pool>,
+    address: Pubkey,
+) {
+    custom_pools.write().retain(|pool| pool.address != address);
+    persist_custom_pools(custom_pools);
+    if selected_pool.peek().address == address {
+        select_pool(selected_pool, SECOND_POOL.clone());
+    }
+}
+
+fn persist_custom_pools(custom_pools: Signal<Vec<Pool>>) {
+    if let Err(err) = set_key_value(CUSTOM_POOLS_KEY, &*custom_pools.peek()) {
+        log::error!("Failed to persist custom pools: {:?}", err);
+    }
+}
 
 pub fn use_pool_deprecated() -> Resource<Pool> {
     use_resource(move || async move { FIRST_POOL.clone() })
 }
 
+/// The pool being mined, which is the selected pool unless the miner has failed over.
 pub fn use_pool() -> Resource<Pool> {
-    use_resource(move || async move { SECOND_POOL.clone() })
+    let selected_pool = use_selected_pool();
+    let failover = use_pool_failover();
+    use_resource(move || async move {
+        failover
+            .fallback()
+            .unwrap_or_else(|| selected_pool.cloned())
+    })
 }
@@ -0,0 +1,297 @@
+use dioxus::prelude::*;
+use ore_pool_api::state::member_pda;
+use ore_types::request::TransactionType;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    components::submit_transaction,
+    config::Pool,
+    gateway::pool::PoolGateway,
+    hooks::{
+        on_transaction_done, use_gateway, use_member_record, use_miner_is_active, use_miner_status,
+        use_pool_register_transaction, use_pools, use_selected_pool, use_wallet, MinerStatus,
+        PoolSwitchEvent, PoolSwitchReason, Wallet,
+    },
+    time::{sleep, SystemTime, UNIX_EPOCH},
+    utils::{get_value, set_key_value},
+};
+
+/// Consecutive failures on a pool after which the miner switches to another one.
+const FAILOVER_THRESHOLD: u32 = 3;
+
+/// Interval between checks of whether the selected pool has recovered.
+const RECOVERY_INTERVAL_MS: u64 = 30_000;
+
+const FAILOVER_REGISTER_KEY: &str = "failover_register";
+
+/// Tracks the health of the pool being mined and the pool used in place of the selected one.
+#[derive(Clone, Copy)]
+pub struct PoolFailover {
+    /// Pool mined while the selected pool is failing.
+    fallback: Signal<Option<Pool>>,
+    /// Consecutive failed requests to the pool being mined.
+    failures: Signal<u32>,
+    /// Whether the miner may join another pool to fail over to it.
+    allow_register: Signal<bool>,
+    registration: Signal<FailoverRegistration>,
+    switching: Signal<bool>,
+}
+
+/// Progress of joining the pool failed over to.
+#[derive(Clone, PartialEq)]
+enum FailoverRegistration {
+    Idle,
+    Joining(Pool),
+    Submitted(Pool),
+}
+
+impl PoolFailover {
+    pub fn fallback(&self) -> Option<Pool> {
+        self.fallback.cloned()
+    }
+
+    pub fn record_failure(&mut self) {
+        *self.failures.write() += 1;
+    }
+
+    pub fn record_success(&mut self) {
+        if *self.failures.peek() > 0 {
+            self.failures.set(0);
+        }
+    }
+
+    pub fn allow_register(&self) -> Signal<bool> {
+        self.allow_register
+    }
+
+    /// Sets whether the miner may join another pool to fail over to it, and persists it.
+    pub fn set_allow_register(&mut self, allow_register: bool) {
+        if let Err(err) = set_key_value(FAILOVER_REGISTER_KEY, &allow_register) {
+            log::error!("Failed to persist failover registration: {:?}", err);
+        }
+        self.allow_register.set(allow_register);
+    }
+
+    fn switch(&mut self, selected_pool: &Pool, from: &Pool, to: Pool, reason: PoolSwitchReason) {
+        log::info!("Switching pool from {} to {}", from.name, to.name);
+        PoolSwitchEvent::add_to_signal(PoolSwitchEvent {
+            from: from.name.clone(),
+            to: to.name.clone(),
+            reason,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        });
+        self.failures.set(0);
+        if to.address == selected_pool.address {
+            self.fallback.set(None);
+        } else {
+            self.fallback.set(Some(to));
+        }
+    }
+}
+
+pub fn use_pool_failover_provider() {
+    let mut failover = use_context_provider(|| PoolFailover {
+        fallback: Signal::new(None),
+        failures: Signal::new(0),
+        allow_register: Signal::new(get_value::<bool>(FAILOVER_REGISTER_KEY).unwrap_or(false)),
+        registration: Signal::new(FailoverRegistration::Idle),
+        switching: Signal::new(false),
+    });
+
+    // Choosing a pool clears any failover from the previous one
+    let selected_pool = use_selected_pool();
+    use_effect(move || {
+        let _ = selected_pool.read();
+        if failover.fallback.peek().is_some() {
+            failover.fallback.set(None);
+        }
+        failover.failures.set(0);
+        failover.registration.set(FailoverRegistration::Idle);
+    });
+}
+
+pub fn use_pool_failover() -> PoolFailover {
+    use_context()
+}
+
+/// Switches pools while the one being mined keeps failing, and returns to the selected pool
+/// once it recovers.
+pub fn use_pool_failover_loop() {
+    let mut failover = use_pool_failover();
+    let selected_pool = use_selected_pool();
+    let pools = use_pools();
+    let wallet = use_wallet();
+    let is_active = use_miner_is_active();
+    let mut miner_status = use_miner_status();
+    let mut member_record = use_member_record();
+    let register_tx = use_pool_register_transaction();
+
+    // Fail over once the pool has failed too many times in a row
+    use_effect(move || {
+        if *failover.failures.read() < FAILOVER_THRESHOLD {
+            return;
+        }
+        if !*is_active.peek() || *failover.switching.peek() {
+            return;
+        }
+        let Wallet::Connected(authority) = *wallet.peek() else {
+            return;
+        };
+        let selected = selected_pool.peek().clone();
+        let current = failover
+            .fallback
+            .peek()
+            .clone()
+            .unwrap_or_else(|| selected.clone());
+        let pools = pools.peek().clone();
+        let allow_register = *failover.allow_register.peek();
+        failover.switching.set(true);
+        spawn(async move {
+            match find_fallback_pool(authority, &current, pools, allow_register).await {
+                Some((pool, true)) => {
+                    failover.switch(&selected, &current, pool, PoolSwitchReason::Failover);
+                }
+                Some((pool, false)) => {
+                    failover.switch(
+                        &selected,
+                        &current,
+                        pool.clone(),
+                        PoolSwitchReason::Failover,
+                    );
+                    failover
+                        .registration
+                        .set(FailoverRegistration::Joining(pool));
+                    miner_status.set(MinerStatus::Registering);
+                }
+                None => {
+                    log::warn!("No healthy pool to fail over to from {}", current.name);
+                    failover.failures.set(0);
+                }
+            }
+            failover.switching.set(false);
+        });
+    });
+
+    // Join the pool failed over to, once its registration transaction is built
+    use_effect(move || {
+        let FailoverRegistration::Joining(pool) = failover.registration.cloned() else {
+            return;
+        };
+        let Some(Ok(tx)) = register_tx.cloned() else {
+            return;
+        };
+        // The transaction may still be built for the previous pool
+        if !tx.message.static_account_keys().contains(&pool.address) {
+            return;
+        }
+        failover
+            .registration
+            .set(FailoverRegistration::Submitted(pool));
+        submit_transaction(tx, TransactionType::PoolJoin);
+    });
+
+    // Register with the pool server once the join lands, then resume mining
+    on_transaction_done(move |_sig| {
+        let FailoverRegistration::Submitted(pool) = failover.registration.peek().clone() else {
+            return;
+        };
+        let Wallet::Connected(authority) = *wallet.peek() else {
+            return;
+        };
+        failover.registration.set(FailoverRegistration::Idle);
+        spawn(async move {
+            match use_gateway().register(authority, pool.url).await {
+                Ok(_member_record) => {
+                    member_record.restart();
+                    miner_status.set(MinerStatus::FetchingChallenge);
+                }
+                Err(err) => {
+                    log::error!("Error registering with failover pool: {:?}", err);
+                    miner_status.set(MinerStatus::Stopped);
+                }
+            }
+        });
+    });
+
+    // Return to the selected pool once it serves challenges again
+    use_future(move || async move {
+        loop {
+            sleep(RECOVERY_INTERVAL_MS).await;
+            let Some(current) = failover.fallback.peek().clone() else {
+                continue;
+            };
+            if !*is_active.peek() {
+                continue;
+            }
+            let Wallet::Connected(authority) = *wallet.peek() else {
+                continue;
+            };
+            let selected = selected_pool.peek().clone();
+            if use_gateway()
+                .get_challenge(authority, selected.url.clone())
+                .await
+                .is_ok()
+            {
+                failover.switch(
+                    &selected,
+                    &current,
+                    selected.clone(),
+                    PoolSwitchReason::Recovered,
+                );
+            }
+        }
+    });
+}
+
+/// Finds the next reachable pool after the current one, preferring pools the authority is
+/// registered with. Returns the pool and whether it is registered.
+async fn find_fallback_pool(
+    authority: Pubkey,
+    current: &Pool,
+    pools: Vec<Pool>,
+    allow_register: bool,
+) -> Option<(Pool, bool)> {
+    let gateway = use_gateway();
+    let start = pools
+        .iter()
+        .position(|pool| pool.address == current.address)
+        .map_or(0, |i| i + 1);
+    let candidates = pools
+        .iter()
+        .cycle()
+        .skip(start)
+        .take(pools.len())
+        .filter(|pool| pool.address != current.address);
+    let mut unregistered = None;
+    for pool in candidates {
+        if gateway.get_pool_latency(pool.url.clone()).await.is_err() {
+            continue;
+        }
+        let is_member = gateway
+            .get_member(member_pda(authority, pool.address).0)
+            .await
+            .is_ok();
+        if is_member
+            && gateway
+                .get_member_record(authority, pool.url.clone())
+                .await
+                .is_ok()
+        {
+            return Some((pool.clone(), true));
+        }
+        // A pool that answers but cannot serve a challenge is not worth registering with
+        if allow_register
+            && unregistered.is_none()
+            && gateway
+                .get_challenge(authority, pool.url.clone())
+                .await
+                .is_ok()
+        {
+            unregistered = Some((pool.clone(), false));
+        }
+    }
+    unregistered
+}
@@ -0,0 +1,207 @@
+use dioxus::prelude::*;
+use drillx::Solution;
+use ore_api_v2::consts::ONE_MINUTE;
+use ore_miner_types::{InputMessage, OutputMessage};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+use crate::{
+    gateway::{
+        compute_budget::size_compute_unit_limit, program_error::decode_simulation_error,
+        solana::SolanaGateway, solo::SoloGateway, GatewayError, GatewayResult, Rpc,
+    },
+    hooks::{
+        build_solo_mine_instructions, build_solo_open_instructions, use_gateway, use_miner,
+        use_miner_cores, use_miner_is_active, use_miner_status, use_mining_mode, use_wallet,
+        GetPubkey, MinerStatus, MiningMode, Wallet,
+    },
+    time::sleep,
+};
+
+/// Seconds before the proof may be mined again at which hashing stops, leaving time to submit.
+const BUFFER_SECONDS: i64 = 5;
+
+/// Least time spent hashing a challenge, for proofs that are already due.
+const MIN_HASH_SECONDS: i64 = 5;
+
+/// Interval between reads of the proof while waiting for its challenge to change.
+const POLL_INTERVAL_MS: u64 = 1_000;
+
+/// A proof challenge to dispatch to the miner.
+#[derive(Clone, Copy, PartialEq)]
+struct SoloChallenge {
+    challenge: [u8; 32],
+    last_hash_at: i64,
+    min_difficulty: u32,
+    cutoff_time: i64,
+}
+
+/// Mines against the ORE program with the user's own keypair, without a pool.
+pub fn use_solo_mining_loop() {
+    let (from_miner, to_miner) = use_miner();
+    let mining_mode = use_mining_mode();
+    let wallet = use_wallet();
+    let is_active = use_miner_is_active();
+    let mut miner_status = use_miner_status();
+    let miner_cores = use_miner_cores();
+
+    // Last hash of the proof when a solution for its challenge landed
+    let mut mined_hash_at = use_signal(|| 0);
+    let mut best_solution = use_signal::<Option<Solution>>(|| None);
+    // Bumped once a proof is opened, to fetch its first challenge
+    let mut proofs_opened = use_signal(|| 0u32);
+
+    // Fetch the next proof challenge, opening a proof first if there is none
+    let mut challenge = use_resource(move || async move {
+        if *mining_mode.read() != MiningMode::Solo || !*is_active.read() {
+            return None;
+        }
+        let authority = wallet.pubkey().ok()?;
+        let mined_hash_at = *mined_hash_at.read();
+        let _ = *proofs_opened.read();
+        miner_status.set(MinerStatus::FetchingChallenge);
+        loop {
+            match next_challenge(authority, mined_hash_at).await {
+                Ok(Some(challenge)) => return Some(challenge),
+                Ok(None) => {}
+                Err(GatewayError::AccountNotFound) => {
+                    // Spawned so the transaction survives this resource restarting
+                    miner_status.set(MinerStatus::Registering);
+                    spawn(async move {
+                        match open_proof(authority).await {
+                            Ok(sig) => {
+                                log::info!("Opened proof: {}", sig);
+                                miner_status.set(MinerStatus::FetchingChallenge);
+                                *proofs_opened.write() += 1;
+                            }
+                            Err(err) => {
+                                log::error!("Error opening proof: {:?}", err);
+                                miner_status.set(MinerStatus::Stopped);
+                            }
+                        }
+                    });
+                    return None;
+                }
+                Err(err) => {
+                    log::error!("Error fetching proof challenge: {:?}", err);
+                }
+            }
+            sleep(POLL_INTERVAL_MS).await;
+        }
+    });
+
+    // Dispatch the challenge to the miner
+    use_effect(move || {
+        let Some(Some(challenge)) = challenge.cloned() else {
+            return;
+        };
+        best_solution.set(None);
+        miner_status.set(MinerStatus::Hashing);
+        to_miner.send(InputMessage::Solo {
+            challenge: challenge.challenge,
+            last_hash_at: challenge.last_hash_at,
+            min_difficulty: challenge.min_difficulty,
+            cutoff_time: challenge.cutoff_time,
+            cores: *miner_cores.peek(),
+        });
+    });
+
+    // Keep the best solution, and submit it once hashing is done
+    use_effect(move || {
+        if *mining_mode.peek() != MiningMode::Solo || !*is_active.peek() {
+            return;
+        }
+        let Wallet::Connected(authority) = *wallet.peek() else {
+            return;
+        };
+        match *from_miner.read() {
+            OutputMessage::Solution(solution) => best_solution.set(Some(solution)),
+            OutputMessage::Expired(last_hash_at) => {
+                let Some(solution) = best_solution.write().take() else {
+                    log::info!("No solution found, mining the challenge again");
+                    challenge.restart();
+                    return;
+                };
+                spawn(async move {
+                    miner_status.set(MinerStatus::SubmittingSolution);
+                    match submit_solution(authority, solution).await {
+                        Ok(sig) => {
+                            log::info!("Submitted solution: {}", sig);
+                            mined_hash_at.set(last_hash_at);
+                        }
+                        Err(err) => {
+                            log::error!("Error submitting solution: {:?}", err);
+                            challenge.restart();
+                        }
+                    }
+                });
+            }
+            _ => {}
+        }
+    });
+}
+
+/// Returns the proof challenge if it has changed since `mined_hash_at`.
+async fn next_challenge(
+    authority: Pubkey,
+    mined_hash_at: i64,
+) -> GatewayResult<Option<SoloChallenge>> {
+    let gateway = use_gateway();
+    let proof = gateway.get_proof(authority).await?;
+    if proof.last_hash_at == mined_hash_at {
+        return Ok(None);
+    }
+    let config = gateway.get_mining_config().await?;
+    let min_difficulty =
+        u32::try_from(config.min_difficulty).map_err(|_| GatewayError::FailedDeserialization)?;
+    let clock = gateway.rpc.get_clock().await?;
+    // Stop hashing shortly before the proof may be mined again
+    let cutoff_time = proof
+        .last_hash_at
+        .saturating_add(ONE_MINUTE)
+        .saturating_sub(BUFFER_SECONDS)
+        .saturating_sub(clock.unix_timestamp)
+        .max(MIN_HASH_SECONDS);
+    Ok(Some(SoloChallenge {
+        challenge: proof.challenge,
+        last_hash_at: proof.last_hash_at,
+        min_difficulty,
+        cutoff_time,
+    }))
+}
+
+async fn open_proof(authority: Pubkey) -> GatewayResult<Signature> {
+    let ixs = build_solo_open_instructions(authority).await;
+    sign_and_send(authority, &ixs).await
+}
+
+async fn submit_solution(authority: Pubkey, solution: Solution) -> GatewayResult<Signature> {
+    let ixs = build_solo_mine_instructions(authority, solution).await?;
+    sign_and_send(authority, &ixs).await
+}
+
+/// Signs with the local keypair and sends without going through the transaction toast,
+/// since solo mining submits a transaction every round.
+async fn sign_and_send(authority: Pubkey, ixs: &[Instruction]) -> GatewayResult<Signature> {
+    let signer = crate::hooks::use_wallet_native::get()?.0.creator;
+    if signer.pubkey() != authority {
+        return Err(GatewayError::WalletDisconnected);
+    }
+    let gateway = use_gateway();
+    let (hash, last_valid_block_height) = gateway.rpc.get_latest_blockhash_with_height().await?;
+    let mut tx: VersionedTransaction = Transaction::new_with_payer(ixs, Some(&authority)).into();
+    tx.message.set_recent_blockhash(hash);
+    // Size compute units and check why the transaction would fail before paying for it
+    let simulated_tx = size_compute_unit_limit(&gateway.rpc, &mut tx).await?;
+    if let Some(err) = decode_simulation_error(&tx, &simulated_tx) {
+        return Err(err);
+    }
+    gateway
+        .send_and_confirm_bundle(&signer, tx, last_valid_block_height, |_| {})
+        .await
+}
@@ -0,0 +1,468 @@
+use dioxus::prelude::*;
+use ore_pool_types::MemberChallenge;
+
+use crate::time::{Duration, Instant};
+
+/// Rounds kept in the submission history.
+const MAX_SUBMISSION_ROUNDS: usize = 10;
+
+/// Delay before retrying a failed submission, doubled after each further failure.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Seconds after the last hash during which the pool accepts solutions for the round.
+const ROUND_SECONDS: i64 = 60;
+
+/// What came of submitting a solution to the pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Accepted,
+    /// The pool refused the solution. It is not retried.
+    Rejected,
+    /// The request failed and the solution will be retried.
+    Failed,
+    /// The round ended before the solution could be submitted.
+    Late,
+}
+
+/// A single submission of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmissionAttempt {
+    pub difficulty: u32,
+    /// Submissions of this solution so far, starting at 1.
+    pub attempt: u32,
+    pub outcome: SubmissionOutcome,
+}
+
+/// Submissions made for one challenge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmissionRound {
+    pub last_hash_at: i64,
+    pub attempts: Vec<SubmissionAttempt>,
+}
+
+impl SubmissionRound {
+    pub fn count(&self, outcome: SubmissionOutcome) -> usize {
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.outcome == outcome)
+            .count()
+    }
+
+    /// Submissions that repeated an earlier failed one.
+    pub fn retries(&self) -> usize {
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.attempt > 1 && attempt.outcome != SubmissionOutcome::Late)
+            .count()
+    }
+
+    pub fn best_accepted(&self) -> Option<u32> {
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.outcome == SubmissionOutcome::Accepted)
+            .map(|attempt| attempt.difficulty)
+            .max()
+    }
+}
+
+/// A solution due for submission.
+#[derive(Clone, Copy, Debug)]
+pub struct QueuedSolution {
+    pub solution: drillx::Solution,
+    pub difficulty: u32,
+    pub last_hash_at: i64,
+    attempt: u32,
+    retry_at: Instant,
+}
+
+/// The challenge solutions are queued for.
+struct Round {
+    challenge: [u8; 32],
+    last_hash_at: i64,
+    /// Time after which the pool no longer accepts solutions.
+    deadline: Instant,
+    /// Best solution waiting to be submitted or retried. Only the best solution is worth
+    /// submitting, so a better one replaces it.
+    pending: Option<QueuedSolution>,
+    /// Best difficulty accepted by the pool or being submitted.
+    best_difficulty: u32,
+}
+
+impl Round {
+    fn new(challenge: &MemberChallenge, now: Instant) -> Self {
+        let last_hash_at = challenge.challenge.lash_hash_at;
+        let remaining = last_hash_at
+            .saturating_add(ROUND_SECONDS)
+            .saturating_sub(challenge.unix_timestamp)
+            .max(0);
+        Round {
+            challenge: challenge.challenge.challenge,
+            last_hash_at,
+            deadline: now + Duration::from_secs(remaining as u64),
+            pending: None,
+            best_difficulty: 0,
+        }
+    }
+
+    /// Queues a solution, unless it was found for another challenge or a better one is already
+    /// pending or accepted. A pending solution it beats is dropped.
+    fn push(&mut self, solution: drillx::Solution, now: Instant) {
+        // Workers may still report solutions for the previous challenge after a new job starts
+        if !solution.is_valid(&self.challenge) {
+            log::warn!("Dropping a solution for another challenge");
+            return;
+        }
+        let difficulty = solution.to_hash().difficulty();
+        let pending = self.pending.map(|queued| queued.difficulty);
+        if difficulty <= self.best_difficulty.max(pending.unwrap_or(0)) {
+            return;
+        }
+        self.pending = Some(QueuedSolution {
+            solution,
+            difficulty,
+            last_hash_at: self.last_hash_at,
+            attempt: 0,
+            retry_at: now,
+        });
+    }
+
+    /// Takes the pending solution if it is due for submission.
+    fn next(&mut self, now: Instant) -> Option<QueuedSolution> {
+        if !self.pending.is_some_and(|queued| queued.retry_at <= now) {
+            return None;
+        }
+        let mut queued = self.pending.take()?;
+        queued.attempt += 1;
+        self.best_difficulty = self.best_difficulty.max(queued.difficulty);
+        Some(queued)
+    }
+
+    /// Queues a failed solution again with backoff, unless a better one has been queued since.
+    /// `best_accepted` is the best difficulty the pool has accepted this round.
+    fn record(
+        &mut self,
+        queued: QueuedSolution,
+        outcome: SubmissionOutcome,
+        best_accepted: u32,
+        now: Instant,
+    ) {
+        if outcome == SubmissionOutcome::Accepted {
+            return;
+        }
+        // The failed solution no longer holds back weaker ones
+        self.best_difficulty = best_accepted;
+        let beaten = self
+            .pending
+            .is_some_and(|best| best.difficulty > queued.difficulty);
+        if outcome == SubmissionOutcome::Failed && !beaten {
+            let backoff = RETRY_BACKOFF
+                .saturating_mul(2u32.saturating_pow(queued.attempt.saturating_sub(1)))
+                .min(MAX_RETRY_BACKOFF);
+            self.pending = Some(QueuedSolution {
+                retry_at: now + backoff,
+                ..queued
+            });
+        }
+    }
+
+    /// The solution never submitted before the round ended, if any.
+    fn late(self) -> impl Iterator<Item = SubmissionAttempt> {
+        self.pending.into_iter().map(|queued| SubmissionAttempt {
+            difficulty: queued.difficulty,
+            attempt: queued.attempt + 1,
+            outcome: SubmissionOutcome::Late,
+        })
+    }
+}
+
+/// Solutions waiting to be submitted to the pool, and the outcome of past submissions.
+#[derive(Clone, Copy)]
+pub struct SolutionQueue {
+    round: Signal<Option<Round>>,
+    /// Submissions per round, newest first.
+    history: Signal<Vec<SubmissionRound>>,
+}
+
+impl SolutionQueue {
+    pub fn history(&self) -> Vec<SubmissionRound> {
+        self.history.cloned()
+    }
+
+    /// Starts queueing solutions for a new challenge. Solutions left from the previous one are
+    /// recorded as late.
+    pub fn start_round(&mut self, challenge: &MemberChallenge) {
+        let last_hash_at = challenge.challenge.lash_hash_at;
+        if let Some(round) = &*self.round.peek() {
+            if round.last_hash_at == last_hash_at {
+                return;
+            }
+        }
+        self.expire();
+        self.round.set(Some(Round::new(challenge, Instant::now())));
+        let mut history = self.history.peek().clone();
+        history.insert(
+            0,
+            SubmissionRound {
+                last_hash_at,
+                attempts: vec![],
+            },
+        );
+        history.truncate(MAX_SUBMISSION_ROUNDS);
+        self.history.set(history);
+    }
+
+    /// Queues a solution for the current round, unless it was found for another challenge or
+    /// a better one is already pending or accepted. A pending solution it beats is dropped.
+    pub fn push(&mut self, solution: drillx::Solution) {
+        let mut round = self.round.write();
+        let Some(round) = round.as_mut() else {
+            log::error!("No challenge to submit the solution for");
+            return;
+        };
+        round.push(solution, Instant::now());
+    }
+
+    /// Takes the best solution due for submission, if any. Solutions still queued once the
+    /// round has ended are recorded as late.
+    pub fn next(&mut self) -> Option<QueuedSolution> {
+        let now = Instant::now();
+        let expired = match &*self.round.peek() {
+            Some(round) => now >= round.deadline,
+            None => return None,
+        };
+        if expired {
+            self.expire();
+            return None;
+        }
+        self.round.write().as_mut()?.next(now)
+    }
+
+    /// Records the outcome of a submission. Failed solutions are queued again with backoff
+    /// unless a better one has been queued or accepted since.
+    pub fn record(&mut self, queued: QueuedSolution, outcome: SubmissionOutcome) {
+        self.record_attempt(
+            queued.last_hash_at,
+            SubmissionAttempt {
+                difficulty: queued.difficulty,
+                attempt: queued.attempt,
+                outcome,
+            },
+        );
+        let best_accepted = self
+            .history
+            .peek()
+            .iter()
+            .find(|history| history.last_hash_at == queued.last_hash_at)
+            .and_then(|history| history.best_accepted())
+            .unwrap_or(0);
+        let mut round = self.round.write();
+        let Some(round) = round.as_mut() else {
+            return;
+        };
+        if round.last_hash_at != queued.last_hash_at {
+            return;
+        }
+        round.record(queued, outcome, best_accepted, Instant::now());
+    }
+
+    /// Ends the current round, recording the solutions it never submitted as late.
+    fn expire(&mut self) {
+        let Some(round) = self.round.write().take() else {
+            return;
+        };
+        let last_hash_at = round.last_hash_at;
+        for attempt in round.late() {
+            self.record_attempt(last_hash_at, attempt);
+        }
+    }
+
+    fn record_attempt(&mut self, last_hash_at: i64, attempt: SubmissionAttempt) {
+        let mut history = self.history.write();
+        let Some(round) = history
+            .iter_mut()
+            .find(|round| round.last_hash_at == last_hash_at)
+        else {
+            return;
+        };
+        round.attempts.push(attempt);
+    }
+}
+
+pub fn use_solution_queue_provider() {
+    use_context_provider(|| SolutionQueue {
+        round: Signal::new(None),
+        history: Signal::new(vec![]),
+    });
+}
+
+pub fn use_solution_queue() -> SolutionQueue {
+    use_context()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHALLENGE: [u8; 32] = [7; 32];
+
+    /// Finds solutions to a challenge with distinct, nonzero difficulties, weakest first.
+    fn solutions(challenge: &[u8; 32], count: usize) -> Vec<drillx::Solution> {
+        let mut solutions: Vec<drillx::Solution> = vec![];
+        let mut nonce = 0u64;
+        while solutions.len() < count {
+            let n = nonce.to_le_bytes();
+            nonce += 1;
+            let Ok(hash) = drillx::hash(challenge, &n) else {
+                continue;
+            };
+            let difficulty = hash.difficulty();
+            if difficulty > 0
+                && solutions
+                    .iter()
+                    .all(|solution| solution.to_hash().difficulty() != difficulty)
+            {
+                solutions.push(drillx::Solution { d: hash.d, n });
+            }
+        }
+        solutions.sort_by_key(|solution| solution.to_hash().difficulty());
+        solutions
+    }
+
+    fn round(now: Instant) -> Round {
+        Round {
+            challenge: CHALLENGE,
+            last_hash_at: 100,
+            deadline: now + Duration::from_secs(ROUND_SECONDS as u64),
+            pending: None,
+            best_difficulty: 0,
+        }
+    }
+
+    fn difficulties(round: &Round) -> Vec<u32> {
+        round
+            .pending
+            .iter()
+            .map(|queued| queued.difficulty)
+            .collect()
+    }
+
+    #[test]
+    fn push_keeps_only_the_best_solution() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let solutions = solutions(&CHALLENGE, 2);
+        let (weak, strong) = (solutions[0], solutions[1]);
+        let strong_difficulty = strong.to_hash().difficulty();
+
+        round.push(weak, now);
+        round.push(strong, now);
+        assert_eq!(difficulties(&round), vec![strong_difficulty]);
+
+        // weaker and duplicate solutions are ignored
+        round.push(weak, now);
+        round.push(strong, now);
+        assert_eq!(difficulties(&round), vec![strong_difficulty]);
+        assert_eq!(round.pending.unwrap().last_hash_at, 100);
+    }
+
+    #[test]
+    fn push_drops_solutions_for_another_challenge() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let stale = solutions(&[8; 32], 1)[0];
+        round.push(stale, now);
+        assert!(round.pending.is_none());
+    }
+
+    #[test]
+    fn next_holds_back_weaker_solutions() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let solutions = solutions(&CHALLENGE, 2);
+        let (weak, strong) = (solutions[0], solutions[1]);
+
+        round.push(strong, now);
+        let queued = round.next(now).unwrap();
+        assert_eq!(queued.attempt, 1);
+        assert_eq!(queued.difficulty, strong.to_hash().difficulty());
+        assert!(round.next(now).is_none());
+
+        // the solution being submitted already beats it
+        round.push(weak, now);
+        assert!(round.pending.is_none());
+    }
+
+    #[test]
+    fn failed_solutions_are_retried_with_backoff() {
+        let now = Instant::now();
+        let mut round = round(now);
+        round.push(solutions(&CHALLENGE, 1)[0], now);
+
+        let queued = round.next(now).unwrap();
+        round.record(queued, SubmissionOutcome::Failed, 0, now);
+        assert!(round.next(now).is_none());
+        let queued = round.next(now + RETRY_BACKOFF).unwrap();
+        assert_eq!(queued.attempt, 2);
+
+        // the delay doubles after each failure, up to the cap
+        round.record(queued, SubmissionOutcome::Failed, 0, now);
+        assert_eq!(round.pending.unwrap().retry_at, now + RETRY_BACKOFF * 2);
+        let queued = QueuedSolution {
+            attempt: 20,
+            ..round.pending.take().unwrap()
+        };
+        round.record(queued, SubmissionOutcome::Failed, 0, now);
+        assert_eq!(round.pending.unwrap().retry_at, now + MAX_RETRY_BACKOFF);
+    }
+
+    #[test]
+    fn rejected_solutions_are_not_retried() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let solutions = solutions(&CHALLENGE, 2);
+        let (weak, strong) = (solutions[0], solutions[1]);
+
+        round.push(strong, now);
+        let queued = round.next(now).unwrap();
+        round.record(queued, SubmissionOutcome::Rejected, 0, now);
+        assert!(round.pending.is_none());
+
+        // with nothing accepted, weaker solutions may be submitted again
+        round.push(weak, now);
+        assert_eq!(difficulties(&round), vec![weak.to_hash().difficulty()]);
+    }
+
+    #[test]
+    fn failed_solutions_beaten_since_are_dropped() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let solutions = solutions(&CHALLENGE, 2);
+        let (weak, strong) = (solutions[0], solutions[1]);
+
+        round.push(weak, now);
+        let queued = round.next(now).unwrap();
+        round.push(strong, now);
+        round.record(queued, SubmissionOutcome::Failed, 0, now);
+        assert_eq!(difficulties(&round), vec![strong.to_hash().difficulty()]);
+    }
+
+    #[test]
+    fn unsubmitted_solutions_are_late() {
+        let now = Instant::now();
+        let mut round = round(now);
+        let solution = solutions(&CHALLENGE, 1)[0];
+        round.push(solution, now);
+
+        let late = round.late().collect::<Vec<_>>();
+        assert_eq!(
+            late,
+            vec![SubmissionAttempt {
+                difficulty: solution.to_hash().difficulty(),
+                attempt: 1,
+                outcome: SubmissionOutcome::Late,
+            }]
+        );
+    }
+}
@@ -5,8 +5,10 @@ mod resources;
 mod transaction_builders;
 mod transaction_status;
 mod use_claimable_yield;
+mod use_commitment;
 #[cfg(feature = "web")]
 mod use_download_url;
+mod use_endpoints;
 mod use_gateway;
 // #[cfg(not(feature = "web"))]
 // mod use_persistent;
@@ -21,8 +23,10 @@ pub use resources::*;
 pub use transaction_builders::*;
 pub use transaction_status::*;
 pub use use_claimable_yield::*;
+pub use use_commitment::*;
 #[cfg(feature = "web")]
 pub use use_download_url::*;
+pub use use_endpoints::*;
 pub use use_gateway::*;
 // #[cfg(not(feature = "web"))]
 // pub use use_persistent::*;
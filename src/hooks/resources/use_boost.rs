@@ -7,8 +7,8 @@ use steel::Pubkey;
 
 use crate::{
     config::LISTED_BOOSTS,
-    gateway::{ore::OreGateway, GatewayError, GatewayResult},
-    hooks::use_gateway,
+    gateway::{commitment::commitment, ore::OreGateway, GatewayResult},
+    hooks::{use_gateway, use_unconfirmed_accounts},
 };
 
 pub(crate) fn use_boosts_provider() {
@@ -30,11 +30,12 @@ pub(crate) fn use_boosts_provider() {
 }
 
 fn use_boost_resource(address: Pubkey) -> Resource<GatewayResult<Boost>> {
+    let mut unconfirmed = use_unconfirmed_accounts();
     use_resource(move || async move {
-        use_gateway()
-            .get_boost(address)
-            .await
-            .map_err(GatewayError::from)
+        let commitment = commitment();
+        let boost = use_gateway().get_boost(address).await?;
+        unconfirmed.mark(address, commitment);
+        Ok(boost)
     })
 }
 
@@ -5,9 +5,10 @@ use base64::prelude::BASE64_STANDARD;
 use ore_boost_api::state::Config as BoostConfig;
 use steel::AccountDeserialize;
 
+use crate::gateway::commitment::commitment;
 use crate::gateway::ore::OreGateway;
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult};
-use crate::hooks::{use_gateway, use_wss_subscription};
+use crate::hooks::{use_gateway, use_unconfirmed_accounts, use_wss_subscription};
 
 pub(crate) fn use_boost_config_wss_provider() {
     let signal = use_boost_config_signal();
@@ -19,11 +20,16 @@ fn use_boost_config_signal() -> Signal<GatewayResult<BoostConfig>> {
 
     // Init
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
+    let mut unconfirmed = use_unconfirmed_accounts();
     use_effect(move || {
         spawn(async move {
             let gateway = use_gateway();
+            let commitment = commitment();
             match gateway.get_boost_config(boost_config_address).await {
-                Ok(boost_config) => data.set(Ok(boost_config)),
+                Ok(boost_config) => {
+                    unconfirmed.mark(boost_config_address, commitment);
+                    data.set(Ok(boost_config))
+                }
                 Err(err) => {
                     log::error!("Failed to initialize boost config: {:?}", err);
                     data.set(Err(err));
@@ -10,8 +10,8 @@ use steel::Pubkey;
 
 use crate::{
     config::LISTED_BOOSTS,
-    gateway::{ore::OreGateway, GatewayError, GatewayResult},
-    hooks::use_gateway,
+    gateway::{commitment::commitment, ore::OreGateway, GatewayResult},
+    hooks::{use_gateway, use_unconfirmed_accounts},
 };
 
 pub(crate) fn use_boost_proofs_provider() {
@@ -35,11 +35,12 @@ pub(crate) fn use_boost_proofs_provider() {
 }
 
 fn use_proof_resource(address: Pubkey) -> Resource<GatewayResult<Proof>> {
+    let mut unconfirmed = use_unconfirmed_accounts();
     use_resource(move || async move {
-        use_gateway()
-            .get_proof(address)
-            .await
-            .map_err(GatewayError::from)
+        let commitment = commitment();
+        let proof = use_gateway().get_proof(address).await?;
+        unconfirmed.mark(address, commitment);
+        Ok(proof)
     })
 }
 
@@ -5,9 +5,10 @@ use base64::prelude::BASE64_STANDARD;
 use ore_api::state::{proof_pda, Proof};
 use steel::AccountDeserialize;
 
+use crate::gateway::commitment::commitment;
 use crate::gateway::ore::OreGateway;
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult};
-use crate::hooks::{use_gateway, use_wss_subscription};
+use crate::hooks::{use_gateway, use_unconfirmed_accounts, use_wss_subscription};
 
 pub(crate) fn use_boost_proof_wss_provider() {
     let signal = use_boost_proof_signal();
@@ -20,11 +21,16 @@ fn use_boost_proof_signal() -> Signal<GatewayResult<Proof>> {
 
     // Init
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
+    let mut unconfirmed = use_unconfirmed_accounts();
     use_effect(move || {
         spawn(async move {
             let gateway = use_gateway();
+            let commitment = commitment();
             match gateway.get_proof(proof_address).await {
-                Ok(boost) => data.set(Ok(boost)),
+                Ok(boost) => {
+                    unconfirmed.mark(proof_address, commitment);
+                    data.set(Ok(boost))
+                }
                 Err(err) => {
                     log::error!("Failed to initialize boost proof: {:?}", err);
                     data.set(Err(err));
@@ -10,9 +10,10 @@ use solana_sdk::pubkey::Pubkey;
 use steel::AccountDeserialize;
 
 use crate::config::{LISTED_BOOSTS, UNLISTED_BOOSTS};
+use crate::gateway::commitment::commitment;
 use crate::gateway::ore::OreGateway;
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult};
-use crate::hooks::{use_gateway, use_wss_subscription};
+use crate::hooks::{use_gateway, use_unconfirmed_accounts, use_wss_subscription};
 
 pub(crate) fn use_boosts_wss_provider() {
     // Hashmap to cache resources
@@ -41,13 +42,18 @@ pub(crate) fn use_boosts_wss_provider() {
 fn use_boost_signal(boost_address: Pubkey) -> Signal<GatewayResult<Boost>> {
     // Create and initialize the data signal
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
+    let mut unconfirmed = use_unconfirmed_accounts();
 
     // Initialize data with current boost
     use_effect(move || {
         spawn(async move {
             let gateway = use_gateway();
+            let commitment = commitment();
             match gateway.get_boost(boost_address).await {
-                Ok(boost) => data.set(Ok(boost)),
+                Ok(boost) => {
+                    unconfirmed.mark(boost_address, commitment);
+                    data.set(Ok(boost))
+                }
                 Err(err) => {
                     log::error!("Failed to initialize boost: {:?}", err);
                     data.set(Err(err));
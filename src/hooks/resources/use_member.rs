@@ -5,8 +5,14 @@ use ore_pool_types::Member as MemberRecord;
 use steel::AccountDeserialize;
 
 use crate::{
-    gateway::{pool::PoolGateway, AccountNotificationParams, GatewayError, GatewayResult},
-    hooks::{use_gateway, use_pool, use_pool_deprecated, use_wallet, GetPubkey, Wallet},
+    gateway::{
+        commitment::commitment, pool::PoolGateway, AccountNotificationParams, GatewayError,
+        GatewayResult,
+    },
+    hooks::{
+        use_gateway, use_pool, use_pool_deprecated, use_unconfirmed_accounts, use_wallet,
+        GetPubkey, Wallet,
+    },
 };
 
 use super::use_wss_subscription;
@@ -27,13 +33,18 @@ pub fn use_member() -> Signal<GatewayResult<Member>> {
 fn use_member_wss() -> Signal<GatewayResult<Member>> {
     let wallet = use_wallet();
     let pool = use_pool();
+    let mut unconfirmed = use_unconfirmed_accounts();
     // init signal
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
     use_effect(move || {
         if let (Wallet::Connected(pubkey), Some(pool)) = (wallet.cloned(), pool.cloned()) {
             let address = member_pda(pubkey, pool.address).0;
             spawn(async move {
+                let commitment = commitment();
                 let member = use_gateway().get_member(address).await;
+                if member.is_ok() {
+                    unconfirmed.mark(address, commitment);
+                }
                 data.set(member);
             });
         }
@@ -7,9 +7,10 @@ use ore_boost_api::state::reserve_pda;
 use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 use solana_sdk::program_pack::Pack;
 
+use crate::gateway::commitment::commitment;
 use crate::gateway::spl::SplGateway;
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult, UiTokenAmount};
-use crate::hooks::{use_gateway, use_wss_subscription};
+use crate::hooks::{use_gateway, use_unconfirmed_accounts, use_wss_subscription};
 use crate::solana::{spl_associated_token_account, spl_token};
 
 pub(crate) fn use_reserve_balance_wss_provider() {
@@ -28,8 +29,10 @@ fn use_reserve_balance_signal() -> Signal<GatewayResult<UiTokenAmount>> {
 
     // Init
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
+    let mut unconfirmed = use_unconfirmed_accounts();
     let _ = use_resource(move || async move {
         let gateway = use_gateway();
+        let commitment = commitment();
         match gateway
             .rpc
             .get_token_balance(&reserve_address, &ore_api::consts::MINT_ADDRESS)
@@ -37,6 +40,7 @@ fn use_reserve_balance_signal() -> Signal<GatewayResult<UiTokenAmount>> {
         {
             Ok(balance) => {
                 log::info!("balance: {:?}", balance);
+                unconfirmed.mark(*reserve_tokens_address.peek(), commitment);
                 data.set(Ok(balance))
             }
             Err(err) => {
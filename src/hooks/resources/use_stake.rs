@@ -7,8 +7,10 @@ use steel::Pubkey;
 
 use crate::{
     config::LISTED_BOOSTS,
-    gateway::{ore::OreGateway, GatewayError, GatewayResult, UiTokenAmount},
-    hooks::{use_gateway, use_wallet, Wallet},
+    gateway::{
+        commitment::commitment, ore::OreGateway, GatewayError, GatewayResult, UiTokenAmount,
+    },
+    hooks::{use_gateway, use_unconfirmed_accounts, use_wallet, Wallet},
     solana::spl_token::ui_amount_to_amount,
     utils::LiquidityPair,
 };
@@ -31,16 +33,17 @@ pub(crate) fn use_stakes_provider() {
 
 fn use_stake_resource(mint_address: Pubkey) -> Resource<GatewayResult<Stake>> {
     let wallet = use_wallet();
+    let mut unconfirmed = use_unconfirmed_accounts();
     use_resource(move || async move {
         match *wallet.read() {
             Wallet::Disconnected => Err(GatewayError::WalletDisconnected),
             Wallet::Connected(authority) => {
                 let boost_address = boost_pda(mint_address).0;
                 let stake_address = stake_pda(authority, boost_address).0;
-                use_gateway()
-                    .get_stake(stake_address)
-                    .await
-                    .map_err(GatewayError::from)
+                let commitment = commitment();
+                let stake = use_gateway().get_stake(stake_address).await?;
+                unconfirmed.mark(stake_address, commitment);
+                Ok(stake)
             }
         }
     })
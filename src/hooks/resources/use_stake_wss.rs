@@ -10,9 +10,13 @@ use solana_sdk::pubkey::Pubkey;
 use steel::AccountDeserialize;
 
 use crate::config::{LISTED_BOOSTS, UNLISTED_BOOSTS};
+use crate::gateway::commitment::commitment;
 use crate::gateway::ore::OreGateway;
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult, UiTokenAmount};
-use crate::hooks::{use_gateway, use_wallet, use_wss_subscription, Wallet};
+use crate::hooks::{
+    use_gateway, use_unconfirmed, use_unconfirmed_accounts, use_wallet, use_wss_subscription,
+    Wallet,
+};
 use crate::solana::spl_token::ui_amount_to_amount;
 use crate::utils::LiquidityPair;
 
@@ -42,6 +46,7 @@ fn use_stake_signal(mint_address: Pubkey) -> Signal<GatewayResult<Stake>> {
     let boost_address = boost_pda(mint_address).0;
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
     let wallet = use_wallet();
+    let mut unconfirmed = use_unconfirmed_accounts();
 
     // Initialize data with current boost
     use_effect(move || {
@@ -50,8 +55,12 @@ fn use_stake_signal(mint_address: Pubkey) -> Signal<GatewayResult<Stake>> {
             log::info!("stake address: {:?}", stake_address);
             spawn(async move {
                 let gateway = use_gateway();
+                let commitment = commitment();
                 match gateway.get_stake(stake_address).await {
-                    Ok(stake) => data.set(Ok(stake)),
+                    Ok(stake) => {
+                        unconfirmed.mark(stake_address, commitment);
+                        data.set(Ok(stake))
+                    }
                     Err(err) => {
                         log::error!("Failed to initialize stake: {:?}", err);
                         data.set(Err(err));
@@ -99,6 +108,16 @@ pub fn use_stake_wss(mint_address: Pubkey) -> Signal<GatewayResult<Stake>> {
     }
 }
 
+/// Whether the wallet's stake was recently updated from data that is not yet finalized.
+pub fn use_stake_unconfirmed(mint_address: Pubkey) -> Memo<bool> {
+    let wallet = use_wallet();
+    let address = use_memo(move || match *wallet.read() {
+        Wallet::Connected(pubkey) => Some(stake_pda(pubkey, boost_pda(mint_address).0).0),
+        Wallet::Disconnected => None,
+    });
+    use_unconfirmed(address)
+}
+
 pub fn use_all_stakes() -> HashMap<Pubkey, Signal<GatewayResult<Stake>>> {
     use_context()
 }
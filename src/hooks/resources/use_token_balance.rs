@@ -4,11 +4,15 @@ use std::collections::HashMap;
 
 use crate::{
     config::{Token, LISTED_TOKENS},
-    gateway::{spl::SplGateway, GatewayError, GatewayResult, Rpc, UiTokenAmount},
+    gateway::{
+        commitment::commitment, spl::SplGateway, GatewayError, GatewayResult, Rpc, UiTokenAmount,
+    },
     utils::LiquidityPair,
 };
 
-use crate::hooks::{use_gateway, use_wallet, Wallet};
+use crate::hooks::{
+    use_gateway, use_unconfirmed_accounts, use_wallet, UnconfirmedAccounts, Wallet,
+};
 
 use super::{use_ore_price, OrePrice};
 
@@ -25,10 +29,11 @@ pub(crate) fn use_token_balance_provider() {
 
 fn use_token_balance_resource(mint: Pubkey) -> Resource<GatewayResult<UiTokenAmount>> {
     let wallet_status = use_wallet();
+    let unconfirmed = use_unconfirmed_accounts();
     use_resource(move || async move {
         match *wallet_status.read() {
             Wallet::Disconnected => Err(GatewayError::AccountNotFound.into()),
-            Wallet::Connected(pubkey) => get_token_balance(pubkey, mint).await,
+            Wallet::Connected(pubkey) => get_token_balance(pubkey, mint, unconfirmed).await,
         }
     })
 }
@@ -57,14 +62,18 @@ pub fn _use_token_balance_for_token(
     }
 }
 
+/// Fetches a wallet's balance of a token, marking the balance account if the read is not
+/// yet finalized.
 pub(super) async fn get_token_balance(
     pubkey: Pubkey,
     mint: Pubkey,
+    mut unconfirmed: UnconfirmedAccounts,
 ) -> GatewayResult<UiTokenAmount> {
-    if mint == Token::sol().mint {
+    let commitment = commitment();
+    let balance = if mint == Token::sol().mint {
         use_gateway()
             .rpc
-            .get_balance(&pubkey)
+            .get_balance(&pubkey, commitment)
             .await
             .map(|lamports| {
                 let sol = lamports_to_sol(lamports);
@@ -82,6 +91,18 @@ pub(super) async fn get_token_balance(
             .get_token_balance(&pubkey, &mint)
             .await
             .map_err(GatewayError::from)
+    }?;
+    unconfirmed.mark(balance_address(pubkey, mint), commitment);
+    Ok(balance)
+}
+
+/// Account holding a wallet's balance of a token.
+pub(super) fn balance_address(owner: Pubkey, mint: Pubkey) -> Pubkey {
+    match mint.eq(&Token::sol().mint) {
+        true => owner,
+        false => {
+            crate::solana::spl_associated_token_account::get_associated_token_address(&owner, &mint)
+        }
     }
 }
 
@@ -92,6 +113,7 @@ pub fn use_token_balances_for_liquidity_pair(
     Signal<GatewayResult<UiTokenAmount>>,
 ) {
     let wallet = use_wallet();
+    let unconfirmed = use_unconfirmed_accounts();
 
     let token_a_balance = use_signal(|| Err(GatewayError::AccountNotFound));
     use_effect({
@@ -107,7 +129,8 @@ pub fn use_token_balances_for_liquidity_pair(
                         spawn({
                             let mut token_a_balance = token_a_balance.clone();
                             async move {
-                                let bal = get_token_balance(authority, token_mint).await;
+                                let bal =
+                                    get_token_balance(authority, token_mint, unconfirmed).await;
                                 token_a_balance.set(bal);
                             }
                         });
@@ -133,7 +156,8 @@ pub fn use_token_balances_for_liquidity_pair(
                         spawn({
                             let mut token_b_balance = token_b_balance.clone();
                             async move {
-                                let bal = get_token_balance(authority, token_mint).await;
+                                let bal =
+                                    get_token_balance(authority, token_mint, unconfirmed).await;
                                 token_b_balance.set(bal);
                             }
                         });
@@ -154,10 +178,13 @@ pub fn _use_sol_balance() -> Resource<GatewayResult<UiTokenAmount>> {
 
 pub fn use_ore_balance() -> Resource<GatewayResult<UiTokenAmount>> {
     let wallet_status = use_wallet();
+    let unconfirmed = use_unconfirmed_accounts();
     use_resource(move || async move {
         match *wallet_status.read() {
             Wallet::Disconnected => Err(GatewayError::AccountNotFound.into()),
-            Wallet::Connected(pubkey) => use_gateway().rpc.get_ore_balance(&pubkey).await,
+            Wallet::Connected(pubkey) => {
+                get_token_balance(pubkey, ore_api::consts::MINT_ADDRESS, unconfirmed).await
+            }
         }
     })
 }
@@ -11,9 +11,11 @@ use solana_sdk::pubkey::Pubkey;
 
 use crate::config::{Token, LISTED_TOKENS, UNLISTED_TOKENS};
 use crate::gateway::{AccountNotificationParams, GatewayError, GatewayResult, UiTokenAmount};
-use crate::hooks::{use_wallet, use_wss_subscription, Wallet};
+use crate::hooks::{
+    use_unconfirmed, use_unconfirmed_accounts, use_wallet, use_wss_subscription, Wallet,
+};
 
-use super::get_token_balance;
+use super::{balance_address, get_token_balance};
 
 pub fn use_token_balance_wss_provider() {
     let mut token_balances = HashMap::new();
@@ -45,6 +47,17 @@ pub fn use_token_balance_wss(mint: &Pubkey) -> Signal<GatewayResult<UiTokenAmoun
     }
 }
 
+/// Whether the wallet's balance of a token was recently updated from data that is not yet
+/// finalized.
+pub fn use_token_balance_unconfirmed(mint: Memo<Pubkey>) -> Memo<bool> {
+    let wallet = use_wallet();
+    let address = use_memo(move || match *wallet.read() {
+        Wallet::Connected(pubkey) => Some(balance_address(pubkey, mint())),
+        Wallet::Disconnected => None,
+    });
+    use_unconfirmed(address)
+}
+
 pub fn use_sol_balance_wss() -> Signal<GatewayResult<UiTokenAmount>> {
     let cache: HashMap<Pubkey, Signal<GatewayResult<UiTokenAmount>>> = use_context();
     match cache.get(&Token::sol().mint) {
@@ -122,12 +135,13 @@ where
     U: Fn(&AccountNotificationParams) -> GatewayResult<UiTokenAmount> + Clone + 'static,
 {
     let wallet = use_wallet();
+    let unconfirmed = use_unconfirmed_accounts();
     // Create and initialize the data signal
     let mut data = use_signal(|| Err(GatewayError::AccountNotFound));
     use_effect(move || {
         if let Wallet::Connected(pubkey) = *wallet.read() {
             spawn(async move {
-                match get_token_balance(pubkey, mint).await {
+                match get_token_balance(pubkey, mint, unconfirmed).await {
                     Ok(initial_data) => data.set(Ok(initial_data)),
                     Err(err) => {
                         log::error!("Failed to initialize token balance: {:?}", err);
@@ -142,13 +156,7 @@ where
     let subscriber = use_wss_subscription(data.clone(), update_callback.clone());
     use_effect(move || {
         if let Wallet::Connected(pubkey) = *wallet.read() {
-            let address = match mint.eq(&Token::sol().mint) {
-                true => pubkey,
-                false => crate::solana::spl_associated_token_account::get_associated_token_address(
-                    &pubkey, &mint,
-                ),
-            };
-            subscriber.send(address);
+            subscriber.send(balance_address(pubkey, mint));
         }
     });
 
@@ -1,13 +1,16 @@
 use dioxus::prelude::*;
 use futures::{
-    channel::mpsc::{self, Receiver, Sender},
+    channel::mpsc::{self, Receiver, Sender, UnboundedSender},
     FutureExt, SinkExt, StreamExt,
 };
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::collections::HashMap;
 
 use crate::gateway::{
-    AccountNotificationParams, AccountSubscribe, AccountSubscribeGateway, GatewayError,
+    commitment::Commitment, endpoints, set_signature_worker, AccountNotificationParams,
+    AccountSubscribe, AccountSubscribeConfig, AccountSubscribeGateway, ConfirmationNotification,
+    GatewayError, Notification, SignatureEvent, SignatureSubscribe, SubscriptionError,
+    WatchRequest,
 };
 use crate::time::sleep;
 
@@ -20,14 +23,28 @@ pub enum FromWssMsg {
     Subscription(SubRequestId, SubId),
     Notif(AccountNotificationParams),
 }
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub enum ToWssMsg {
-    Subscribe(SubRequestId, Pubkey),
-    Unsubscribe(SubId),
+    Subscribe(SubRequestId, Pubkey, AccountSubscribeConfig),
+    /// Drops the listener added by a subscribe request, whether or not it was answered yet.
+    Unsubscribe(SubRequestId),
 }
+/// Subscription id handed out to consumers. It is shared by every consumer of the same
+/// stream and stays the same across reconnects.
 type SubId = u64;
 type SubRequestId = u64;
+/// Subscription id assigned by the rpc server for the current connection.
+type ServerSubId = u64;
+
+/// State of the shared WebSocket connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WssConnectionState {
+    Connecting,
+    Connected,
+    /// The connection dropped and is being retried, with the number of failed attempts so far.
+    Reconnecting(u32),
+}
 
 /// Two way channel backed by a WebSocket
 /// for subscribing to notifications from the RPC server.
@@ -37,6 +54,11 @@ pub(super) fn use_wss() -> (FromWss, ToWss) {
     (from, to)
 }
 
+/// State of the shared WebSocket connection.
+pub fn use_wss_connection_state() -> Signal<WssConnectionState> {
+    use_context()
+}
+
 /// Impl
 ///
 /// Two way channel backed by a WebSocket
@@ -44,26 +66,37 @@ pub(super) fn use_wss() -> (FromWss, ToWss) {
 pub fn use_wss_provider() {
     // Init from wss
     let mut from_wss = use_context_provider(|| Signal::new(FromWssMsg::Init));
+    // Init connection state
+    let connection_state = use_context_provider(|| Signal::new(WssConnectionState::Connecting));
     // Init to wss
     let _to_wss = use_coroutine(move |mut rx: UnboundedReceiver<ToWssMsg>| async move {
         if let Err(err) = async {
             // Create channel for sending commands to the WebSocket worker
             let (cmd_tx, cmd_rx) = mpsc::channel::<WssCommand>(100);
 
+            // Route transaction confirmations through the same connection
+            let (watch_tx, watch_rx) = mpsc::unbounded::<WatchRequest>();
+            set_signature_worker(watch_tx);
+
             // Spawn the WebSocket worker task that owns the WebSocket connection exclusively
-            spawn(wss_worker(cmd_rx, from_wss.clone()));
+            spawn(wss_worker(cmd_rx, watch_rx, from_wss, connection_state));
 
             // Handle UI commands and forward them to the WebSocket worker
             while let Some(msg) = rx.next().await {
                 match msg {
-                    ToWssMsg::Subscribe(request_id, pubkey) => {
+                    ToWssMsg::Subscribe(request_id, pubkey, config) => {
                         // Create a one-shot channel for the subscription ID response
                         let (sub_resp_tx, mut sub_resp_rx) = mpsc::channel::<SubId>(1);
 
                         // Send the subscribe command to the worker
                         if let Err(e) = cmd_tx
                             .clone()
-                            .send(WssCommand::Subscribe(request_id, pubkey, sub_resp_tx))
+                            .send(WssCommand::Subscribe(
+                                request_id,
+                                pubkey,
+                                config,
+                                sub_resp_tx,
+                            ))
                             .await
                         {
                             log::error!("Failed to send subscribe command: {:?}", e);
@@ -75,9 +108,13 @@ pub fn use_wss_provider() {
                             from_wss.set(FromWssMsg::Subscription(request_id, sub_id));
                         }
                     }
-                    ToWssMsg::Unsubscribe(sub_id) => {
+                    ToWssMsg::Unsubscribe(request_id) => {
                         // Send the unsubscribe command to the worker
-                        if let Err(e) = cmd_tx.clone().send(WssCommand::Unsubscribe(sub_id)).await {
+                        if let Err(e) = cmd_tx
+                            .clone()
+                            .send(WssCommand::Unsubscribe(request_id))
+                            .await
+                        {
                             log::error!("Failed to send unsubscribe command: {:?}", e);
                         }
                     }
@@ -93,83 +130,111 @@ pub fn use_wss_provider() {
     });
 }
 
+const INITIAL_RETRY_DELAY_MS: u64 = 1000; // 1 second
+const MAX_RETRY_DELAY_MS: u64 = 60 * 1000; // 60 seconds
+const ENDPOINT_CHECK_INTERVAL_MS: u64 = 10 * 1000; // 10 seconds
+
 /// WebSocket worker function that owns the WebSocket connection exclusively
-async fn wss_worker(mut cmd_rx: Receiver<WssCommand>, mut from_wss: Signal<FromWssMsg>) {
-    // Map SubId -> (Pubkey, SubRequestId)
-    let mut active_subscriptions: HashMap<SubId, (Pubkey, SubRequestId)> = HashMap::new();
-    let mut retry_delay_ms = 1000u64; // Start with 1 second
-    const MAX_RETRY_DELAY_MS: u64 = 60 * 1000; // 60 seconds
+///
+/// Consumers of the same account and config share one server subscription, which is
+/// closed once the last of them unsubscribes. They are handed local subscription ids,
+/// and on every (re)connect the registered subscriptions are replayed and notifications
+/// are routed back under the local ids, so consumers keep receiving updates without
+/// resubscribing.
+///
+/// Submitted transactions are followed on the same connection. Watchers of a signature share
+/// its subscriptions, all of them share one slot subscription, and they are told when the
+/// connection drops so they can poll until it is restored.
+async fn wss_worker(
+    mut cmd_rx: Receiver<WssCommand>,
+    mut watch_rx: mpsc::UnboundedReceiver<WatchRequest>,
+    mut from_wss: Signal<FromWssMsg>,
+    mut connection_state: Signal<WssConnectionState>,
+) {
+    let mut registry = SubscriptionRegistry::default();
+    let mut signatures = SignatureRegistry::default();
+    let mut attempt = 0u32;
 
     // Outer loop for handling reconnections
     'reconnect: loop {
+        signatures.disconnect();
+        if attempt > 0 {
+            let delay = backoff_delay(attempt);
+            log::warn!(
+                "WebSocket disconnected. Reconnecting in {}ms (attempt {})...",
+                delay,
+                attempt
+            );
+            connection_state.set(WssConnectionState::Reconnecting(attempt));
+            if !wait_for_reconnect(
+                delay,
+                &mut cmd_rx,
+                &mut watch_rx,
+                &mut registry,
+                &mut signatures,
+            )
+            .await
+            {
+                log::info!("Command channel closed. Exiting WebSocket worker.");
+                return;
+            }
+        } else {
+            connection_state.set(WssConnectionState::Connecting);
+        }
+
         // Attempt to connect
         log::info!("Attempting WebSocket connection...");
         let mut wss = match AccountSubscribeGateway::connect().await {
-            Ok(wss) => {
-                log::info!("WebSocket connected successfully.");
-                retry_delay_ms = 1000; // Reset delay on successful connection
-                wss
-            }
-            Err(e) => {
-                log::error!(
-                    "Failed to connect to WebSocket: {:?}. Retrying in {}ms...",
-                    e,
-                    retry_delay_ms
-                );
-                sleep(retry_delay_ms).await;
-                retry_delay_ms = (retry_delay_ms * 2).min(MAX_RETRY_DELAY_MS);
-                continue 'reconnect; // Retry connection
+            Ok(wss) => wss,
+            Err(err) => {
+                log::error!("Failed to connect to WebSocket: {:?}", err);
+                attempt += 1;
+                continue 'reconnect;
             }
         };
 
-        // Re-subscribe to all previously active subscriptions
-        let subscriptions_to_restore = active_subscriptions.values().cloned().collect::<Vec<_>>();
-        active_subscriptions.clear(); // Clear old IDs, will be repopulated with new ones
-
-        log::info!(
-            "Attempting to restore {} subscriptions...",
-            subscriptions_to_restore.len()
-        );
-        for (pubkey, request_id) in subscriptions_to_restore {
-            match wss.subscribe(pubkey.to_string().as_str(), request_id).await {
-                Ok(new_sub_id) => {
-                    log::info!(
-                        "Successfully re-subscribed pubkey {} (request_id: {}): new sub_id {}",
-                        pubkey,
-                        request_id,
-                        new_sub_id
-                    );
-                    active_subscriptions.insert(new_sub_id, (pubkey, request_id));
-                    // Notify UI about the new subscription ID for the original request
-                    from_wss.set(FromWssMsg::Subscription(request_id, new_sub_id));
+        // Replay every registered subscription on the new connection
+        registry.unbind_all();
+        for (sub_id, pubkey, config, request_id) in registry.unbound() {
+            match wss
+                .subscribe(pubkey.to_string().as_str(), &config, request_id)
+                .await
+            {
+                Ok(server_id) => registry.bind(sub_id, server_id),
+                Err(err) if err.is_connection_error() => {
+                    log::error!("Connection lost while restoring subscriptions: {:?}", err);
+                    endpoints::report_failure(wss.url());
+                    attempt += 1;
+                    continue 'reconnect;
                 }
                 Err(err) => {
-                    log::error!(
-                        "Failed to re-subscribe pubkey {} (request_id: {}): {:?}",
-                        pubkey,
-                        request_id,
-                        err
-                    );
-                    // TODO: How to signal this failure to the specific subscriber?
-                    // For now, the subscription is simply lost. The UI component might
-                    // need to detect the lack of updates and potentially retry manually.
+                    log::error!("Failed to restore subscription to {}: {:?}", pubkey, err);
                 }
             }
         }
-        log::info!("Finished attempting subscription restoration.");
-
-        // Create a channel for forwarding notifications to the handler task
-        // This needs to be recreated on each connection attempt as the old task might have ended
-        let (notification_tx, mut notification_rx) = mpsc::channel(10);
-
-        // Spawn a task to listen for notifications forwarded from the select loop
-        // This task reads from notification_rx and updates the UI state (from_wss)
-        let _notification_handler_task = spawn(async move {
-            while let Some(notif) = notification_rx.next().await {
-                from_wss.set(FromWssMsg::Notif(notif));
+        for signature in signatures.unbound() {
+            match subscribe_signature(&mut wss, &mut signatures, signature).await {
+                Ok(()) => {}
+                Err(err) if err.is_connection_error() => {
+                    log::error!("Connection lost while restoring subscriptions: {:?}", err);
+                    endpoints::report_failure(wss.url());
+                    attempt += 1;
+                    continue 'reconnect;
+                }
+                Err(err) => {
+                    log::error!("Failed to restore subscription to {}: {:?}", signature, err);
+                }
             }
-            log::warn!("Notification handler task finished."); // Should ideally not happen unless channel closes
-        });
+        }
+        log::info!(
+            "WebSocket connected with {} active subscriptions.",
+            registry.len()
+        );
+        connection_state.set(WssConnectionState::Connected);
+        attempt = 0;
+
+        // One timer across iterations, so a busy socket doesn't keep resetting it
+        let mut endpoint_check = Box::pin(sleep(ENDPOINT_CHECK_INTERVAL_MS)).fuse();
 
         // Inner loop to process commands and notifications for the current connection
         loop {
@@ -177,61 +242,68 @@ async fn wss_worker(mut cmd_rx: Receiver<WssCommand>, mut from_wss: Signal<FromW
                 // Handle commands from the UI coroutine
                 cmd = cmd_rx.next() => {
                     match cmd {
-                        Some(WssCommand::Subscribe(request_id, pubkey, resp_tx)) => {
-                            // TODO: Need to handle potential errors during subscribe/unsubscribe
-                            // that might indicate a dead connection, potentially triggering reconnect.
-                            match wss.subscribe(pubkey.to_string().as_str(), request_id).await {
-                                Ok(sub_id) => {
-                                    log::info!(
-                                        "Subscribed pubkey {} (request_id: {}): sub_id {}",
-                                        pubkey,
-                                        request_id,
-                                        sub_id
-                                    );
-                                    // Track the active subscription
-                                    active_subscriptions.insert(sub_id, (pubkey, request_id));
-                                    // Send subscription ID back to the caller (use_wss_subscription)
-                                    if resp_tx.clone().send(sub_id).await.is_err() {
-                                        log::warn!("Failed to send subscription ID response back to caller (channel closed).");
-                                        // If we can't send back, the caller might be gone. Clean up?
-                                        // Attempt to unsubscribe immediately if the caller is gone.
-                                        if let Err(e) = wss.unsubscribe(sub_id).await {
-                                             log::error!("Failed to auto-unsubscribe after caller disappeared: {:?}", e);
-                                        }
-                                        active_subscriptions.remove(&sub_id);
-                                    }
-                                    // Also notify the general listener (redundant with resp_tx but follows pattern)
-                                    // This ensures the UI gets the initial sub_id via the FromWssMsg stream too.
-                                    from_wss.set(FromWssMsg::Subscription(request_id, sub_id));
+                        Some(WssCommand::Subscribe(request_id, pubkey, config, resp_tx)) => {
+                            // Listeners joining an open stream need no new server subscription
+                            let Some(sub_id) = registry.register(request_id, pubkey, config.clone(), resp_tx).await else {
+                                continue;
+                            };
+                            match wss.subscribe(pubkey.to_string().as_str(), &config, request_id).await {
+                                Ok(server_id) => {
+                                    log::info!("Subscribed pubkey {} (sub_id: {})", pubkey, sub_id);
+                                    registry.bind(sub_id, server_id);
+                                }
+                                Err(err) if err.is_connection_error() => {
+                                    // The subscription stays registered and is replayed on reconnect
+                                    log::error!("Failed to subscribe pubkey {}: {:?}", pubkey, err);
+                                    endpoints::report_failure(wss.url());
+                                    attempt = 1;
+                                    break;
                                 }
                                 Err(err) => {
-                                    log::error!("Failed to subscribe pubkey {} (request_id: {}): {:?}", pubkey, request_id, err);
-                                    // TODO: Should we signal failure back via resp_tx? Requires changing resp_tx type.
-                                    // Consider if this error warrants a reconnect attempt
+                                    log::error!("Failed to subscribe pubkey {}: {:?}", pubkey, err);
                                 }
                             }
                         }
-                        Some(WssCommand::Unsubscribe(sub_id)) => {
-                            // Remove from tracking *before* sending unsubscribe, in case of error
-                            if let Some((pubkey, request_id)) = active_subscriptions.remove(&sub_id) {
-                                log::info!(
-                                    "Unsubscribing sub_id {} (pubkey: {}, request_id: {})",
-                                    sub_id, pubkey, request_id
-                                );
-                                if let Err(err) = wss.unsubscribe(sub_id).await {
-                                    log::error!("Failed to unsubscribe sub_id {}: {:?}", sub_id, err);
-                                    // If unsubscribe fails, should we put it back in the map?
-                                    // Probably not, as the state is now uncertain.
-                                    // Consider if this error warrants a reconnect attempt.
+                        Some(WssCommand::Unsubscribe(request_id)) => {
+                            // Only the last listener of a stream closes it on the server
+                            match registry.release(request_id) {
+                                Some((sub_id, Subscription { server_id: Some(server_id), .. })) => {
+                                    if let Err(err) = wss.unsubscribe(server_id).await {
+                                        log::error!("Failed to unsubscribe sub_id {}: {:?}", sub_id, err);
+                                        if err.is_connection_error() {
+                                            endpoints::report_failure(wss.url());
+                                            attempt = 1;
+                                            break;
+                                        }
+                                    }
                                 }
-                            } else {
-                                log::warn!("Attempted to unsubscribe unknown sub_id: {}", sub_id);
+                                _ => {}
                             }
                         }
                         None => {
                             // Command channel closed, UI coroutine likely dropped. Exit the worker completely.
                             log::info!("Command channel closed. Exiting WebSocket worker.");
-                            return; // Exit the entire wss_worker function
+                            return;
+                        }
+                    }
+                }
+
+                // Handle signatures to follow for submitted transactions
+                request = watch_rx.next() => {
+                    let Some(request) = request else {
+                        continue;
+                    };
+                    let signature = request.signature;
+                    if !signatures.watch(request) {
+                        continue;
+                    }
+                    if let Err(err) = subscribe_signature(&mut wss, &mut signatures, signature).await {
+                        // The signature stays registered and is replayed on reconnect
+                        log::error!("Failed to subscribe signature {}: {:?}", signature, err);
+                        if err.is_connection_error() {
+                            endpoints::report_failure(wss.url());
+                            attempt = 1;
+                            break;
                         }
                     }
                 }
@@ -239,43 +311,402 @@ async fn wss_worker(mut cmd_rx: Receiver<WssCommand>, mut from_wss: Signal<FromW
                 // Handle notifications from the WebSocket connection
                 notification_result = wss.next_notification().fuse() => {
                     match notification_result {
-                        Ok(notification) => {
-                            // Forward the notification to the handler task via the channel
-                            if let Err(e) = notification_tx.clone().send(notification.params).await {
-                                log::error!("Failed to forward notification to handler task: {:?}. Channel likely closed.", e);
-                                // If the channel is closed, the handler task might have panicked or finished.
-                                // This might indicate a need to restart the connection/worker.
-                                // For now, we'll log and continue, but this could be a reconnect trigger.
+                        Ok(Notification::Account(mut params)) => {
+                            // Route the notification under the id the consumer was handed
+                            if let Some(sub_id) = registry.local_id(params.subscription) {
+                                params.subscription = sub_id;
+                                from_wss.set(FromWssMsg::Notif(params));
+                            }
+                        }
+                        Ok(Notification::Confirmation(notification)) => {
+                            let released = signatures.notify(notification);
+                            if let Err(err) = close_signature_subscriptions(&mut wss, released).await {
+                                log::error!("Failed to close signature subscriptions: {:?}", err);
+                                endpoints::report_failure(wss.url());
+                                attempt = 1;
+                                break;
                             }
                         }
                         Err(e) => {
                             // An error here likely means the WebSocket connection is broken.
                             log::error!("WebSocket notification error: {:?}. Triggering reconnect.", e);
-                            // Break the inner loop to trigger reconnection in the outer loop
-                            break; // Exit inner loop, go to 'reconnect loop start
+                            endpoints::report_failure(wss.url());
+                            attempt = 1;
+                            break;
                         }
                     }
                 }
 
-                // Make select! biased towards completion to avoid starvation if one branch is always ready
-                complete => break, // Exit inner loop if select! completes (e.g., both futures resolved/closed)
+                // Reconnect immediately if the active endpoint changed due to a failover or user edit
+                _ = endpoint_check => {
+                    if endpoints::wss_url() != wss.url() {
+                        log::info!("Active endpoint changed. Reconnecting WebSocket.");
+                        break;
+                    }
+                    endpoint_check = Box::pin(sleep(ENDPOINT_CHECK_INTERVAL_MS)).fuse();
+                }
             }
         }
+    }
+}
 
-        // If we break out of the inner loop due to an error, prepare for reconnection attempt
-        log::warn!(
-            "WebSocket connection lost or error occurred. Attempting reconnect after {}ms delay...",
-            retry_delay_ms
-        );
-        sleep(retry_delay_ms).await;
-        retry_delay_ms = (retry_delay_ms * 2).min(MAX_RETRY_DELAY_MS);
-        // The outer 'reconnect loop will now iterate, attempting to connect again.
+/// Exponential backoff with jitter, so clients dropped together don't reconnect in lockstep.
+fn backoff_delay(attempt: u32) -> u64 {
+    let exponent = attempt.saturating_sub(1).min(6);
+    let max = (INITIAL_RETRY_DELAY_MS << exponent).min(MAX_RETRY_DELAY_MS);
+    max / 2 + fastrand::u64(..=max / 2)
+}
+
+/// Waits out a reconnect delay while still accepting commands, so consumers are never
+/// blocked on a dropped connection. Returns false if the command channel closed.
+async fn wait_for_reconnect(
+    delay_ms: u64,
+    cmd_rx: &mut Receiver<WssCommand>,
+    watch_rx: &mut mpsc::UnboundedReceiver<WatchRequest>,
+    registry: &mut SubscriptionRegistry,
+    signatures: &mut SignatureRegistry,
+) -> bool {
+    let delay = sleep(delay_ms).fuse();
+    futures::pin_mut!(delay);
+    loop {
+        futures::select! {
+            _ = delay => return true,
+            cmd = cmd_rx.next() => {
+                match cmd {
+                    Some(WssCommand::Subscribe(request_id, pubkey, config, resp_tx)) => {
+                        // Subscribed once the connection is restored
+                        registry.register(request_id, pubkey, config, resp_tx).await;
+                    }
+                    Some(WssCommand::Unsubscribe(request_id)) => {
+                        registry.release(request_id);
+                    }
+                    None => return false,
+                }
+            }
+            request = watch_rx.next() => {
+                // Subscribed once the connection is restored
+                if let Some(request) = request {
+                    signatures.watch(request);
+                }
+            }
+        }
+    }
+}
+
+/// Subscribes a signature at both commitments, along with the slot stream if it is not open
+/// yet, and tells its watchers once done.
+async fn subscribe_signature(
+    wss: &mut AccountSubscribeGateway,
+    signatures: &mut SignatureRegistry,
+    signature: Signature,
+) -> Result<(), SubscriptionError> {
+    for commitment in [Commitment::Processed, Commitment::Confirmed] {
+        let server_id = wss
+            .signature_subscribe(&signature.to_string(), commitment, fastrand::u64(..))
+            .await?;
+        signatures.bind(signature, commitment, server_id);
+    }
+    if signatures.slot.is_none() {
+        signatures.slot = Some(wss.slot_subscribe(fastrand::u64(..)).await?);
+    }
+    signatures.send(&signature, SignatureEvent::Subscribed);
+    Ok(())
+}
+
+/// Closes subscriptions no watcher needs anymore. Fails only if the connection is lost.
+async fn close_signature_subscriptions(
+    wss: &mut AccountSubscribeGateway,
+    released: ReleasedSubscriptions,
+) -> Result<(), SubscriptionError> {
+    for server_id in released.signatures {
+        if let Err(err) = wss.signature_unsubscribe(server_id).await {
+            if err.is_connection_error() {
+                return Err(err);
+            }
+            log::warn!("Failed to unsubscribe signature {}: {:?}", server_id, err);
+        }
+    }
+    if let Some(server_id) = released.slot {
+        if let Err(err) = wss.slot_unsubscribe(server_id).await {
+            if err.is_connection_error() {
+                return Err(err);
+            }
+            log::warn!("Failed to unsubscribe slots {}: {:?}", server_id, err);
+        }
     }
+    Ok(())
 }
 
 // Internal message types for the WebSocket worker
 #[derive(Debug)]
 enum WssCommand {
-    Subscribe(SubRequestId, Pubkey, Sender<SubId>),
-    Unsubscribe(SubId),
+    Subscribe(SubRequestId, Pubkey, AccountSubscribeConfig, Sender<SubId>),
+    Unsubscribe(SubRequestId),
+}
+
+struct Subscription {
+    pubkey: Pubkey,
+    config: AccountSubscribeConfig,
+    request_id: SubRequestId,
+    /// Number of consumers sharing the stream.
+    listeners: usize,
+    /// Id assigned by the server, if subscribed on the current connection.
+    server_id: Option<ServerSubId>,
+}
+
+/// Active subscriptions, keyed by the local id handed out to consumers.
+#[derive(Default)]
+struct SubscriptionRegistry {
+    last_id: SubId,
+    subscriptions: HashMap<SubId, Subscription>,
+    streams: HashMap<(Pubkey, AccountSubscribeConfig), SubId>,
+    server_ids: HashMap<ServerSubId, SubId>,
+    /// Stream each listener joined, keyed by the request that added it.
+    listeners: HashMap<SubRequestId, SubId>,
+}
+
+impl SubscriptionRegistry {
+    fn len(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    /// Adds a listener and sends the id of its stream back to the caller.
+    ///
+    /// Returns the id if the listener opened a new stream, which then still needs
+    /// to be subscribed on the server.
+    async fn register(
+        &mut self,
+        request_id: SubRequestId,
+        pubkey: Pubkey,
+        config: AccountSubscribeConfig,
+        mut resp_tx: Sender<SubId>,
+    ) -> Option<SubId> {
+        let key = (pubkey, config);
+        let existing = self.streams.get(&key).copied();
+        // Ids start at 1, as consumers treat 0 as unsubscribed
+        let sub_id = existing.unwrap_or(self.last_id + 1);
+        if resp_tx.send(sub_id).await.is_err() {
+            log::warn!(
+                "Subscriber for pubkey {} dropped before subscribing.",
+                pubkey
+            );
+            return None;
+        }
+        self.listeners.insert(request_id, sub_id);
+        if let Some(subscription) = existing.and_then(|id| self.subscriptions.get_mut(&id)) {
+            subscription.listeners += 1;
+            return None;
+        }
+        self.last_id = sub_id;
+        let (pubkey, config) = key;
+        self.streams.insert((pubkey, config.clone()), sub_id);
+        self.subscriptions.insert(
+            sub_id,
+            Subscription {
+                pubkey,
+                config,
+                request_id,
+                listeners: 1,
+                server_id: None,
+            },
+        );
+        Some(sub_id)
+    }
+
+    /// Drops the listener added by a request, returning its stream once no listeners are left.
+    ///
+    /// Listeners are released by request id, so a consumer dropped before its request was
+    /// answered still releases the listener it added.
+    fn release(&mut self, request_id: SubRequestId) -> Option<(SubId, Subscription)> {
+        let Some(sub_id) = self.listeners.remove(&request_id) else {
+            log::warn!("Attempted to unsubscribe unknown request: {}", request_id);
+            return None;
+        };
+        let subscription = self.subscriptions.get_mut(&sub_id)?;
+        subscription.listeners -= 1;
+        if subscription.listeners > 0 {
+            return None;
+        }
+        let subscription = self.subscriptions.remove(&sub_id)?;
+        self.streams
+            .remove(&(subscription.pubkey, subscription.config.clone()));
+        if let Some(server_id) = subscription.server_id {
+            self.server_ids.remove(&server_id);
+        }
+        Some((sub_id, subscription))
+    }
+
+    fn bind(&mut self, sub_id: SubId, server_id: ServerSubId) {
+        if let Some(subscription) = self.subscriptions.get_mut(&sub_id) {
+            subscription.server_id = Some(server_id);
+            self.server_ids.insert(server_id, sub_id);
+        }
+    }
+
+    /// Forgets the server ids of the previous connection.
+    fn unbind_all(&mut self) {
+        self.server_ids.clear();
+        for subscription in self.subscriptions.values_mut() {
+            subscription.server_id = None;
+        }
+    }
+
+    /// Streams not yet subscribed on the current connection.
+    fn unbound(&self) -> Vec<(SubId, Pubkey, AccountSubscribeConfig, SubRequestId)> {
+        self.subscriptions
+            .iter()
+            .filter(|(_, subscription)| subscription.server_id.is_none())
+            .map(|(sub_id, subscription)| {
+                (
+                    *sub_id,
+                    subscription.pubkey,
+                    subscription.config.clone(),
+                    subscription.request_id,
+                )
+            })
+            .collect()
+    }
+
+    fn local_id(&self, server_id: ServerSubId) -> Option<SubId> {
+        self.server_ids.get(&server_id).copied()
+    }
+}
+
+/// A signature followed for one or more watchers.
+#[derive(Default)]
+struct SignatureWatch {
+    watchers: Vec<UnboundedSender<SignatureEvent>>,
+    /// Ids assigned by the server to the processed and confirmed subscriptions, if subscribed
+    /// on the current connection.
+    processed: Option<ServerSubId>,
+    confirmed: Option<ServerSubId>,
+}
+
+/// Server subscriptions no watcher needs anymore.
+#[derive(Default)]
+struct ReleasedSubscriptions {
+    signatures: Vec<ServerSubId>,
+    slot: Option<ServerSubId>,
+}
+
+/// Signatures followed for submitted transactions.
+#[derive(Default)]
+struct SignatureRegistry {
+    signatures: HashMap<Signature, SignatureWatch>,
+    server_ids: HashMap<ServerSubId, (Signature, Commitment)>,
+    /// Id of the slot subscription shared by every watcher, if open on the current connection.
+    slot: Option<ServerSubId>,
+}
+
+impl SignatureRegistry {
+    /// Adds a watcher. Returns true if the signature is new, which then still needs to be
+    /// subscribed on the server.
+    fn watch(&mut self, request: WatchRequest) -> bool {
+        let is_new = !self.signatures.contains_key(&request.signature);
+        let watch = self.signatures.entry(request.signature).or_default();
+        if watch.confirmed.is_some() {
+            let _ = request.events.unbounded_send(SignatureEvent::Subscribed);
+        }
+        watch.watchers.push(request.events);
+        is_new
+    }
+
+    fn bind(&mut self, signature: Signature, commitment: Commitment, server_id: ServerSubId) {
+        if let Some(watch) = self.signatures.get_mut(&signature) {
+            match commitment {
+                Commitment::Processed => watch.processed = Some(server_id),
+                _ => watch.confirmed = Some(server_id),
+            }
+            self.server_ids.insert(server_id, (signature, commitment));
+        }
+    }
+
+    /// Signatures not yet subscribed on the current connection.
+    fn unbound(&self) -> Vec<Signature> {
+        self.signatures
+            .iter()
+            .filter(|(_, watch)| watch.confirmed.is_none())
+            .map(|(signature, _)| *signature)
+            .collect()
+    }
+
+    /// Forgets the server ids of the previous connection and tells every watcher.
+    fn disconnect(&mut self) {
+        self.server_ids.clear();
+        self.slot = None;
+        for watch in self.signatures.values_mut() {
+            watch.processed = None;
+            watch.confirmed = None;
+        }
+        let signatures = self.signatures.keys().copied().collect::<Vec<_>>();
+        for signature in signatures {
+            self.send(&signature, SignatureEvent::Disconnected);
+        }
+    }
+
+    /// Sends an event to the watchers of a signature, dropping those that are gone.
+    fn send(&mut self, signature: &Signature, event: SignatureEvent) {
+        if let Some(watch) = self.signatures.get_mut(signature) {
+            watch
+                .watchers
+                .retain(|watcher| watcher.unbounded_send(event.clone()).is_ok());
+        }
+    }
+
+    /// Routes a notification to the watchers it concerns, returning the subscriptions no
+    /// watcher needs anymore.
+    fn notify(&mut self, notification: ConfirmationNotification) -> ReleasedSubscriptions {
+        match notification {
+            ConfirmationNotification::Slot(slot) => {
+                let signatures = self.signatures.keys().copied().collect::<Vec<_>>();
+                for signature in signatures {
+                    self.send(&signature, SignatureEvent::Slot(slot));
+                }
+            }
+            // The server closes signature subscriptions once they notify
+            ConfirmationNotification::Signature { subscription, err } => {
+                let Some((signature, commitment)) = self.server_ids.remove(&subscription) else {
+                    return ReleasedSubscriptions::default();
+                };
+                if commitment == Commitment::Processed {
+                    if let Some(watch) = self.signatures.get_mut(&signature) {
+                        watch.processed = None;
+                    }
+                    self.send(&signature, SignatureEvent::Processed);
+                } else {
+                    if let Some(watch) = self.signatures.get_mut(&signature) {
+                        watch.confirmed = None;
+                    }
+                    self.send(&signature, SignatureEvent::Confirmed(err));
+                    if let Some(watch) = self.signatures.get_mut(&signature) {
+                        watch.watchers.clear();
+                    }
+                }
+            }
+        }
+        self.prune()
+    }
+
+    /// Drops signatures left without watchers, returning their subscriptions, and the slot
+    /// subscription once no signatures are left.
+    fn prune(&mut self) -> ReleasedSubscriptions {
+        let mut released = ReleasedSubscriptions::default();
+        self.signatures.retain(|_, watch| {
+            watch.watchers.retain(|watcher| !watcher.is_closed());
+            if !watch.watchers.is_empty() {
+                return true;
+            }
+            released
+                .signatures
+                .extend(watch.processed.iter().chain(watch.confirmed.iter()));
+            false
+        });
+        for server_id in &released.signatures {
+            self.server_ids.remove(server_id);
+        }
+        if self.signatures.is_empty() {
+            released.slot = self.slot.take();
+        }
+        released
+    }
 }
@@ -1,21 +1,58 @@
+use std::{rc::Rc, sync::Mutex};
+
 use dioxus::prelude::*;
 use futures::StreamExt;
+use once_cell::sync::Lazy;
 use solana_sdk::pubkey::Pubkey;
 
-use crate::gateway::{AccountNotificationParams, GatewayResult};
+use crate::{
+    gateway::{
+        commitment::Commitment, AccountNotificationParams, AccountSubscribeConfig, GatewayError,
+        GatewayResult,
+    },
+    hooks::{use_commitment, use_gateway, use_unconfirmed_accounts},
+    time::sleep,
+    utils::{get_value, set_key_value},
+};
+
+use super::{use_wss, use_wss_connection_state, FromWssMsg, ToWssMsg, WssConnectionState};
+
+const POLL_INTERVAL_KEY: &str = "wss_poll_interval";
 
-use super::{use_wss, FromWssMsg, ToWssMsg};
+/// Default interval between account reads while the WebSocket is down, in seconds.
+pub const DEFAULT_POLL_INTERVAL_SECS: u64 = 10;
+
+static POLL_INTERVAL_SECS: Lazy<Mutex<u64>> = Lazy::new(|| {
+    Mutex::new(get_value::<u64>(POLL_INTERVAL_KEY).unwrap_or(DEFAULT_POLL_INTERVAL_SECS))
+});
+
+/// Returns the interval between account reads while the WebSocket is down, in seconds.
+pub fn poll_interval_secs() -> u64 {
+    *POLL_INTERVAL_SECS.lock().unwrap()
+}
+
+/// Sets the interval between account reads while the WebSocket is down and persists it.
+pub fn set_poll_interval_secs(secs: u64) {
+    let secs = secs.max(1);
+    if let Err(err) = set_key_value(POLL_INTERVAL_KEY, &secs) {
+        log::error!("Failed to persist poll interval: {:?}", err);
+    }
+    *POLL_INTERVAL_SECS.lock().unwrap() = secs;
+}
 
 /// End to end management of websocket subscriptions.
 ///
 /// We have exactly one wss connection and all notifications come thru the same channel.
 /// This means we need to differentiate between subscribers, and route the correct notifs to
-/// the respective subscribing components.
+/// the respective subscribing components. Components subscribing to the same account share
+/// a subscription id, so one notification reaches all of them.
 ///
 /// This hook manages
 /// 1) Creating new subscriptions
 /// 2) Routing notifications
-/// 3) Closing subscriptions when the parent component unmounts
+/// 3) Polling the account while the socket is down
+/// 4) Resubscribing when the app-wide commitment changes
+/// 5) Closing subscriptions when the parent component unmounts
 pub fn use_wss_subscription<T, U>(
     mut data: Signal<GatewayResult<T>>,
     update_callback: U,
@@ -25,22 +62,48 @@ where
     U: Fn(&AccountNotificationParams) -> GatewayResult<T> + 'static,
 {
     let (from_wss, to_wss) = use_wss();
+    let connection_state = use_wss_connection_state();
+    let commitment = use_commitment();
+    let mut unconfirmed = use_unconfirmed_accounts();
+    let update_callback = Rc::new(update_callback);
     let mut sub_id = use_signal(|| 0);
+    // Request of the current subscription, which releases it even before it is answered
     let mut sub_request_id = use_signal(|| 0);
+    let mut subscribed_pubkey = use_signal(|| None);
+    let mut subscribed_commitment = use_signal(Commitment::default);
 
     // Subscribe when component mounts
     let pubkey_tx = use_coroutine(move |mut rx: UnboundedReceiver<Pubkey>| async move {
         while let Some(pubkey) = rx.next().await {
-            let rid = fastrand::u64(..);
-            // Set sub request id
-            sub_request_id.set(rid);
+            let commitment = *commitment.peek();
+            subscribed_pubkey.set(Some(pubkey));
+            subscribed_commitment.set(commitment);
+            // Ids start at 1, as 0 means no request was sent
+            let rid = fastrand::u64(1..);
             // Unsubscribe from previous wallet first
-            let current_sub_id = *sub_id.read();
-            if current_sub_id > 0 {
-                to_wss.send(ToWssMsg::Unsubscribe(current_sub_id));
+            let current_request_id = *sub_request_id.peek();
+            if current_request_id > 0 {
+                to_wss.send(ToWssMsg::Unsubscribe(current_request_id));
+                sub_id.set(0);
             }
+            // Set sub request id
+            sub_request_id.set(rid);
             // Then subscribe to new wallet
-            to_wss.send(ToWssMsg::Subscribe(rid, pubkey));
+            to_wss.send(ToWssMsg::Subscribe(
+                rid,
+                pubkey,
+                AccountSubscribeConfig::new(commitment),
+            ));
+        }
+    });
+
+    // Resubscribe when the commitment setting changes
+    use_effect(move || {
+        let commitment = commitment();
+        if let Some(pubkey) = *subscribed_pubkey.peek() {
+            if *subscribed_commitment.peek() != commitment {
+                pubkey_tx.send(pubkey);
+            }
         }
     });
 
@@ -49,8 +112,8 @@ where
         let msg = from_wss.cloned();
         let sub_request_id = sub_request_id.cloned();
         // Track subscription ID
+        // Superseded requests were already released when the next one was sent
         if let FromWssMsg::Subscription(rid, sid) = msg {
-            // Only handle subscriptions originating from this component
             if sub_request_id.eq(&rid) {
                 sub_id.set(sid);
             }
@@ -58,21 +121,58 @@ where
     });
 
     // Handle data updates
+    let notif_callback = update_callback.clone();
     use_effect(move || {
         let msg = from_wss.cloned();
         // Only process notification messages
         if let FromWssMsg::Notif(notif) = msg {
             if notif.subscription.eq(&sub_id()) {
-                data.set(update_callback(&notif));
+                data.set(notif_callback(&notif));
+                if let Some(pubkey) = *subscribed_pubkey.peek() {
+                    unconfirmed.mark(pubkey, *subscribed_commitment.peek());
+                }
+            }
+        }
+    });
+
+    // Poll the account while the socket is down, until it recovers
+    use_future(move || {
+        let update_callback = update_callback.clone();
+        async move {
+            loop {
+                sleep(poll_interval_secs() * 1000).await;
+                if *connection_state.peek() == WssConnectionState::Connected {
+                    continue;
+                }
+                let Some(pubkey) = *subscribed_pubkey.peek() else {
+                    continue;
+                };
+                let commitment = *commitment.peek();
+                match use_gateway()
+                    .get_account_with_commitment(&pubkey, commitment)
+                    .await
+                {
+                    Ok(account) => {
+                        let notif =
+                            AccountNotificationParams::from_account(&account, *sub_id.peek());
+                        data.set(update_callback(&notif));
+                        unconfirmed.mark(pubkey, commitment);
+                    }
+                    // Accounts that don't exist yet keep their initial value
+                    Err(GatewayError::AccountNotFound) => {}
+                    Err(err) => log::error!("Failed to poll account {}: {:?}", pubkey, err),
+                }
             }
         }
     });
 
     // Unsubscribe when component is dropped
     use_drop(move || {
-        let current_sub_id = *sub_id.read();
-        if current_sub_id > 0 {
-            to_wss.send(ToWssMsg::Unsubscribe(current_sub_id));
+        let current_request_id = *sub_request_id.peek();
+        if current_request_id > 0 {
+            to_wss.send(ToWssMsg::Unsubscribe(current_request_id));
+            sub_request_id.set(0);
+            sub_id.set(0);
         }
     });
 
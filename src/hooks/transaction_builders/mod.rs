@@ -7,6 +7,8 @@ mod use_pair_deposit_transaction;
 mod use_pair_withdraw_transaction;
 mod use_pool_commit_claim_transaction;
 mod use_pool_register_transaction;
+#[cfg(not(feature = "web"))]
+mod use_solo_mine_transaction;
 mod use_swap_transaction;
 mod use_token_deposit_transaction;
 mod use_token_withdraw_transaction;
@@ -23,6 +25,8 @@ pub use use_pair_deposit_transaction::*;
 pub use use_pair_withdraw_transaction::*;
 pub use use_pool_commit_claim_transaction::*;
 pub use use_pool_register_transaction::*;
+#[cfg(not(feature = "web"))]
+pub use use_solo_mine_transaction::*;
 pub use use_swap_transaction::*;
 pub use use_token_deposit_transaction::*;
 pub use use_token_withdraw_transaction::*;
@@ -33,33 +37,84 @@ pub use use_transfer_transaction::*;
 pub const APP_FEE: u64 = 5_000;
 pub const APP_FEE_ACCOUNT: &str = "tHCCE3KWKx8i8cDjX2DQ3Z7EMJkScAVwkfxdWz8SqgP";
 pub const SOLANA_BASE_FEE: u64 = 5_000;
+/// Placeholder limit set by builders. It is resized from a simulation before signing.
 pub const COMPUTE_UNIT_LIMIT: u32 = 500_000;
 pub const MIN_SOL_BALANCE: f64 = 0.1;
 pub const JITO_TIP_AMOUNT: u64 = 2_000;
 
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0::Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+
 #[cfg(not(feature = "web"))]
-use solana_sdk::instruction::Instruction;
-#[cfg(not(feature = "web"))]
-use solana_sdk::pubkey::Pubkey;
+use crate::gateway::jito::{jito_config, JITO_TIP_ADDRESSES};
+use crate::gateway::{
+    compute_budget::{set_compute_unit_price, writable_accounts},
+    priority_fee::{priority_fee_tier, PriorityFeeTier},
+    GatewayResult,
+};
+
+use super::use_gateway;
 
+/// Prices the compute units of a transaction according to the selected priority fee tier.
+pub async fn add_priority_fee(ixs: &mut Vec<Instruction>) {
+    let tier = priority_fee_tier();
+    if tier == PriorityFeeTier::None {
+        return;
+    }
+    let accounts = writable_accounts(ixs);
+    match use_gateway().get_priority_fee(tier, &accounts).await {
+        Ok(fee) if fee > 0 => set_compute_unit_price(ixs, fee),
+        Ok(_) => {}
+        Err(err) => log::error!("Failed to estimate priority fee: {:?}", err),
+    }
+}
+
+/// Compiles instructions into a v0 transaction, compressing any accounts found in the given lookup tables.
+pub async fn build_versioned_transaction(
+    payer: &Pubkey,
+    ixs: &[Instruction],
+    luts: &[Pubkey],
+) -> GatewayResult<VersionedTransaction> {
+    let luts = if luts.is_empty() {
+        vec![]
+    } else {
+        use_gateway()
+            .get_lookup_tables(luts)
+            .await
+            .unwrap_or_else(|err| {
+                log::error!("Failed to fetch lookup tables: {:?}", err);
+                vec![]
+            })
+    };
+    let message = Message::try_compile(payer, ixs, &luts, Hash::default())?;
+    Ok(VersionedTransaction {
+        signatures: vec![Signature::default(); message.header.num_required_signatures as usize],
+        message: VersionedMessage::V0(message),
+    })
+}
+
+/// A jito tip, if transactions are submitted as bundles. A tip sent through the rpc buys
+/// nothing, so none is added otherwise.
 #[cfg(not(feature = "web"))]
-pub fn tip_ix(signer: &Pubkey) -> Instruction {
+pub fn tip_ix(signer: &Pubkey) -> Option<Instruction> {
+    if !jito_config().enabled {
+        return None;
+    }
     let address = get_jito_tip_address();
-    solana_sdk::system_instruction::transfer(signer, &address, JITO_TIP_AMOUNT)
+    Some(solana_sdk::system_instruction::transfer(
+        signer,
+        &address,
+        JITO_TIP_AMOUNT,
+    ))
 }
 #[cfg(not(feature = "web"))]
 fn get_jito_tip_address() -> Pubkey {
-    let addresses = [
-        solana_sdk::pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5"),
-        solana_sdk::pubkey!("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe"),
-        solana_sdk::pubkey!("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY"),
-        solana_sdk::pubkey!("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49"),
-        solana_sdk::pubkey!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh"),
-        solana_sdk::pubkey!("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt"),
-        solana_sdk::pubkey!("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL"),
-        solana_sdk::pubkey!("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT"),
-    ];
-
-    let random_index = rand::random::<usize>() % addresses.len();
-    addresses[random_index]
+    let random_index = rand::random::<usize>() % JITO_TIP_ADDRESSES.len();
+    JITO_TIP_ADDRESSES[random_index]
 }
@@ -1,12 +1,11 @@
 use dioxus::prelude::*;
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    pubkey::Pubkey,
-    system_instruction::transfer,
-    transaction::{Transaction, VersionedTransaction},
+    compute_budget::ComputeBudgetInstruction, pubkey::Pubkey, system_instruction::transfer,
+    transaction::VersionedTransaction,
 };
 
 use crate::{
+    config::LISTED_BOOSTS_BY_MINT,
     gateway::{GatewayError, GatewayResult},
     hooks::{
         calculate_claimable_yield, use_all_boosts, use_all_stakes, use_boost_config_wss,
@@ -23,6 +22,7 @@ use crate::{
 
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
+use super::{add_priority_fee, build_versioned_transaction};
 
 pub fn use_boost_claim_all_transaction() -> Resource<GatewayResult<VersionedTransaction>> {
     let wallet = use_wallet();
@@ -46,6 +46,7 @@ pub fn use_boost_claim_all_transaction() -> Resource<GatewayResult<VersionedTran
 
             // Create instruction list
             let mut ixs = vec![];
+            let mut luts = vec![];
 
             // Create associated token account if necessary
             if let Some(Ok(_balance)) = ore_balance.cloned() {
@@ -79,6 +80,12 @@ pub fn use_boost_claim_all_transaction() -> Resource<GatewayResult<VersionedTran
                                         boost.mint,
                                         claimable_yield,
                                     ));
+                                    if let Some(lut) = LISTED_BOOSTS_BY_MINT
+                                        .get(&boost.mint)
+                                        .and_then(|boost_meta| boost_meta.lut)
+                                    {
+                                        luts.push(lut);
+                                    }
                                 }
                             }
                         }
@@ -99,12 +106,15 @@ pub fn use_boost_claim_all_transaction() -> Resource<GatewayResult<VersionedTran
             let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
             ixs.push(transfer(&authority, &app_fee_account, 5000));
 
+            // Set compute unit price
+            add_priority_fee(&mut ixs).await;
+
             #[cfg(not(feature = "web"))]
-            // Add jito tip
-            ixs.push(tip_ix(&authority));
+            // Add jito tip when submitting bundles
+            ixs.extend(tip_ix(&authority));
 
             // Build tx
-            let tx = Transaction::new_with_payer(&ixs, Some(&authority)).into();
+            let tx = build_versioned_transaction(&authority, &ixs, &luts).await?;
             Ok(tx)
         }
     })
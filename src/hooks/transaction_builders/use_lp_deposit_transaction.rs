@@ -1,19 +1,19 @@
 use dioxus::prelude::*;
 use ore_boost_api::state::{Boost, Stake};
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    pubkey::Pubkey,
-    system_instruction::transfer,
-    transaction::{Transaction, VersionedTransaction},
+    compute_budget::ComputeBudgetInstruction, pubkey::Pubkey, system_instruction::transfer,
+    transaction::VersionedTransaction,
 };
 
 use crate::{
+    config::LISTED_BOOSTS_BY_MINT,
     gateway::{GatewayError, GatewayResult},
     hooks::{use_wallet, Wallet, APP_FEE_ACCOUNT, COMPUTE_UNIT_LIMIT},
 };
 
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
+use super::{add_priority_fee, build_versioned_transaction};
 
 pub fn use_lp_deposit_transaction(
     boost: Signal<GatewayResult<Boost>>,
@@ -53,12 +53,20 @@ pub fn use_lp_deposit_transaction(
         // Deposit LP tokens
         ixs.push(ore_boost_api::sdk::deposit(authority, boost.mint, u64::MAX));
 
+        // Set compute unit price
+        add_priority_fee(&mut ixs).await;
+
         #[cfg(not(feature = "web"))]
-        // Add jito tip
-        ixs.push(tip_ix(&authority));
+        // Add jito tip when submitting bundles
+        ixs.extend(tip_ix(&authority));
 
         // Build tx
-        let tx = Transaction::new_with_payer(&ixs, Some(&authority)).into();
+        let luts = LISTED_BOOSTS_BY_MINT
+            .get(&boost.mint)
+            .and_then(|boost_meta| boost_meta.lut)
+            .into_iter()
+            .collect::<Vec<_>>();
+        let tx = build_versioned_transaction(&authority, &ixs, &luts).await?;
 
         Ok(tx)
     })
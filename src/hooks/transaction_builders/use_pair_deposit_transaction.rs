@@ -1,5 +1,6 @@
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
+use super::{add_priority_fee, build_versioned_transaction};
 use crate::{
     components::TokenInputError,
     config::{BoostMeta, LpType, Token},
@@ -26,15 +27,8 @@ use crate::{
 use dioxus::prelude::*;
 use ore_boost_api::state::Stake;
 use solana_sdk::{
-    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
-    compute_budget::ComputeBudgetInstruction,
-    hash::Hash,
-    message::{v0::Message, VersionedMessage},
-    native_token::sol_to_lamports,
-    pubkey::Pubkey,
-    signature::Signature,
-    system_instruction::transfer,
-    transaction::VersionedTransaction,
+    compute_budget::ComputeBudgetInstruction, native_token::sol_to_lamports, pubkey::Pubkey,
+    system_instruction::transfer, transaction::VersionedTransaction,
 };
 
 // Build pair deposit transaction
@@ -240,31 +234,16 @@ pub fn use_pair_deposit_transaction(
         let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
         ixs.push(transfer(&authority, &app_fee_account, 5000));
 
-        // Fetch lookup tables
-        let mut luts = vec![];
-        if let Some(lut) = boost_meta.lut {
-            if let Ok(account_data) = use_gateway().rpc.get_account_data(&lut).await {
-                if let Ok(address_lookup_table) = AddressLookupTable::deserialize(&account_data) {
-                    let address_lookup_table_account = AddressLookupTableAccount {
-                        key: lut,
-                        addresses: address_lookup_table.addresses.to_vec(),
-                    };
-                    luts.push(address_lookup_table_account);
-                }
-            }
-        }
+        // Set compute unit price
+        add_priority_fee(&mut ixs).await;
 
         #[cfg(not(feature = "web"))]
-        // Add jito tip
-        ixs.push(tip_ix(&authority));
+        // Add jito tip when submitting bundles
+        ixs.extend(tip_ix(&authority));
 
         // Build tx
-        let tx = VersionedTransaction {
-            signatures: vec![Signature::default()],
-            message: VersionedMessage::V0(
-                Message::try_compile(&authority, &ixs, &luts, Hash::default()).unwrap(),
-            ),
-        };
+        let luts = boost_meta.lut.into_iter().collect::<Vec<_>>();
+        let tx = build_versioned_transaction(&authority, &ixs, &luts).await?;
 
         Ok(tx)
     })
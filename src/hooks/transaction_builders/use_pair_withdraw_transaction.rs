@@ -1,13 +1,7 @@
 use dioxus::prelude::*;
 use ore_boost_api::state::Stake;
 use solana_sdk::{
-    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
-    compute_budget::ComputeBudgetInstruction,
-    hash::Hash,
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    signature::Signature,
-    system_instruction::transfer,
+    compute_budget::ComputeBudgetInstruction, pubkey::Pubkey, system_instruction::transfer,
     transaction::VersionedTransaction,
 };
 
@@ -34,6 +28,7 @@ use crate::{
 
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
+use super::{add_priority_fee, build_versioned_transaction};
 
 // Build pair deposit transaction
 pub fn use_pair_withdraw_transaction(
@@ -211,35 +206,20 @@ pub fn use_pair_withdraw_transaction(
             );
         }
 
-        // Fetch lookup tables
-        let mut luts = vec![];
-        if let Some(lut) = boost_meta.lut {
-            if let Ok(account_data) = use_gateway().rpc.get_account_data(&lut).await {
-                if let Ok(address_lookup_table) = AddressLookupTable::deserialize(&account_data) {
-                    let address_lookup_table_account = AddressLookupTableAccount {
-                        key: lut,
-                        addresses: address_lookup_table.addresses.to_vec(),
-                    };
-                    luts.push(address_lookup_table_account);
-                }
-            }
-        }
-
         // Include ORE app fee
         let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
         ixs.push(transfer(&authority, &app_fee_account, 5000));
 
+        // Set compute unit price
+        add_priority_fee(&mut ixs).await;
+
         #[cfg(not(feature = "web"))]
-        // Add jito tip
-        ixs.push(tip_ix(&authority));
+        // Add jito tip when submitting bundles
+        ixs.extend(tip_ix(&authority));
 
         // Build tx
-        let tx = VersionedTransaction {
-            signatures: vec![Signature::default()],
-            message: VersionedMessage::V0(
-                Message::try_compile(&authority, &ixs, &luts, Hash::default()).unwrap(),
-            ),
-        };
+        let luts = boost_meta.lut.into_iter().collect::<Vec<_>>();
+        let tx = build_versioned_transaction(&authority, &ixs, &luts).await?;
 
         Ok(tx)
     })
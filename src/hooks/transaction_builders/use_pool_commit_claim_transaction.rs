@@ -22,8 +22,8 @@ pub async fn build_commit_claim_instructions<R: Rpc>(
     let mut core_instructions =
         build_core_commit_claim_instructions(gateway, pool, member, member_record_balance).await?;
     instructions.append(&mut core_instructions);
-    // Add jito tip
-    instructions.push(tip_ix(&member.authority));
+    // Add jito tip when submitting bundles
+    instructions.extend(tip_ix(&member.authority));
     Ok(instructions)
 }
 
@@ -15,6 +15,7 @@ use solana_sdk::{
     transaction::{Transaction, VersionedTransaction},
 };
 
+use super::add_priority_fee;
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
 
@@ -45,7 +46,7 @@ pub fn use_pool_register_transaction() -> Resource<GatewayResult<VersionedTransa
                 );
                 // check that beneficiary token account exists o.w. create
                 let gateway = use_gateway();
-                let claim_ata_data = gateway.rpc.get_account_data(&claim_ata).await;
+                let claim_ata_data = gateway.get_account_data(&claim_ata).await;
                 if let Err(_err) = claim_ata_data {
                     let create_ata =
                         spl_associated_token_account::instruction::create_associated_token_account(
@@ -75,9 +76,12 @@ pub fn use_pool_register_transaction() -> Resource<GatewayResult<VersionedTransa
             let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
             ixs.push(transfer(&pubkey, &app_fee_account, APP_FEE));
 
+            // Set compute unit price
+            add_priority_fee(&mut ixs).await;
+
             #[cfg(not(feature = "web"))]
-            // Add jito tip
-            ixs.push(tip_ix(&pubkey));
+            // Add jito tip when submitting bundles
+            ixs.extend(tip_ix(&pubkey));
 
             // Simulate transaction
             // gateway.rpc.simulate_transaction
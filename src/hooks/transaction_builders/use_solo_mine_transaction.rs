@@ -0,0 +1,65 @@
+use drillx::Solution;
+use ore_api_v2::{consts::EPOCH_DURATION, state::proof_pda};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey,
+};
+
+use crate::{
+    gateway::{solana::SolanaGateway, solo::SoloGateway, GatewayResult},
+    hooks::{use_gateway, COMPUTE_UNIT_LIMIT},
+};
+
+use super::{add_priority_fee, tip_ix};
+
+/// Seconds before the epoch ends from which a reset may be included.
+const RESET_BUFFER_SECONDS: i64 = 5;
+
+/// One in this many miners include a reset, so they don't all pay for it.
+const RESET_ODDS: u32 = 100;
+
+/// Builds the instructions to open a proof account for mining without a pool.
+pub async fn build_solo_open_instructions(authority: Pubkey) -> Vec<Instruction> {
+    let mut ixs = vec![ore_api_v2::sdk::open(authority, authority, authority)];
+    add_priority_fee(&mut ixs).await;
+    ixs.extend(tip_ix(&authority));
+    ixs
+}
+
+/// Builds the instructions to submit a solution to the ORE program, resetting the epoch if due.
+pub async fn build_solo_mine_instructions(
+    authority: Pubkey,
+    solution: Solution,
+) -> GatewayResult<Vec<Instruction>> {
+    let gateway = use_gateway();
+    let config = gateway.get_mining_config().await?;
+    let clock = gateway.rpc.get_clock().await?;
+    let bus = gateway.find_bus().await?;
+
+    let mut ixs = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(COMPUTE_UNIT_LIMIT),
+        ore_api_v2::sdk::auth(proof_pda(authority).0),
+    ];
+
+    // Reset the epoch once it has ended
+    let reset_at = config
+        .last_reset_at
+        .saturating_add(EPOCH_DURATION)
+        .saturating_sub(RESET_BUFFER_SECONDS);
+    if clock.unix_timestamp >= reset_at && fastrand::u32(..RESET_ODDS) == 0 {
+        ixs.push(ore_api_v2::sdk::reset(authority));
+    }
+
+    // Mine with the bus that has the most rewards left
+    let boost_config = ore_boost_api::state::config_pda().0;
+    ixs.push(ore_api_v2::sdk::mine(
+        authority,
+        authority,
+        bus,
+        solution,
+        boost_config,
+    ));
+
+    add_priority_fee(&mut ixs).await;
+    ixs.extend(tip_ix(&authority));
+    Ok(ixs)
+}
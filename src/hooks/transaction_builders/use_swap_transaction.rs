@@ -4,7 +4,7 @@ use jupiter_swap_api_client::{
     quote::{QuoteRequest, QuoteResponse, SwapMode},
     route_plan_with_metadata::RoutePlanWithMetadata,
     swap::SwapRequest,
-    transaction_config::TransactionConfig,
+    transaction_config::{ComputeUnitPriceMicroLamports, TransactionConfig},
     JupiterSwapApiClient,
 };
 use rust_decimal::Decimal;
@@ -13,12 +13,12 @@ use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
 use crate::config::Token;
 use crate::{
     components::TokenInputError,
-    gateway::{GatewayError, GatewayResult, UiTokenAmount},
+    gateway::{priority_fee::priority_fee_tier, GatewayError, GatewayResult, UiTokenAmount},
 };
 
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
-use crate::hooks::{use_wallet, GetPubkey};
+use crate::hooks::{use_gateway, use_wallet, GetPubkey};
 
 const API_URL: &str = "https://quote-api.jup.ag/v6";
 
@@ -26,7 +26,6 @@ pub fn use_swap_transaction(
     quote: Signal<Option<QuoteResponse>>,
     sell_token: Signal<Option<Token>>,
     sell_token_balance: Signal<GatewayResult<UiTokenAmount>>,
-    _priority_fee: Signal<u64>,
     mut err: Signal<Option<TokenInputError>>,
 ) -> Resource<GatewayResult<VersionedTransaction>> {
     let wallet = use_wallet();
@@ -57,13 +56,36 @@ pub fn use_swap_transaction(
                 return Err(GatewayError::Unknown);
             }
 
+            // Estimate priority fee from the amms along the route
+            let accounts = quote
+                .route_plan
+                .iter()
+                .map(|step| step.swap_info.amm_key)
+                .collect::<Vec<_>>();
+            let compute_unit_price = use_gateway()
+                .get_priority_fee(priority_fee_tier(), &accounts)
+                .await
+                .unwrap_or(0);
+
             // Build transaction from jupiter quote
             let request = SwapRequest {
                 user_public_key: pubkey,
                 quote_response: quote.clone(),
-                config: TransactionConfig::default(),
+                config: TransactionConfig {
+                    compute_unit_price_micro_lamports: (compute_unit_price > 0).then_some(
+                        ComputeUnitPriceMicroLamports::MicroLamports(compute_unit_price),
+                    ),
+                    ..TransactionConfig::default()
+                },
+            };
+            let response = match client.swap(&request, None).await {
+                Ok(response) => response,
+                Err(swap_err) => {
+                    let swap_err = GatewayError::from(swap_err);
+                    err.set(Some(TokenInputError::Gateway(swap_err.clone())));
+                    return Err(swap_err);
+                }
             };
-            let response = client.swap(&request, None).await?;
             let vtx: VersionedTransaction =
                 bincode::deserialize(response.swap_transaction.as_slice()).map_err(|err| {
                     log::error!("{:?}", err);
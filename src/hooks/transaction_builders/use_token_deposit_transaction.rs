@@ -15,6 +15,7 @@ use crate::{
     solana::spl_token::ui_amount_to_amount,
 };
 
+use super::add_priority_fee;
 #[cfg(not(feature = "web"))]
 use super::tip_ix;
 
@@ -91,9 +92,12 @@ pub fn use_token_deposit_transaction(
         let app_fee_account = Pubkey::from_str_const(APP_FEE_ACCOUNT);
         ixs.push(transfer(&authority, &app_fee_account, APP_FEE));
 
+        // Set compute unit price
+        add_priority_fee(&mut ixs).await;
+
         #[cfg(not(feature = "web"))]
-        // Add jito tip
-        ixs.push(tip_ix(&authority));
+        // Add jito tip when submitting bundles
+        ixs.extend(tip_ix(&authority));
 
         // Build tx
         let tx = Transaction::new_with_payer(&ixs, Some(&authority)).into();
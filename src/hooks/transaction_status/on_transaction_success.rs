@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 
 use solana_sdk::signature::Signature;
 
-use crate::components::TransactionStatus;
+use crate::{components::TransactionStatus, gateway::cache::invalidate_cache};
 
 use super::use_transaction_status;
 
@@ -13,6 +13,8 @@ pub fn on_transaction_done(mut callback: impl FnMut(Signature) + 'static) {
     // Callback if transaction success
     use_effect(move || {
         if let Some(TransactionStatus::Done(signature)) = *transaction_status.read() {
+            // Balances and metrics may have changed
+            invalidate_cache();
             callback(signature);
         }
     });
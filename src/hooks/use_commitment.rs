@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    gateway::commitment::{commitment, Commitment},
+    time::{sleep, Duration, Instant},
+};
+
+/// Time for a block to be rooted by the cluster, about 32 slots.
+const FINALIZATION_TIME: Duration = Duration::from_secs(13);
+
+/// Interval between sweeps of accounts whose updates have since finalized.
+const SWEEP_INTERVAL_MS: u64 = 1_000;
+
+/// Accounts recently updated from data below finalized commitment, with when each update arrived.
+#[derive(Clone, Copy)]
+pub struct UnconfirmedAccounts(Signal<HashMap<Pubkey, Instant>>);
+
+impl UnconfirmedAccounts {
+    /// Records an update to an account read at the given commitment.
+    pub fn mark(&mut self, address: Pubkey, commitment: Commitment) {
+        if !commitment.is_finalized() {
+            self.0.write().insert(address, Instant::now());
+        }
+    }
+}
+
+pub fn use_commitment_provider() {
+    use_context_provider(|| Signal::new(commitment()));
+    let mut unconfirmed = use_context_provider(|| UnconfirmedAccounts(Signal::new(HashMap::new())));
+
+    // Clear accounts once their last update has had time to finalize
+    use_future(move || async move {
+        loop {
+            sleep(SWEEP_INTERVAL_MS).await;
+            let has_finalized = unconfirmed
+                .0
+                .peek()
+                .values()
+                .any(|updated_at| updated_at.elapsed() >= FINALIZATION_TIME);
+            if has_finalized {
+                unconfirmed
+                    .0
+                    .write()
+                    .retain(|_, updated_at| updated_at.elapsed() < FINALIZATION_TIME);
+            }
+        }
+    });
+}
+
+/// App-wide default commitment for reads and subscriptions.
+pub fn use_commitment() -> Signal<Commitment> {
+    use_context()
+}
+
+pub fn use_unconfirmed_accounts() -> UnconfirmedAccounts {
+    use_context()
+}
+
+/// Whether the account was recently updated from data that is not yet finalized.
+pub fn use_unconfirmed(address: Memo<Option<Pubkey>>) -> Memo<bool> {
+    let unconfirmed = use_unconfirmed_accounts();
+    use_memo(move || {
+        address
+            .read()
+            .as_ref()
+            .is_some_and(|address| unconfirmed.0.read().contains_key(address))
+    })
+}
@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::gateway::endpoints::{self, Endpoint, EndpointHealth};
+use crate::gateway::GatewayResult;
+use crate::time::sleep;
+
+const HEALTH_CHECK_INTERVAL_MS: u64 = 30 * 1000;
+
+#[derive(Clone, Copy)]
+struct EndpointState {
+    endpoints: Signal<Vec<Endpoint>>,
+    health: Signal<Vec<EndpointHealth>>,
+    active: Signal<Endpoint>,
+}
+
+pub fn use_endpoints_provider() {
+    let mut state = use_context_provider(|| EndpointState {
+        endpoints: Signal::new(endpoints::endpoints()),
+        health: Signal::new(endpoints::endpoint_health()),
+        active: Signal::new(endpoints::active_endpoint()),
+    });
+
+    // Periodically check endpoint health, failing over if the active endpoint lags behind
+    use_future(move || async move {
+        let http = reqwest::Client::new();
+        loop {
+            let health = endpoints::check_health(&http).await;
+            state.health.set(health);
+            let active = endpoints::active_endpoint();
+            if *state.active.peek() != active {
+                state.active.set(active);
+            }
+            sleep(HEALTH_CHECK_INTERVAL_MS).await;
+        }
+    });
+}
+
+/// The configured RPC endpoints, in order of preference.
+pub fn use_endpoints() -> Signal<Vec<Endpoint>> {
+    use_context::<EndpointState>().endpoints
+}
+
+/// The last observed health of each configured endpoint.
+pub fn use_endpoint_health() -> Signal<Vec<EndpointHealth>> {
+    use_context::<EndpointState>().health
+}
+
+/// The endpoint currently serving requests.
+pub fn use_active_endpoint() -> Signal<Endpoint> {
+    use_context::<EndpointState>().active
+}
+
+/// Replaces the configured endpoints and persists them.
+pub fn save_endpoints(new_endpoints: Vec<Endpoint>) -> GatewayResult<()> {
+    let mut state = consume_context::<EndpointState>();
+    endpoints::set_endpoints(new_endpoints)?;
+    state.endpoints.set(endpoints::endpoints());
+    state.health.set(endpoints::endpoint_health());
+    state.active.set(endpoints::active_endpoint());
+    Ok(())
+}
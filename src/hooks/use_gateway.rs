@@ -4,14 +4,14 @@ use std::rc::Rc;
 use crate::gateway::NativeRpc;
 #[cfg(feature = "web")]
 use crate::gateway::WebRpc;
-use crate::gateway::{Gateway, RPC_URL};
+use crate::gateway::{endpoints, Gateway};
 
 #[cfg(feature = "web")]
 pub fn use_gateway() -> Rc<Gateway<WebRpc>> {
-    Rc::new(Gateway::new(RPC_URL.to_string()))
+    Rc::new(Gateway::new(endpoints::rpc_url()))
 }
 
 #[cfg(not(feature = "web"))]
 pub fn use_gateway() -> Rc<Gateway<NativeRpc>> {
-    Rc::new(Gateway::new(RPC_URL.to_string()))
+    Rc::new(Gateway::new(endpoints::rpc_url()))
 }
@@ -22,8 +22,11 @@ use tracing::Level;
 use crate::utils::AppNapDisabler;
 use crate::{
     hooks::{
-        use_cache_provider, use_docs_drawer_state_provider, use_miner_cores_provider,
-        use_miner_events_provider, use_miner_provider, use_miner_status_provider, use_mining_loop,
+        use_cache_provider, use_commitment_provider, use_docs_drawer_state_provider,
+        use_endpoints_provider, use_miner_benchmark_provider, use_miner_control_provider,
+        use_miner_cores_provider, use_miner_events_provider, use_miner_provider,
+        use_miner_status_provider, use_mining_loop, use_mining_mode_provider,
+        use_pool_failover_provider, use_pool_provider, use_solution_queue_provider,
         use_transaction_status_provider, use_wallet_drawer_state_provider, use_wallet_provider,
         use_wss_provider,
     },
@@ -45,10 +48,18 @@ pub fn App() -> Element {
     use_download_url_provider();
     use_miner_provider();
     use_miner_status_provider();
+    use_miner_control_provider();
     use_miner_events_provider();
+    use_miner_benchmark_provider();
     use_miner_cores_provider();
+    use_mining_mode_provider();
     use_transaction_status_provider();
     use_wallet_provider();
+    use_endpoints_provider();
+    use_pool_provider();
+    use_pool_failover_provider();
+    use_solution_queue_provider();
+    use_commitment_provider();
     use_wss_provider();
     use_cache_provider();
     use_mining_loop();
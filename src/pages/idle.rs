@@ -3,7 +3,7 @@ use crate::{
     gateway::GatewayResult,
     hooks::{
         use_boost_apr, use_boost_claim_transaction, use_boost_wss, use_claimable_yield,
-        use_ore_balance_wss, use_ore_price, use_stake_wss,
+        use_ore_balance_wss, use_ore_price, use_stake_unconfirmed, use_stake_wss,
     },
     solana::spl_token::{amount_to_ui_amount, amount_to_ui_amount_string},
 };
@@ -79,10 +79,8 @@ fn Deposits(stake: Signal<GatewayResult<Stake>>) -> Element {
             com: |stake| {
                 rsx! {
                     if stake.balance > 0 {
-                        OreValue {
-                            ui_amount_string: amount_to_ui_amount_string(stake.balance, TOKEN_DECIMALS),
-                            with_decimal_units: true,
-                            size: TokenValueSize::Small,
+                        DepositsValue {
+                            balance: stake.balance,
                         }
                     } else {
                         NullValue {}
@@ -93,6 +91,24 @@ fn Deposits(stake: Signal<GatewayResult<Stake>>) -> Element {
     }
 }
 
+#[component]
+fn DepositsValue(balance: u64) -> Element {
+    let unconfirmed = use_stake_unconfirmed(ore_api::consts::MINT_ADDRESS);
+    rsx! {
+        Row {
+            gap: 2,
+            if unconfirmed() {
+                UnconfirmedValue {}
+            }
+            OreValue {
+                ui_amount_string: amount_to_ui_amount_string(balance, TOKEN_DECIMALS),
+                with_decimal_units: true,
+                size: TokenValueSize::Small,
+            }
+        }
+    }
+}
+
 #[component]
 pub fn StakeYield(
     boost: Signal<GatewayResult<Boost>>,
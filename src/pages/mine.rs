@@ -8,19 +8,23 @@ use solana_sdk::transaction::{Transaction, TransactionError, VersionedTransactio
 
 use crate::{
     components::*,
-    gateway::{pool::PoolGateway, GatewayResult, Rpc},
+    gateway::{pool::PoolGateway, ConfirmationProgress, GatewayResult, Rpc},
     hooks::{
-        build_commit_claim_instructions, on_transaction_done, use_gateway, use_member,
-        use_member_record, use_member_record_balance, use_miner, use_miner_cores,
-        use_miner_is_active, use_miner_status, use_pool, use_pool_register_transaction,
-        use_pool_url, use_system_cpu_utilization, use_transaction_status, use_wallet, MinerStatus,
-        Wallet,
+        add_priority_fee, build_commit_claim_instructions, on_transaction_done, set_mining_mode,
+        use_gateway, use_member, use_member_record, use_member_record_balance, use_miner,
+        use_miner_benchmark, use_miner_control, use_miner_cores, use_miner_is_active,
+        use_miner_progress, use_miner_status, use_mining_mode, use_pool,
+        use_pool_register_transaction, use_pool_url, use_solution_queue,
+        use_system_cpu_utilization, use_transaction_status, use_wallet, MinerStatus, MiningMode,
+        SubmissionOutcome, Wallet,
     },
     solana::spl_token::amount_to_ui_amount_string,
+    utils::{format_abbreviated_number, format_time_since},
 };
 use ore_types::request::TransactionType;
 
 pub fn Mine() -> Element {
+    let mining_mode = use_mining_mode();
     rsx! {
         Col { class: "w-full h-full pb-20 sm:pb-16", gap: 16,
             Col { class: "w-full max-w-2xl mx-auto px-5 sm:px-8 gap-8",
@@ -32,9 +36,43 @@ pub fn Mine() -> Element {
                     }
                     // DocsButton { tab: DocsTab::Mining }
                 }
+                if cfg!(not(feature = "web")) {
+                    MiningModeToggle {}
+                }
                 MinerData {}
             }
-            // MineTable {}
+            if *mining_mode.read() == MiningMode::Pool {
+                PoolTable {}
+                MineTable {}
+            }
+        }
+    }
+}
+
+fn MiningModeToggle() -> Element {
+    let mining_mode = use_mining_mode();
+    let mut miner_status = use_miner_status();
+    rsx! {
+        Row { class: "w-full flex-wrap", gap: 2,
+            for mode in [MiningMode::Pool, MiningMode::Solo] {
+                button {
+                    key: "{mode.label()}",
+                    class: if *mining_mode.read() == mode {
+                        "rounded-full px-4 h-10 controls-primary hover:cursor-pointer"
+                    } else {
+                        "rounded-full px-4 h-10 controls-secondary hover:cursor-pointer"
+                    },
+                    onclick: move |_| {
+                        if *mining_mode.peek() == mode {
+                            return;
+                        }
+                        // Work in flight belongs to the previous mode
+                        miner_status.set(MinerStatus::Stopped);
+                        set_mining_mode(mining_mode, mode);
+                    },
+                    "{mode.label()}"
+                }
+            }
         }
     }
 }
@@ -42,6 +80,7 @@ pub fn Mine() -> Element {
 fn MinerData() -> Element {
     // Get resources
     let mut member_record = use_member_record();
+    let mining_mode = use_mining_mode();
 
     // Refresh member account
     on_transaction_done(move |_sig| {
@@ -60,7 +99,16 @@ fn MinerData() -> Element {
             //     }
             // }
             // TimeRemaining {}
-            MinerRewards {}
+            if *mining_mode.read() == MiningMode::Solo {
+                MinerStatus {}
+                MinerCores {}
+            } else {
+                MinerRewards {}
+            }
+            MinerHashpower {}
+            if *mining_mode.read() == MiningMode::Pool {
+                MinerSubmissions {}
+            }
         }
     }
 }
@@ -73,11 +121,16 @@ fn StopStartButton() -> Element {
     let mut member_record = use_member_record();
     let register_tx = use_pool_register_transaction();
     let is_active = use_miner_is_active();
-    let disable_mining = true;
+    let mining_mode = use_mining_mode();
+    let is_solo = *mining_mode.read() == MiningMode::Solo;
+    // Pool mining stays disabled, only solo mining can be started
+    let disable_mining = !is_solo;
+    // The miner runs one job at a time
+    let benchmark = use_miner_benchmark();
 
     // offchain pool server registration
     let mut register_with_pool_server = use_future(move || async move {
-        if disable_mining {
+        if *mining_mode.peek() != MiningMode::Pool || disable_mining {
             return;
         }
         let Wallet::Connected(authority) = *wallet.read() else {
@@ -133,10 +186,13 @@ fn StopStartButton() -> Element {
     rsx! {
         button {
             class: "flex flex-row gap-2 my-auto px-8 h-12 rounded-full {controls_class}",
-            disabled: matches!(*wallet.read(), Wallet::Disconnected) || disable_mining,
+            disabled: matches!(*wallet.read(), Wallet::Disconnected) || disable_mining || benchmark.is_running(),
             onclick: move |_| {
                 if *is_active.read() {
                     miner_status.set(MinerStatus::Stopped);
+                } else if is_solo {
+                    // The solo loop opens a proof first if there is none
+                    miner_status.set(MinerStatus::FetchingChallenge);
                 } else {
                     if let Ok(_member) = *member.read() {
                         if let Some(Ok(_member_db)) = member_record.cloned() {
@@ -169,7 +225,7 @@ fn StopStartButton() -> Element {
 }
 
 fn TimeRemaining() -> Element {
-    let (out_msg, _in_msg) = use_miner();
+    let out_msg = use_miner_progress();
     let miner_status = use_miner_status();
 
     let display_time_remaining = use_memo(move || match miner_status.cloned() {
@@ -221,22 +277,41 @@ fn TimeRemaining() -> Element {
 fn MinerStatus() -> Element {
     let (_out_msg, _in_msg) = use_miner();
     let miner_status = use_miner_status();
+    let mining_mode = use_mining_mode();
+    let is_solo = *mining_mode.read() == MiningMode::Solo;
+    let is_active = use_miner_is_active();
+    let control = use_miner_control();
     let status = use_memo(move || match miner_status.cloned() {
         MinerStatus::Registering => "Registering",
         MinerStatus::FetchingChallenge => "Fetching",
+        MinerStatus::Hashing if control.is_paused() => "Paused",
         MinerStatus::Hashing => "Hashing",
         MinerStatus::SubmittingSolution => "Submitting",
         MinerStatus::Stopped => "Stopped",
     });
 
-    let description = use_memo(move || match miner_status.cloned() {
-        MinerStatus::Registering => "Curerntly registering with the pool server.",
-        MinerStatus::FetchingChallenge => {
+    let description = use_memo(move || match (miner_status.cloned(), *mining_mode.read()) {
+        (MinerStatus::Registering, MiningMode::Solo) => "Currently opening your proof account.",
+        (MinerStatus::Registering, MiningMode::Pool) => {
+            "Curerntly registering with the pool server."
+        }
+        (MinerStatus::FetchingChallenge, MiningMode::Solo) => {
+            "Currently fetching the next challenge from your proof account."
+        }
+        (MinerStatus::FetchingChallenge, MiningMode::Pool) => {
             "Currently fetching the next challenge from the pool server."
         }
-        MinerStatus::Hashing => "Currently searching for valid solutions.",
-        MinerStatus::SubmittingSolution => "Currently submitting the solution to the pool server.",
-        MinerStatus::Stopped => "Currently not active.",
+        (MinerStatus::Hashing, _) if control.is_paused() => {
+            "Currently paused. The challenge still expires on schedule."
+        }
+        (MinerStatus::Hashing, _) => "Currently searching for valid solutions.",
+        (MinerStatus::SubmittingSolution, MiningMode::Solo) => {
+            "Currently submitting the solution to the ORE program."
+        }
+        (MinerStatus::SubmittingSolution, MiningMode::Pool) => {
+            "Currently submitting the solution to the pool server."
+        }
+        (MinerStatus::Stopped, _) => "Currently not active.",
     });
 
     let mut info_hidden = use_signal(|| true);
@@ -259,22 +334,184 @@ fn MinerStatus() -> Element {
             Row { class: "justify-between",
                 Col {
                     span { class: "font-semibold text-2xl sm:text-3xl", "{status}" }
+                    if !is_solo {
+                        span {
+                            class: "text-elements-midEmphasis text-sm mt-2 text-left",
+                            "Mining is currently disabled. Please try again soon."
+                        }
+                    }
+                }
+                Row { class: "my-auto", gap: 2,
+                    if *is_active.read() {
+                        PauseResumeButton {}
+                    }
+                    StopStartButton {}
+                }
+            }
+        }
+    }
+}
+
+fn PauseResumeButton() -> Element {
+    let mut control = use_miner_control();
+    rsx! {
+        button {
+            class: "flex flex-row gap-2 my-auto px-8 h-12 rounded-full controls-secondary",
+            onclick: move |_| {
+                if control.is_paused() {
+                    control.resume();
+                } else {
+                    control.pause();
+                }
+            },
+            if control.is_paused() {
+                PlayIcon { class: "my-auto h-5" }
+                span { class: "my-auto", "Resume" }
+            } else {
+                span { class: "my-auto", "Pause" }
+            }
+        }
+    }
+}
+
+fn MinerHashpower() -> Element {
+    let (_from_miner, to_miner) = use_miner();
+    let mut benchmark = use_miner_benchmark();
+    let control = use_miner_control();
+    let mut cores = use_miner_cores();
+    let is_active = use_miner_is_active();
+    let mut info_hidden = use_signal(|| true);
+
+    let max_cores = crate::cores::get();
+    let live = benchmark.live();
+    let latest = benchmark.latest();
+    let stats = control.stats();
+    let hashrate = match (&live, &stats) {
+        (Some((run, _)), _) => run.samples.last().map(|sample| sample.hashrate()),
+        (None, Some(stats)) => Some(stats.hashrate),
+        (None, None) => latest.as_ref().and_then(|run| run.hashrate(*cores.read())),
+    };
+    let recommended_cores = latest.as_ref().and_then(|run| run.recommended_cores());
+
+    rsx! {
+        Col { gap: 4,
+            button {
+                class: "flex flex-col gap-0 group",
+                onclick: move |_| info_hidden.set(!info_hidden.cloned()),
+                Row { gap: 2,
+                    span { class: "text-elements-lowEmphasis font-medium", "Hashpower" }
+                    InfoIcon { class: "h-4 w-4 shrink-0 text-elements-lowEmphasis group-hover:text-elements-highEmphasis transition-all duration-300 ease-in-out my-auto" }
+                }
+                InfoText {
+                    class: "text-wrap text-left text-sm max-w-lg mr-auto",
+                    text: "The number of hashes your machine computes per second with the selected number of cores. Run a benchmark to measure it on each core count and find how many cores are worth using.",
+                    hidden: info_hidden,
+                }
+            }
+            Row { class: "justify-between",
+                Col {
+                    span { class: "font-semibold text-2xl sm:text-3xl",
+                        if let Some(hashrate) = hashrate {
+                            "{format_abbreviated_number(hashrate)} H/s"
+                        } else {
+                            "–"
+                        }
+                    }
+                    if let Some((run, expected)) = &live {
+                        span { class: "text-elements-midEmphasis text-sm mt-2 text-left",
+                            "Benchmarking {run.samples.len() + 1} of {expected} core counts..."
+                        }
+                    } else if let Some(stats) = &stats {
+                        span { class: "text-elements-midEmphasis text-sm mt-2 text-left",
+                            "Live · {format_abbreviated_number(stats.hashes as f64)} hashes · best difficulty {stats.best_difficulty}"
+                        }
+                    } else if let Some(recommended_cores) = recommended_cores {
+                        Row { class: "mt-2", gap: 2,
+                            span { class: "text-elements-midEmphasis text-sm my-auto",
+                                "Recommended cores: {recommended_cores}"
+                            }
+                            if *cores.read() != recommended_cores {
+                                button {
+                                    class: "controls-secondary rounded-full px-3 h-8 text-xs hover:cursor-pointer",
+                                    onclick: move |_| cores.set(recommended_cores),
+                                    "Use"
+                                }
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "flex flex-row gap-2 my-auto px-8 h-12 rounded-full controls-secondary",
+                    disabled: *is_active.read() || live.is_some(),
+                    onclick: move |_| benchmark.start(to_miner, max_cores),
+                    "Benchmark"
+                }
+            }
+            BenchmarkHistory {}
+        }
+    }
+}
+
+fn BenchmarkHistory() -> Element {
+    let benchmark = use_miner_benchmark();
+    rsx! {
+        Col { class: "w-full", gap: 2,
+            for run in benchmark.history() {
+                Row {
+                    key: "{run.timestamp}",
+                    class: "w-full justify-between text-sm text-elements-lowEmphasis",
+                    span { "{format_time_since(run.timestamp)} ago" }
                     span {
-                        class: "text-elements-midEmphasis text-sm mt-2 text-left",
-                        "Mining is currently disabled. Please try again soon."
+                        if let Some(sample) = run
+                            .samples
+                            .iter()
+                            .max_by(|a, b| a.hashrate().total_cmp(&b.hashrate()))
+                        {
+                            "{format_abbreviated_number(sample.hashrate())} H/s on {sample.cores} cores · best difficulty {run.best_difficulty()}"
+                        }
                     }
                 }
-                StopStartButton {}
             }
         }
     }
 }
 
-fn _MinerHashpower() -> Element {
+fn MinerSubmissions() -> Element {
+    let solution_queue = use_solution_queue();
+    let mut info_hidden = use_signal(|| true);
     rsx! {
         Col { gap: 4,
-            span { class: "text-elements-lowEmphasis font-medium", "Hashpower" }
-            span { class: "font-semibold text-2xl sm:text-3xl", "1230 H/s" }
+            button {
+                class: "flex flex-col gap-0 group",
+                onclick: move |_| info_hidden.set(!info_hidden.cloned()),
+                Row { gap: 2,
+                    span { class: "text-elements-lowEmphasis font-medium", "Submissions" }
+                    InfoIcon { class: "h-4 w-4 shrink-0 text-elements-lowEmphasis group-hover:text-elements-highEmphasis transition-all duration-300 ease-in-out my-auto" }
+                }
+                InfoText {
+                    class: "text-wrap text-left text-sm max-w-lg mr-auto",
+                    text: "The solutions submitted to the pool in recent rounds. Failed submissions are retried until the round ends, and solutions beaten by a better one are skipped.",
+                    hidden: info_hidden,
+                }
+            }
+            Col { class: "w-full", gap: 2,
+                for round in solution_queue.history() {
+                    Row {
+                        key: "{round.last_hash_at}",
+                        class: "w-full justify-between text-sm text-elements-lowEmphasis",
+                        span {
+                            if let Some(difficulty) = round.best_accepted() {
+                                "Best accepted difficulty {difficulty}"
+                            } else {
+                                "No accepted solutions"
+                            }
+                        }
+                        span {
+                            "{round.count(SubmissionOutcome::Accepted)} accepted · {round.count(SubmissionOutcome::Rejected)} rejected · {round.count(SubmissionOutcome::Failed)} failed · {round.retries()} retries · {round.count(SubmissionOutcome::Late)} late"
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -300,25 +537,22 @@ fn MinerCores() -> Element {
             }
             Row { class: "justify-between",
                 span { class: "font-semibold text-2xl sm:text-3xl", "{cores}" }
-                if cfg!(not(feature = "web")) {
-                    Row { gap: 2,
-                        button {
-                            class: "flex items-center justify-center w-12 h-12 controls-secondary rounded-full text-3xl",
-                            onclick: move |_| {
-                                let current = cores.peek().clone() - 1;
-                                cores.set(current.max(1));
-                            },
-                            "–"
-                        }
-                        button {
-                            class: "flex items-center justify-center w-12 h-12 controls-secondary rounded-full text-3xl hover:disabled:cursor-not-allowed",
-                            disabled: cfg!(feature = "web"),
-                            onclick: move |_| {
-                                let current = cores.peek().clone() + 1;
-                                cores.set(current.min(max));
-                            },
-                            "+"
-                        }
+                Row { gap: 2,
+                    button {
+                        class: "flex items-center justify-center w-12 h-12 controls-secondary rounded-full text-3xl",
+                        onclick: move |_| {
+                            let current = cores.peek().clone() - 1;
+                            cores.set(current.max(1));
+                        },
+                        "–"
+                    }
+                    button {
+                        class: "flex items-center justify-center w-12 h-12 controls-secondary rounded-full text-3xl hover:disabled:cursor-not-allowed",
+                        onclick: move |_| {
+                            let current = cores.peek().clone() + 1;
+                            cores.set(current.min(max));
+                        },
+                        "+"
                     }
                 }
             }
@@ -363,10 +597,11 @@ fn MinerRewards() -> Element {
         {
             spawn(async move {
                 let gateway = use_gateway();
-                if let Ok(ixs) =
+                if let Ok(mut ixs) =
                     build_commit_claim_instructions(&gateway.rpc, &pool, &member, member_db_balance)
                         .await
                 {
+                    add_priority_fee(&mut ixs).await;
                     let tx = Transaction::new_with_payer(&ixs, Some(&member.authority));
                     let tx_clone = tx.clone();
                     claim_tx.set(Ok(tx_clone));
@@ -477,7 +712,9 @@ pub fn MinerRewardsClaimButton(
 
                         match sign_partial {
                             Ok((tx, hash)) => {
-                                transaction_status.set(Some(TransactionStatus::Sending(0)));
+                                transaction_status.set(Some(TransactionStatus::Sending(
+                                    ConfirmationProgress::default(),
+                                )));
                                 match gateway
                                     .commit_claim(member.authority, pool_url, tx, hash)
                                     .await
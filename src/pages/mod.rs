@@ -8,6 +8,7 @@ mod not_found;
 mod pair;
 mod post;
 mod post_terms;
+mod settings;
 mod stake;
 mod topup;
 mod trade;
@@ -24,6 +25,7 @@ pub use not_found::*;
 pub use pair::*;
 pub use post::*;
 pub use post_terms::*;
+pub use settings::*;
 pub use stake::*;
 pub use topup::*;
 pub use trade::*;
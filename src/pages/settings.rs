@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+
+use crate::components::*;
+
+pub fn Settings() -> Element {
+    #[cfg(not(feature = "web"))]
+    let jito_settings = rsx! { JitoSettings {} };
+    #[cfg(feature = "web")]
+    let jito_settings = rsx! {};
+
+    rsx! {
+        Col {
+            class: "w-full h-full pb-20 sm:pb-16",
+            gap: 8,
+            Heading {
+                class: "mx-auto w-full max-w-2xl px-5 sm:px-8",
+                title: "Settings",
+                subtitle: "Configure how the app connects to Solana and prices transactions."
+            }
+            Col {
+                class: "mx-auto w-full max-w-2xl px-5 sm:px-8",
+                gap: 16,
+                EndpointSettings {}
+                PollIntervalSettings {}
+                CommitmentSettings {}
+                CustomPoolSettings {}
+                FailoverSettings {}
+                PriorityFeeSettings {}
+                {jito_settings}
+            }
+        }
+    }
+}
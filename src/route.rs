@@ -13,6 +13,8 @@ pub enum Route {
         Post {},
         #[route("/post_terms")]
         PostTerms {},
+        #[route("/settings")]
+        Settings {},
         #[route("/stake")]
         Stake {},
         #[route("/stake/ore")]
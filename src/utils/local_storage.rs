@@ -0,0 +1,14 @@
+use gloo_storage::{errors::StorageError, LocalStorage, Storage};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub fn set_key_value<T: Serialize + DeserializeOwned + 'static>(
+    key: &str,
+    value: &T,
+) -> Result<(), StorageError> {
+    LocalStorage::set(key, value)
+}
+
+pub fn get_value<T: Serialize + DeserializeOwned + 'static>(key: &str) -> Result<T, StorageError> {
+    LocalStorage::get(key)
+}
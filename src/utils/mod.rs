@@ -1,15 +1,21 @@
-// #[cfg(feature = "desktop")]
-// mod file;
+#[cfg(feature = "desktop")]
+mod file;
 #[cfg(all(feature = "desktop", target_os = "macos"))]
 mod app_nap;
 mod format;
 mod liquidity_pair;
 #[cfg(feature = "web")]
+mod local_storage;
+#[cfg(feature = "web")]
 mod metrics;
 mod serde;
 
 #[cfg(all(feature = "desktop", target_os = "macos"))]
 pub use app_nap::*;
+#[cfg(feature = "desktop")]
+pub use file::*;
 pub use format::*;
 pub use liquidity_pair::*;
+#[cfg(feature = "web")]
+pub use local_storage::*;
 pub use serde::*;